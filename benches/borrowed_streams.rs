@@ -0,0 +1,96 @@
+//! Compares deserializing a large streams response into the owned `Streams`
+//! with deserializing it into the `Streams` of `model::borrowed`, as done by `TwitchClient::streams_borrowed`.
+//!
+//! Besides the timings of criterion the number of allocations of both approaches is printed:
+//!
+//! ```text
+//! cargo bench --features testing --bench borrowed_streams
+//! ```
+
+extern crate criterion;
+extern crate serde_json;
+extern crate twitch_client;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::Criterion;
+use twitch_client::model;
+use twitch_client::model::borrowed;
+use twitch_client::model::stream::Streams;
+use twitch_client::testing::fixtures;
+
+
+const STREAMS_COUNT: usize = 10_000;
+
+/// Allocator that keeps track of the number of allocations and allocated bytes.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+
+/// Response body with `STREAMS_COUNT` copies of the stream of the fixture.
+fn large_streams_response() -> String {
+    let mut streams: serde_json::Value = serde_json::from_str(fixtures::STREAMS).unwrap();
+    let stream = streams["streams"][0].clone();
+    streams["streams"] = serde_json::Value::Array(vec![stream; STREAMS_COUNT]);
+    streams["_total"] = serde_json::Value::from(STREAMS_COUNT);
+    serde_json::to_string(&streams).unwrap()
+}
+
+fn deserialize_owned(json: &str) -> usize {
+    let streams: Streams = serde_json::from_str(json).unwrap();
+    streams.streams().len()
+}
+
+fn deserialize_borrowed(json: &str) -> usize {
+    let streams: borrowed::Streams = model::from_json_borrowed(json).unwrap();
+    streams.streams().len()
+}
+
+/// Returns the number of allocations and allocated bytes while deserializing.
+fn count_allocations<F: FnOnce() -> usize>(deserialize: F) -> (usize, usize) {
+    let allocations_before = ALLOCATIONS.load(Ordering::SeqCst);
+    let allocated_bytes_before = ALLOCATED_BYTES.load(Ordering::SeqCst);
+    let streams_count = deserialize();
+    let allocations = ALLOCATIONS.load(Ordering::SeqCst) - allocations_before;
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::SeqCst) - allocated_bytes_before;
+    assert_eq!(streams_count, STREAMS_COUNT);
+    (allocations, allocated_bytes)
+}
+
+fn main() {
+    let response = large_streams_response();
+
+    let (owned_allocations, owned_bytes) = count_allocations(|| deserialize_owned(&response));
+    let (borrowed_allocations, borrowed_bytes) = count_allocations(|| deserialize_borrowed(&response));
+    println!("response body:          {:>10} bytes", response.len());
+    println!("owned streams:          {:>10} allocations, {:>10} bytes", owned_allocations, owned_bytes);
+    println!("borrowed streams:       {:>10} allocations, {:>10} bytes", borrowed_allocations, borrowed_bytes);
+
+    let mut criterion = Criterion::default().configure_from_args();
+    let owned_response = response.clone();
+    criterion.bench_function("streams owned", move |bencher| {
+        bencher.iter(|| deserialize_owned(&owned_response))
+    });
+    criterion.bench_function("streams borrowed", move |bencher| {
+        bencher.iter(|| deserialize_borrowed(&response))
+    });
+    criterion.final_summary();
+}
@@ -0,0 +1,101 @@
+//! Compares buffering a large follows response into a `String` before deserializing it
+//! with deserializing it directly from the response reader, as done by `TwitchClient::channel_follows`.
+//!
+//! Besides the timings of criterion the peak heap usage of both approaches is printed:
+//!
+//! ```text
+//! cargo bench --features testing --bench large_lists
+//! ```
+
+extern crate criterion;
+extern crate serde_json;
+extern crate twitch_client;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io::{Cursor, Read};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::Criterion;
+use twitch_client::model::follow::Follows;
+use twitch_client::testing::fixtures;
+
+
+const FOLLOWS_COUNT: usize = 10_000;
+
+/// Allocator that keeps track of the current and the peak number of allocated bytes.
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+        let mut peak_allocated = PEAK_ALLOCATED.load(Ordering::SeqCst);
+        while allocated > peak_allocated {
+            match PEAK_ALLOCATED.compare_exchange_weak(peak_allocated, allocated, Ordering::SeqCst, Ordering::SeqCst) {
+                Ok(_) => break,
+                Err(current_peak_allocated) => peak_allocated = current_peak_allocated,
+            }
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+
+/// Response body with `FOLLOWS_COUNT` copies of the follow of the fixture.
+fn large_follows_response() -> Vec<u8> {
+    let mut follows: serde_json::Value = serde_json::from_str(fixtures::FOLLOWS).unwrap();
+    let follow = follows["follows"][0].clone();
+    follows["follows"] = serde_json::Value::Array(vec![follow; FOLLOWS_COUNT]);
+    follows["_total"] = serde_json::Value::from(FOLLOWS_COUNT);
+    serde_json::to_vec(&follows).unwrap()
+}
+
+fn deserialize_buffered<R: Read>(mut reader: R) -> Follows {
+    let mut body = String::new();
+    reader.read_to_string(&mut body).unwrap();
+    serde_json::from_str(&body).unwrap()
+}
+
+fn deserialize_streaming<R: Read>(reader: R) -> Follows {
+    serde_json::from_reader(reader).unwrap()
+}
+
+/// Returns the peak number of bytes that were allocated in addition to the already allocated bytes.
+fn peak_allocated_bytes<F: FnOnce() -> Follows>(deserialize: F) -> usize {
+    let allocated_before = ALLOCATED.load(Ordering::SeqCst);
+    PEAK_ALLOCATED.store(allocated_before, Ordering::SeqCst);
+    let follows = deserialize();
+    let peak_allocated = PEAK_ALLOCATED.load(Ordering::SeqCst);
+    assert_eq!(follows.follows().len(), FOLLOWS_COUNT);
+    peak_allocated - allocated_before
+}
+
+fn main() {
+    let response = large_follows_response();
+
+    let buffered_bytes = peak_allocated_bytes(|| deserialize_buffered(Cursor::new(&response)));
+    let streaming_bytes = peak_allocated_bytes(|| deserialize_streaming(Cursor::new(&response)));
+    println!("response body:          {:>10} bytes", response.len());
+    println!("peak heap (buffered):   {:>10} bytes", buffered_bytes);
+    println!("peak heap (streaming):  {:>10} bytes", streaming_bytes);
+
+    let mut criterion = Criterion::default().configure_from_args();
+    let buffered_response = response.clone();
+    criterion.bench_function("follows buffered", move |bencher| {
+        bencher.iter(|| deserialize_buffered(Cursor::new(&buffered_response)))
+    });
+    criterion.bench_function("follows streaming", move |bencher| {
+        bencher.iter(|| deserialize_streaming(Cursor::new(&response)))
+    });
+    criterion.final_summary();
+}
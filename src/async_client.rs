@@ -0,0 +1,261 @@
+//! Futures based client for the Twitch REST API (`async` feature).
+//!
+//! The `AsyncTwitchClient` shares the models, params and errors with the blocking `TwitchClient`,
+//! but returns futures (of the [futures] 0.1 crate) instead of blocking the calling thread,
+//! which need to be driven by a tokio runtime (e.g. with `tokio::run`).
+//! It covers the commonly polled endpoints, the blocking client remains the complete client.
+//!
+//! [futures]: https://docs.rs/futures/0.1
+//!
+//! # Examples
+//!
+//! ```no_run
+//! extern crate futures;
+//! extern crate twitch_client;
+//!
+//! use futures::Future;
+//! use twitch_client::*;
+//! use twitch_client::async_client::AsyncTwitchClient;
+//!
+//! # fn main() {
+//! let twitch_client = AsyncTwitchClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap();
+//! let _top_games = twitch_client.top_games(TopGamesParams::default())
+//!         .map(|top_games| println!("Total games: {}", top_games.total()))
+//!         .map_err(|err| println!("Failed to retrieve top games: {}", err));
+//! // Run the future on a tokio runtime, e.g. with `tokio::run(_top_games)`.
+//! # }
+//! ```
+
+use std::sync::Arc;
+
+use futures::Future;
+use futures::future;
+use serde::de::DeserializeOwned;
+
+use error::{Error, Result};
+use model;
+use param::{IdOrName, IntoQueryString, StreamsParams, TopGamesParams};
+use transport::{self, AsyncTransport, Method, ReqwestAsyncTransport, Request};
+use transport::{KRAKEN_BASE_URL, DEFAULT_USER_AGENT};
+use {ApiVersion, StrictMode};
+
+
+/// Future of a model that is requested by the `AsyncTwitchClient`.
+pub type TwitchFuture<T> = Box<Future<Item=T, Error=Error> + Send>;
+
+
+/// Readonly futures based client for the [Twitch REST API].
+///
+/// Like the `TwitchClient` [Twitch API version 3] is used by default,
+/// which can be changed with `with_api_version`.
+/// The client is cheap to clone: clones share the transport.
+///
+/// [Twitch REST API]: https://dev.twitch.tv/docs
+/// [Twitch API version 3]: https://dev.twitch.tv/docs/v3
+#[derive(Clone)]
+pub struct AsyncTwitchClient {
+    base_url: String,
+    client_id: String,
+    oauth_token: Option<String>,
+    api_version: ApiVersion,
+    strict_mode: StrictMode,
+    transport: Arc<AsyncTransport>,
+}
+
+impl AsyncTwitchClient {
+
+    /// Constructs a new client instance with a new async reqwest client as transport.
+    pub fn new<S: Into<String>>(client_id: S) -> Result<AsyncTwitchClient> {
+        let transport = try!(ReqwestAsyncTransport::new());
+        Ok(AsyncTwitchClient::with_transport(client_id, transport))
+    }
+
+    /// Constructs a new client instance that sends the requests with the transport.
+    pub fn with_transport<S: Into<String>, T: AsyncTransport + 'static>(client_id: S, transport: T)
+            -> AsyncTwitchClient {
+        AsyncTwitchClient {
+            base_url: KRAKEN_BASE_URL.to_owned(),
+            client_id: client_id.into(),
+            oauth_token: None,
+            api_version: ApiVersion::V3,
+            strict_mode: StrictMode::default(),
+            transport: Arc::new(transport),
+        }
+    }
+
+    /// Sets the OAuth token that is sent with every request.
+    pub fn with_oauth_token<S: Into<String>>(mut self, oauth_token: S) -> AsyncTwitchClient {
+        self.oauth_token = Some(oauth_token.into());
+        self
+    }
+
+    /// Sets the base url that replaces `https://api.twitch.tv/kraken` (like `TwitchClientBuilder::with_base_url`).
+    ///
+    /// Fails with `Error::InvalidUrl` if the base url is no absolute http(s) url.
+    pub fn with_base_url<S: Into<String>>(mut self, base_url: S) -> Result<AsyncTwitchClient> {
+        self.base_url = try!(transport::normalize_base_url(base_url.into()));
+        Ok(self)
+    }
+
+    /// Sets the version of the Twitch API (defaults to `ApiVersion::V3`).
+    pub fn with_api_version(mut self, api_version: ApiVersion) -> AsyncTwitchClient {
+        self.api_version = api_version;
+        self
+    }
+
+    /// Sets the handling of unknown response fields (defaults to `StrictMode::Lenient`).
+    pub fn with_strict_mode(mut self, strict_mode: StrictMode) -> AsyncTwitchClient {
+        self.strict_mode = strict_mode;
+        self
+    }
+
+    /// Get games by number of viewers.
+    ///
+    /// Resolves to a list of games objects sorted by number of current viewers on Twitch, most popular first.
+    pub fn top_games(&self, params: TopGamesParams) -> TwitchFuture<model::game::TopGames> {
+        self.get_with_params("/games/top", params)
+    }
+
+    /// Get stream object.
+    ///
+    /// Resolves to a stream object if live.
+    pub fn stream<C: Into<IdOrName>>(&self, channel: C) -> TwitchFuture<model::stream::ChannelStream> {
+        match self.resolve_id_or_name(channel.into()) {
+            Ok(channel) => self.get(&format!("/streams/{}", channel)),
+            Err(err) => Box::new(future::err(err)),
+        }
+    }
+
+    /// Get stream objects.
+    ///
+    /// Resolves to a list of stream objects that are queried by a number of parameters
+    /// sorted by number of viewers descending.
+    pub fn streams(&self, params: StreamsParams) -> TwitchFuture<model::stream::Streams> {
+        self.get_with_params("/streams", params)
+    }
+
+    /// Get channel object.
+    ///
+    /// Resolves to a channel object.
+    pub fn channel<C: Into<IdOrName>>(&self, channel: C) -> TwitchFuture<model::channel::Channel> {
+        match self.resolve_id_or_name(channel.into()) {
+            Ok(channel) => self.get(&format!("/channels/{}", channel)),
+            Err(err) => Box::new(future::err(err)),
+        }
+    }
+
+    /// Channels and users are identified by name in version 3 and by id in version 5.
+    fn resolve_id_or_name(&self, id_or_name: IdOrName) -> Result<String> {
+        match (self.api_version, id_or_name) {
+            (ApiVersion::V3, IdOrName::Name(name)) => Ok(name),
+            (ApiVersion::V5, IdOrName::Id(id)) => Ok(id.to_string()),
+            (api_version, id_or_name) => Err(Error::UnsupportedIdOrName(api_version, id_or_name)),
+        }
+    }
+
+    fn get_with_params<Q: IntoQueryString, T: DeserializeOwned + Send + 'static>(&self, relative_url: &str,
+                                                                                params: Q) -> TwitchFuture<T> {
+        let relative_url = format!("{}{}", relative_url, params.into_query_string());
+        self.get(&relative_url)
+    }
+
+    fn get<T: DeserializeOwned + Send + 'static>(&self, relative_url: &str) -> TwitchFuture<T> {
+        let url = format!("{}{}", self.base_url, relative_url);
+        let request = Request::new(Method::Get, url, self.create_headers(), None);
+        let strict_mode = self.strict_mode;
+        let model = self.transport.send(request)
+                .and_then(move |response| {
+                    if !response.is_success() {
+                        return Err(Error::from_response(response));
+                    }
+                    model::from_json(response.body(), strict_mode)
+                });
+        Box::new(model)
+    }
+
+    fn create_headers(&self) -> Vec<(String, String)> {
+        let accept = format!("application/vnd.twitchtv.{}+json", self.api_version.to_media_type_version());
+        let mut headers = vec![
+            ("Accept".to_owned(), accept),
+            ("Client-ID".to_owned(), self.client_id.clone()),
+            ("User-Agent".to_owned(), DEFAULT_USER_AGENT.to_owned()),
+        ];
+        if let Some(ref oauth_token) = self.oauth_token {
+            headers.push(("Authorization".to_owned(), format!("OAuth {}", oauth_token)));
+        }
+        headers
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use super::*;
+    use transport::{Response, ResponseFuture};
+
+    const CHANNEL: &'static str = include_str!("testing/fixtures/channel.json");
+
+    /// Responds with the body and records the sent requests.
+    struct RecordingTransport {
+        status: u16,
+        body: &'static str,
+        requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl AsyncTransport for RecordingTransport {
+        fn send(&self, request: Request) -> ResponseFuture {
+            self.requests.lock().unwrap().push(request);
+            Box::new(future::ok(Response::new(self.status, Vec::new(), self.body.to_owned())))
+        }
+    }
+
+    fn client(status: u16, body: &'static str) -> (AsyncTwitchClient, Arc<Mutex<Vec<Request>>>) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let transport = RecordingTransport { status: status, body: body, requests: requests.clone() };
+        (AsyncTwitchClient::with_transport("client", transport), requests)
+    }
+
+    #[test]
+    fn test_channel_should_resolve_to_channel_of_shared_model() {
+        let (twitch_client, requests) = client(200, CHANNEL);
+        let channel = twitch_client.with_oauth_token("token").channel("test_channel").wait().unwrap();
+        assert_eq!(channel.name(), "test_channel");
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].url(), "https://api.twitch.tv/kraken/channels/test_channel");
+        assert!(requests[0].headers().contains(&("Accept".to_owned(), "application/vnd.twitchtv.v3+json".to_owned())));
+        assert!(requests[0].headers().contains(&("Authorization".to_owned(), "OAuth token".to_owned())));
+    }
+
+    #[test]
+    fn test_base_url_should_replace_kraken_base_url() {
+        let (twitch_client, requests) = client(200, CHANNEL);
+        let twitch_client = twitch_client.with_base_url("http://localhost:8080/kraken/").unwrap();
+        twitch_client.channel("test_channel").wait().unwrap();
+        assert_eq!(requests.lock().unwrap()[0].url(), "http://localhost:8080/kraken/channels/test_channel");
+
+        match twitch_client.with_base_url("localhost:8080/kraken") {
+            Err(Error::InvalidUrl(url)) => assert_eq!(url, "localhost:8080/kraken"),
+            result => panic!("expected Error::InvalidUrl, but was {:?}", result.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_unsuccessful_response_should_fail_with_error_of_response() {
+        let (twitch_client, _) = client(404, r#"{"error":"Not Found","status":404,"message":"Channel not found"}"#);
+        match twitch_client.channel("unknown_channel").wait() {
+            Err(Error::NotFound { .. }) => {},
+            result => panic!("expected Error::NotFound, but was {:?}", result.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_name_with_v5_should_fail_without_request() {
+        let (twitch_client, requests) = client(200, CHANNEL);
+        let result = twitch_client.with_api_version(ApiVersion::V5).channel("test_channel").wait();
+        assert!(result.is_err());
+        assert!(requests.lock().unwrap().is_empty());
+    }
+}
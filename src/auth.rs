@@ -0,0 +1,241 @@
+//! Helpers for the [OAuth authorization code flow].
+//!
+//! The flow consists of two steps:
+//!
+//! 1. The user is redirected to the url built by `AuthorizationUrlBuilder`
+//!    and authorizes the application for the requested scopes.
+//! 2. Twitch redirects the user back to the redirect uri with a `code` query parameter,
+//!    which is exchanged for an access token with `AuthClient::exchange_code`.
+//!
+//! [OAuth authorization code flow]: https://dev.twitch.tv/docs/authentication/getting-tokens-oauth#oauth-authorization-code-flow
+
+#[cfg(feature = "hyper-transport")]
+use hyper;
+#[cfg(feature = "reqwest")]
+use reqwest;
+use serde_json;
+
+use http::{TwitchHttpClient, Api, IntoQueryString};
+use transport::{self, Transport, TransportConfig};
+use error::Result;
+use model::auth::AccessToken;
+use param::QueryParams;
+
+
+/// Builder for the url the user is redirected to for authorizing the application.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::auth::AuthorizationUrlBuilder;
+///
+/// let url = AuthorizationUrlBuilder::new("uo6dggojyb8d6soh92zknwmi5ej1q2", "http://localhost")
+///         .with_scope("user_read")
+///         .with_state("c3ab8aa609ea11e793ae92361f002671")
+///         .build();
+/// assert_eq!(url, "https://id.twitch.tv/oauth2/authorize?response_type=code\
+///                  &client_id=uo6dggojyb8d6soh92zknwmi5ej1q2&redirect_uri=http%3A%2F%2Flocalhost\
+///                  &scope=user_read&state=c3ab8aa609ea11e793ae92361f002671");
+/// ```
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct AuthorizationUrlBuilder {
+    client_id: String,
+    redirect_uri: String,
+    scopes: Vec<String>,
+    state: Option<String>,
+    force_verify: Option<bool>,
+}
+
+impl AuthorizationUrlBuilder {
+    /// Creates a builder for the application with the client id and the registered redirect uri.
+    pub fn new<S: Into<String>, R: Into<String>>(client_id: S, redirect_uri: R) -> AuthorizationUrlBuilder {
+        AuthorizationUrlBuilder {
+            client_id: client_id.into(),
+            redirect_uri: redirect_uri.into(),
+            scopes: Vec::new(),
+            state: None,
+            force_verify: None,
+        }
+    }
+    /// Adds a scope that is requested from the user (e.g. "user_read").
+    pub fn with_scope<S: Into<String>>(mut self, scope: S) -> AuthorizationUrlBuilder {
+        self.scopes.push(scope.into());
+        self
+    }
+    /// Sets the scopes that are requested from the user, replacing the already added ones.
+    pub fn with_scopes(mut self, scopes: Vec<String>) -> AuthorizationUrlBuilder {
+        self.scopes = scopes;
+        self
+    }
+    /// Sets an unique token that is passed back to the redirect uri to protect against CSRF attacks.
+    pub fn with_state<S: Into<String>>(mut self, state: S) -> AuthorizationUrlBuilder {
+        self.state = Some(state.into());
+        self
+    }
+    /// Sets whether the user should be asked to re-authorize the application,
+    /// even if it was already authorized for the requested scopes.
+    pub fn with_force_verify(mut self, force_verify: bool) -> AuthorizationUrlBuilder {
+        self.force_verify = Some(force_verify);
+        self
+    }
+    /// Builds the authorization url.
+    pub fn build(self) -> String {
+        let scope = if self.scopes.is_empty() {
+            None
+        } else {
+            Some(self.scopes.join(" "))
+        };
+        let params = AuthorizationParams {
+            response_type: "code",
+            client_id: self.client_id,
+            redirect_uri: self.redirect_uri,
+            scope: scope,
+            state: self.state,
+            force_verify: self.force_verify,
+        };
+
+        let mut url = String::from(Api::Auth.base_url());
+        url.push_str("/authorize");
+        url.push_str(&params.into_query_string());
+        url
+    }
+}
+
+
+/// Client for obtaining access tokens of users with the [OAuth authorization code flow].
+///
+/// [OAuth authorization code flow]: https://dev.twitch.tv/docs/authentication/getting-tokens-oauth#oauth-authorization-code-flow
+pub struct AuthClient {
+    client_id: String,
+    client_secret: String,
+    redirect_uri: String,
+    http_client: TwitchHttpClient,
+}
+
+impl AuthClient {
+
+    /// Constructs a new client instance with the default transport (see `transport`).
+    ///
+    /// The redirect uri needs to be the same one that was used for the authorization url.
+    pub fn new<S: Into<String>, T: Into<String>, R: Into<String>>(client_id: S, client_secret: T, redirect_uri: R)
+            -> Result<AuthClient> {
+        let client_id = client_id.into();
+        let http_client = try!(TwitchHttpClient::new(Api::Auth, client_id.as_str(), &TransportConfig::default()));
+
+        let auth_client = AuthClient {
+            client_id: client_id,
+            client_secret: client_secret.into(),
+            redirect_uri: redirect_uri.into(),
+            http_client: http_client,
+        };
+        Ok(auth_client)
+    }
+
+    /// Constructs a new client instance using the provided hyper client.
+    ///
+    /// Note that the provided hyper client needs to use a tls connection.
+    #[cfg(feature = "hyper-transport")]
+    pub fn with_hyper_client<S: Into<String>, T: Into<String>, R: Into<String>>(client_id: S, client_secret: T,
+                                                                              redirect_uri: R,
+                                                                              hyper_client: hyper::Client)
+                                                                              -> AuthClient {
+        let transport = transport::HyperTransport::with_hyper_client(hyper_client);
+        AuthClient::with_transport(client_id, client_secret, redirect_uri, Box::new(transport))
+    }
+
+    /// Constructs a new client instance using the provided reqwest client.
+    #[cfg(feature = "reqwest")]
+    pub fn with_reqwest_client<S: Into<String>, T: Into<String>, R: Into<String>>(client_id: S, client_secret: T,
+                                                                                redirect_uri: R,
+                                                                                reqwest_client: reqwest::Client)
+                                                                                -> AuthClient {
+        let transport = transport::ReqwestTransport::with_reqwest_client(reqwest_client);
+        AuthClient::with_transport(client_id, client_secret, redirect_uri, Box::new(transport))
+    }
+
+    fn with_transport<S: Into<String>, T: Into<String>, R: Into<String>>(client_id: S, client_secret: T,
+                                                                       redirect_uri: R, transport: Box<Transport>)
+                                                                       -> AuthClient {
+        let client_id = client_id.into();
+        let http_client = TwitchHttpClient::with_transport(Api::Auth, client_id.as_str(), transport);
+
+        AuthClient {
+            client_id: client_id,
+            client_secret: client_secret.into(),
+            redirect_uri: redirect_uri.into(),
+            http_client: http_client,
+        }
+    }
+
+    /// Exchanges the authorization code passed to the redirect uri for an access token.
+    ///
+    /// The access token can be used with `TwitchClient::with_oauth_token`.
+    pub fn exchange_code(&self, code: &str) -> Result<AccessToken> {
+        let params = ExchangeCodeParams {
+            client_id: &self.client_id,
+            client_secret: &self.client_secret,
+            code: code,
+            grant_type: "authorization_code",
+            redirect_uri: &self.redirect_uri,
+        };
+        let response = try!(self.http_client.post_unscoped_content_with_params("/token", params));
+        let access_token: AccessToken = try!(serde_json::from_str(&response));
+        Ok(access_token)
+    }
+
+}
+
+
+#[derive(Serialize)]
+struct AuthorizationParams {
+    response_type: &'static str,
+    client_id: String,
+    redirect_uri: String,
+    scope: Option<String>,
+    state: Option<String>,
+    force_verify: Option<bool>,
+}
+
+impl QueryParams for AuthorizationParams {}
+
+#[derive(Serialize)]
+struct ExchangeCodeParams<'a> {
+    client_id: &'a str,
+    client_secret: &'a str,
+    code: &'a str,
+    grant_type: &'static str,
+    redirect_uri: &'a str,
+}
+
+impl<'a> QueryParams for ExchangeCodeParams<'a> {}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorization_url_should_join_scopes_and_force_verify() {
+        let url = AuthorizationUrlBuilder::new("client", "http://localhost")
+                .with_scope("user_read")
+                .with_scope("channel_read")
+                .with_force_verify(true)
+                .build();
+        assert_eq!(url, "https://id.twitch.tv/oauth2/authorize?response_type=code&client_id=client\
+                         &redirect_uri=http%3A%2F%2Flocalhost&scope=user_read+channel_read&force_verify=true");
+    }
+
+    #[test]
+    fn test_exchange_code_params() {
+        let params = ExchangeCodeParams {
+            client_id: "client",
+            client_secret: "secret",
+            code: "394a8bc98028f39660e53025de824134fb46313",
+            grant_type: "authorization_code",
+            redirect_uri: "http://localhost",
+        };
+        assert_eq!(params.into_query_string(), "?client_id=client&client_secret=secret\
+                                                &code=394a8bc98028f39660e53025de824134fb46313\
+                                                &grant_type=authorization_code&redirect_uri=http%3A%2F%2Flocalhost");
+    }
+}
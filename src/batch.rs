@@ -0,0 +1,91 @@
+//! Concurrent execution of the requests of batch lookups.
+
+use std::cmp;
+use std::sync::Mutex;
+
+use crossbeam;
+
+
+/// Maximum number of channels that Twitch accepts in the `channel` list of a request.
+pub const MAX_CHANNELS_PER_REQUEST: usize = 100;
+
+/// Maximum number of threads that send requests concurrently.
+pub const MAX_THREADS: usize = 4;
+
+
+/// Maps the items with `f` on up to `MAX_THREADS` threads and returns the results in the order of the items.
+pub fn map_concurrently<T, R, F>(items: Vec<T>, f: F) -> Vec<R>
+        where T: Send, R: Send, F: Fn(T) -> R + Sync {
+    map_with_max_threads(items, MAX_THREADS, f)
+}
+
+/// Maps the items with `f` on up to `max_threads` threads and returns the results in the order of the items.
+pub fn map_with_max_threads<T, R, F>(items: Vec<T>, max_threads: usize, f: F) -> Vec<R>
+        where T: Send, R: Send, F: Fn(T) -> R + Sync {
+    let thread_count = cmp::min(max_threads, items.len());
+    if thread_count <= 1 {
+        return items.into_iter().map(f).collect();
+    }
+
+    let item_count = items.len();
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let results = Mutex::new(Vec::with_capacity(item_count));
+    crossbeam::scope(|scope| {
+        for _ in 0..thread_count {
+            scope.spawn(|| {
+                loop {
+                    let next = queue.lock().unwrap().next();
+                    match next {
+                        Some((index, item)) => {
+                            let result = f(item);
+                            results.lock().unwrap().push((index, result));
+                        },
+                        None => break,
+                    }
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|&(index, _)| index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+    use super::*;
+
+    #[test]
+    fn test_map_concurrently_should_keep_order() {
+        let items: Vec<u32> = (0..50).collect();
+        let results = map_concurrently(items, |item| item * 2);
+        assert_eq!(results, (0..50).map(|item| item * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_map_with_max_threads_should_limit_concurrency() {
+        let running = Mutex::new((0, 0));
+        let results = map_with_max_threads((0..20).collect(), 2, |item: u32| {
+            {
+                let mut running = running.lock().unwrap();
+                running.0 += 1;
+                running.1 = cmp::max(running.0, running.1);
+            }
+            thread::sleep(Duration::from_millis(1));
+            running.lock().unwrap().0 -= 1;
+            item
+        });
+        assert_eq!(results, (0..20).collect::<Vec<_>>());
+        assert!(running.into_inner().unwrap().1 <= 2);
+    }
+
+    #[test]
+    fn test_map_concurrently_without_items() {
+        let results = map_concurrently(Vec::<u32>::new(), |item| item);
+        assert!(results.is_empty());
+    }
+}
@@ -0,0 +1,334 @@
+//! Caching of responses.
+//!
+//! Bots that repeatedly query the same channels or streams can avoid
+//! hitting the rate limit of Twitch by caching the responses for a short time.
+//! Only successful GET requests that do not require a scope are cached, keyed by their url.
+
+use std::collections::{HashMap, VecDeque};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+
+/// Cache for the bodies of responses, keyed by the request url.
+///
+/// Implementations need to be thread safe and may be backed by an external store (e.g. redis)
+/// to share the responses between processes.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use twitch_client::*;
+/// use twitch_client::cache::LruResponseCache;
+///
+/// let twitch_client = TwitchClientBuilder::new("<YOUR_TWITCH_CLIENT_ID>")
+///         .with_response_cache(LruResponseCache::new(1000), Duration::from_secs(60))
+///         .build()
+///         .unwrap();
+/// ```
+pub trait ResponseCache: Send + Sync {
+    /// Returns the cached response body of the url, or `None` if it is not cached or expired.
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// Caches the response body of the url for the time to live.
+    fn put(&self, key: &str, value: String, ttl: Duration);
+
+    /// Returns the cached response body of the url even if it is expired,
+    /// which is used if Twitch is unavailable (e.g. while offline or during an outage).
+    ///
+    /// Defaults to `None`, i.e. expired responses are not used.
+    fn get_stale(&self, _key: &str) -> Option<String> {
+        None
+    }
+}
+
+impl<C: ResponseCache + ?Sized> ResponseCache for Arc<C> {
+    fn get(&self, key: &str) -> Option<String> {
+        (**self).get(key)
+    }
+
+    fn put(&self, key: &str, value: String, ttl: Duration) {
+        (**self).put(key, value, ttl)
+    }
+
+    fn get_stale(&self, key: &str) -> Option<String> {
+        (**self).get_stale(key)
+    }
+}
+
+
+/// In-memory cache that evicts the least recently used response if the capacity is exceeded.
+///
+/// Can be shared between multiple clients by wrapping it in an `Arc`.
+pub struct LruResponseCache {
+    capacity: usize,
+    entries: Mutex<LruEntries>,
+}
+
+struct LruEntries {
+    values: HashMap<String, LruEntry>,
+    /// Keys from the least to the most recently used.
+    usage: VecDeque<String>,
+}
+
+struct LruEntry {
+    value: String,
+    expires_at: Instant,
+}
+
+impl LruResponseCache {
+    /// Creates a cache for up to `capacity` responses.
+    pub fn new(capacity: usize) -> LruResponseCache {
+        LruResponseCache {
+            capacity: capacity,
+            entries: Mutex::new(LruEntries {
+                values: HashMap::new(),
+                usage: VecDeque::new(),
+            }),
+        }
+    }
+    /// Maximum number of cached responses.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    /// Number of currently cached responses, including expired ones that were not evicted yet.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().values.len()
+    }
+    /// Whether no responses are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl LruEntries {
+    fn mark_used(&mut self, key: &str) {
+        self.remove_usage(key);
+        self.usage.push_back(key.to_owned());
+    }
+
+    fn remove(&mut self, key: &str) {
+        self.values.remove(key);
+        self.remove_usage(key);
+    }
+
+    fn remove_usage(&mut self, key: &str) {
+        if let Some(position) = self.usage.iter().position(|used_key| used_key == key) {
+            self.usage.remove(position);
+        }
+    }
+}
+
+impl ResponseCache for LruResponseCache {
+    fn get(&self, key: &str) -> Option<String> {
+        let mut entries = self.entries.lock().unwrap();
+        let value = match entries.values.get(key) {
+            Some(entry) if entry.expires_at > Instant::now() => entry.value.clone(),
+            Some(_) => {
+                entries.remove(key);
+                return None;
+            },
+            None => return None,
+        };
+        entries.mark_used(key);
+        Some(value)
+    }
+
+    fn put(&self, key: &str, value: String, ttl: Duration) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap();
+        entries.values.insert(key.to_owned(), LruEntry {
+            value: value,
+            expires_at: Instant::now() + ttl,
+        });
+        entries.mark_used(key);
+        while entries.values.len() > self.capacity {
+            match entries.usage.pop_front() {
+                Some(least_recently_used_key) => {
+                    entries.values.remove(&least_recently_used_key);
+                },
+                None => break,
+            }
+        }
+    }
+}
+
+
+/// File-backed cache in a directory, which survives restarts of the application.
+///
+/// Intended for slowly-changing resources (e.g. game box art, ingest lists or chat badges)
+/// that should be usable offline after the first fetch: expired responses are still returned by `get_stale`,
+/// which the client uses if Twitch is unavailable.
+/// Every response is stored in a file named after the hash of the url,
+/// containing the expiry (unix epoch timestamp in seconds), the url and the body.
+///
+/// Note that the cache applies to all GET requests of the client that do not require a scope,
+/// so a separate client may be used for the static resources.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use twitch_client::*;
+/// use twitch_client::cache::DiskResponseCache;
+///
+/// let disk_cache = DiskResponseCache::new("/var/cache/my-bot/twitch").unwrap();
+/// let twitch_client = TwitchClientBuilder::new("<YOUR_TWITCH_CLIENT_ID>")
+///         .with_response_cache(disk_cache, Duration::from_secs(24 * 60 * 60))
+///         .build()
+///         .unwrap();
+/// let ingests = twitch_client.ingests().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct DiskResponseCache {
+    directory: PathBuf,
+}
+
+/// Counter that makes the names of temporary files unique within the process.
+static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+impl DiskResponseCache {
+    /// Creates a cache in the directory, creating the directory if it does not exist.
+    pub fn new<P: AsRef<Path>>(directory: P) -> io::Result<DiskResponseCache> {
+        let directory = directory.as_ref().to_path_buf();
+        try!(fs::create_dir_all(&directory));
+        Ok(DiskResponseCache {
+            directory: directory,
+        })
+    }
+    /// Directory that contains the cached responses.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+    /// Removes all cached responses.
+    pub fn clear(&self) -> io::Result<()> {
+        for entry in try!(fs::read_dir(&self.directory)) {
+            let path = try!(entry).path();
+            if path.extension().map_or(false, |extension| extension == "cache") {
+                try!(fs::remove_file(path));
+            }
+        }
+        Ok(())
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.directory.join(format!("{:016x}.cache", fnv1a_hash(key)))
+    }
+
+    /// Reads the expiry and body of the key, or `None` if it is not cached or the file is invalid.
+    fn read(&self, key: &str) -> Option<(u64, String)> {
+        let file = match File::open(self.path(key)) {
+            Ok(file) => file,
+            Err(_) => return None,
+        };
+        let mut reader = BufReader::new(file);
+        let mut expires_at = String::new();
+        let mut cached_key = String::new();
+        let mut value = String::new();
+        if reader.read_line(&mut expires_at).is_err() || reader.read_line(&mut cached_key).is_err()
+                || reader.read_to_string(&mut value).is_err() {
+            return None;
+        }
+        // Different keys may have the same hash.
+        if cached_key.trim_right_matches('\n') != key {
+            return None;
+        }
+        expires_at.trim().parse().ok().map(|expires_at| (expires_at, value))
+    }
+
+    fn write(&self, key: &str, value: &str, expires_at: u64) -> io::Result<()> {
+        let path = self.path(key);
+        let temp_path = path.with_extension(format!("tmp{}", TEMP_FILE_COUNTER.fetch_add(1, Ordering::SeqCst)));
+        {
+            let mut file = try!(File::create(&temp_path));
+            try!(write!(file, "{}\n{}\n", expires_at, key));
+            try!(file.write_all(value.as_bytes()));
+        }
+        // Renaming replaces the file atomically, so that concurrent reads never see a partially written response.
+        fs::rename(&temp_path, &path).map_err(|error| {
+            let _ = fs::remove_file(&temp_path);
+            error
+        })
+    }
+}
+
+impl ResponseCache for DiskResponseCache {
+    fn get(&self, key: &str) -> Option<String> {
+        match self.read(key) {
+            Some((expires_at, value)) if expires_at > unix_timestamp() => Some(value),
+            _ => None,
+        }
+    }
+
+    fn put(&self, key: &str, value: String, ttl: Duration) {
+        // Caching is best effort, a response that can not be written is requested again next time.
+        let _ = self.write(key, &value, unix_timestamp().saturating_add(ttl.as_secs()));
+    }
+
+    fn get_stale(&self, key: &str) -> Option<String> {
+        self.read(key).map(|(_, value)| value)
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or(0)
+}
+
+/// Stable hash of the key, which (unlike `DefaultHasher`) does not change between Rust releases.
+fn fnv1a_hash(key: &str) -> u64 {
+    key.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_evict_least_recently_used() {
+        let cache = LruResponseCache::new(2);
+        cache.put("a", "1".to_owned(), Duration::from_secs(60));
+        cache.put("b", "2".to_owned(), Duration::from_secs(60));
+        assert_eq!(cache.get("a"), Some("1".to_owned()));
+        cache.put("c", "3".to_owned(), Duration::from_secs(60));
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("a"), Some("1".to_owned()));
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("c"), Some("3".to_owned()));
+    }
+
+    #[test]
+    fn test_should_not_return_expired() {
+        let cache = LruResponseCache::new(2);
+        cache.put("a", "1".to_owned(), Duration::from_secs(0));
+        assert_eq!(cache.get("a"), None);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_disk_cache_should_persist_responses() {
+        let directory = ::std::env::temp_dir().join(format!("twitch-client-disk-cache-{}", ::std::process::id()));
+        let ingests_key = "v3 https://api.twitch.tv/kraken/ingests";
+        let top_games_key = "v3 https://api.twitch.tv/kraken/games/top";
+        let cache = DiskResponseCache::new(&directory).unwrap();
+        cache.put(ingests_key, "{\"ingests\":[]}\n".to_owned(), Duration::from_secs(60));
+        cache.put(top_games_key, "{}".to_owned(), Duration::from_secs(0));
+
+        let reopened_cache = DiskResponseCache::new(&directory).unwrap();
+        assert_eq!(reopened_cache.get(ingests_key), Some("{\"ingests\":[]}\n".to_owned()));
+        assert_eq!(reopened_cache.get("v3 https://api.twitch.tv/kraken/streams"), None);
+        assert_eq!(reopened_cache.get(top_games_key), None);
+        assert_eq!(reopened_cache.get_stale(top_games_key), Some("{}".to_owned()));
+
+        reopened_cache.clear().unwrap();
+        assert_eq!(cache.get_stale(ingests_key), None);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
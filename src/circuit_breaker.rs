@@ -0,0 +1,196 @@
+//! Circuit breaker for failing Twitch endpoints.
+//!
+//! During Twitch outages every request waits for a timeout or a server error, which slows down
+//! the application and adds load to Twitch. The circuit breaker opens after consecutive failures
+//! and then fails requests fast with `Error::CircuitOpen`, until a single probe request succeeds again.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use error::{Result, Error};
+
+
+/// Policy of the circuit breaker.
+///
+/// The circuit opens after `failure_threshold` consecutive failed requests (server errors or io errors).
+/// While open, requests fail with `Error::CircuitOpen` without being sent.
+/// After `open_duration` the circuit is half-open and lets a single probe request through,
+/// which closes the circuit if it succeeds and opens it again if it fails.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use twitch_client::circuit_breaker::CircuitBreakerPolicy;
+///
+/// let circuit_breaker_policy = CircuitBreakerPolicy::new(3)
+///         .with_open_duration(Duration::from_secs(10));
+/// assert_eq!(circuit_breaker_policy.failure_threshold(), 3);
+/// ```
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct CircuitBreakerPolicy {
+    failure_threshold: u32,
+    open_duration: Duration,
+}
+
+impl CircuitBreakerPolicy {
+    /// Creates a policy with the number of consecutive failures that open the circuit
+    /// and an open duration of 30 seconds.
+    pub fn new(failure_threshold: u32) -> CircuitBreakerPolicy {
+        CircuitBreakerPolicy {
+            failure_threshold: failure_threshold,
+            open_duration: Duration::from_secs(30),
+        }
+    }
+    /// Sets the duration that the circuit stays open before a probe request is let through.
+    pub fn with_open_duration(mut self, open_duration: Duration) -> CircuitBreakerPolicy {
+        self.open_duration = open_duration;
+        self
+    }
+    /// Number of consecutive failures that open the circuit.
+    pub fn failure_threshold(&self) -> u32 {
+        self.failure_threshold
+    }
+    /// Duration that the circuit stays open before a probe request is let through.
+    pub fn open_duration(&self) -> Duration {
+        self.open_duration
+    }
+}
+
+impl Default for CircuitBreakerPolicy {
+    /// Policy that opens the circuit after 5 consecutive failures for 30 seconds.
+    fn default() -> CircuitBreakerPolicy {
+        CircuitBreakerPolicy::new(5)
+    }
+}
+
+/// State of the circuit breaker.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum CircuitState {
+    /// Requests are sent.
+    Closed,
+    /// Requests fail fast with `Error::CircuitOpen`.
+    Open,
+    /// The open duration elapsed and a single probe request is let through.
+    HalfOpen,
+}
+
+
+/// Circuit breaker that is shared by the clones of a client.
+#[derive(Debug)]
+pub(crate) struct CircuitBreaker {
+    policy: CircuitBreakerPolicy,
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Debug, Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    probing: bool,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(policy: CircuitBreakerPolicy) -> CircuitBreaker {
+        CircuitBreaker {
+            policy: policy,
+            state: Mutex::new(BreakerState::default()),
+        }
+    }
+
+    pub(crate) fn state(&self) -> CircuitState {
+        let state = self.state.lock().unwrap();
+        match state.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if !state.probing && opened_at.elapsed() < self.policy.open_duration => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Returns `Error::CircuitOpen` if the request must not be sent,
+    /// otherwise the request is let through (as probe if the circuit is half-open).
+    pub(crate) fn before_request(&self) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        let opened_at = match state.opened_at {
+            Some(opened_at) => opened_at,
+            None => return Ok(()),
+        };
+        let elapsed = opened_at.elapsed();
+        if elapsed < self.policy.open_duration {
+            return Err(Error::CircuitOpen { retry_after: self.policy.open_duration - elapsed });
+        }
+        if state.probing {
+            return Err(Error::CircuitOpen { retry_after: Duration::from_secs(0) });
+        }
+        state.probing = true;
+        Ok(())
+    }
+
+    /// Records the result of a request that was let through.
+    pub(crate) fn after_request<T>(&self, result: &Result<T>) {
+        let mut state = self.state.lock().unwrap();
+        match *result {
+            Err(ref error) if is_failure(error) => {
+                state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+                if state.probing || state.consecutive_failures >= self.policy.failure_threshold {
+                    state.opened_at = Some(Instant::now());
+                }
+            },
+            _ => {
+                state.consecutive_failures = 0;
+                state.opened_at = None;
+            },
+        }
+        state.probing = false;
+    }
+}
+
+/// Whether the error indicates an outage of Twitch, i.e. a server error (5xx) or an io error (e.g. a timeout).
+fn is_failure(error: &Error) -> bool {
+    match *error {
+        Error::RateLimited { .. } => false,
+        ref error => error.is_retryable(),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server_error() -> Result<()> {
+        Err(Error::UnexpectedStatus { status: 503, headers: Vec::new(), body: String::new(), api_error: None })
+    }
+
+    #[test]
+    fn test_should_open_after_consecutive_failures() {
+        let circuit_breaker = CircuitBreaker::new(CircuitBreakerPolicy::new(2));
+        circuit_breaker.after_request(&server_error());
+        circuit_breaker.after_request(&Ok(()));
+        circuit_breaker.after_request(&server_error());
+        assert_eq!(circuit_breaker.state(), CircuitState::Closed);
+        assert!(circuit_breaker.before_request().is_ok());
+
+        circuit_breaker.after_request(&server_error());
+        assert_eq!(circuit_breaker.state(), CircuitState::Open);
+        match circuit_breaker.before_request() {
+            Err(Error::CircuitOpen { retry_after }) => assert!(retry_after <= Duration::from_secs(30)),
+            result => panic!("expecting circuit open error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_should_let_single_probe_through_when_half_open() {
+        let policy = CircuitBreakerPolicy::new(1).with_open_duration(Duration::from_secs(0));
+        let circuit_breaker = CircuitBreaker::new(policy);
+        circuit_breaker.after_request(&server_error());
+        assert_eq!(circuit_breaker.state(), CircuitState::HalfOpen);
+
+        assert!(circuit_breaker.before_request().is_ok());
+        assert!(circuit_breaker.before_request().is_err());
+        circuit_breaker.after_request(&server_error());
+        assert!(circuit_breaker.before_request().is_ok());
+        circuit_breaker.after_request(&Ok(()));
+        assert_eq!(circuit_breaker.state(), CircuitState::Closed);
+    }
+}
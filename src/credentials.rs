@@ -0,0 +1,113 @@
+//! Credentials that are sent with each request.
+//!
+//! Twitch applies the rate limits per Client ID (and per OAuth token for authenticated requests).
+//! Applications that have several Client IDs or tokens can spread their requests over them
+//! with a `CredentialsProvider`, which is asked for the credentials of every request.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+
+/// Client ID and optional OAuth token of a request.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Credentials {
+    client_id: String,
+    oauth_token: Option<String>,
+}
+
+impl Credentials {
+    /// Creates credentials with the Client ID and without an OAuth token.
+    pub fn new<S: Into<String>>(client_id: S) -> Credentials {
+        Credentials {
+            client_id: client_id.into(),
+            oauth_token: None,
+        }
+    }
+    /// Sets the OAuth token that is used to authenticate the request.
+    pub fn with_oauth_token<S: Into<String>>(mut self, oauth_token: S) -> Credentials {
+        self.oauth_token = Some(oauth_token.into());
+        self
+    }
+    /// Client ID that is sent in the `Client-ID` header.
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+    /// OAuth token that is sent in the `Authorization` header.
+    pub fn oauth_token(&self) -> Option<&str> {
+        self.oauth_token.as_ref().map(|oauth_token| oauth_token.as_str())
+    }
+}
+
+/// Provides the credentials of each request (including retries),
+/// replacing the Client ID and OAuth token of the client.
+///
+/// Providers are shared between threads, e.g. for the concurrent requests of batch lookups.
+pub trait CredentialsProvider: Send + Sync {
+    /// Returns the credentials for the next request.
+    fn credentials(&self) -> Credentials;
+}
+
+/// Provider that cycles through a fixed list of credentials, so that the requests are spread evenly.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::*;
+/// use twitch_client::credentials::{Credentials, RoundRobinCredentials};
+///
+/// let credentials = RoundRobinCredentials::new(vec![
+///     Credentials::new("<FIRST_TWITCH_CLIENT_ID>"),
+///     Credentials::new("<SECOND_TWITCH_CLIENT_ID>").with_oauth_token("<OAUTH_TOKEN>"),
+/// ]);
+/// let twitch_client = TwitchClientBuilder::new("<FIRST_TWITCH_CLIENT_ID>")
+///         .with_credentials_provider(credentials)
+///         .build()
+///         .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct RoundRobinCredentials {
+    credentials: Vec<Credentials>,
+    next: AtomicUsize,
+}
+
+impl RoundRobinCredentials {
+    /// Creates a provider that starts with the first credentials.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list of credentials is empty.
+    pub fn new(credentials: Vec<Credentials>) -> RoundRobinCredentials {
+        assert!(!credentials.is_empty(), "at least one credentials are required");
+        RoundRobinCredentials {
+            credentials: credentials,
+            next: AtomicUsize::new(0),
+        }
+    }
+    /// Credentials that are cycled through.
+    pub fn credentials_list(&self) -> &[Credentials] {
+        &self.credentials
+    }
+}
+
+impl CredentialsProvider for RoundRobinCredentials {
+    fn credentials(&self) -> Credentials {
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.credentials.len();
+        self.credentials[index].clone()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_robin_should_cycle_through_credentials() {
+        let provider = RoundRobinCredentials::new(vec![
+            Credentials::new("client_id1"),
+            Credentials::new("client_id2").with_oauth_token("oauth_token2"),
+        ]);
+        let client_ids: Vec<_> = (0..5).map(|_| provider.credentials().client_id().to_owned()).collect();
+        assert_eq!(client_ids, vec!["client_id1", "client_id2", "client_id1", "client_id2", "client_id1"]);
+        assert_eq!(provider.credentials().oauth_token(), Some("oauth_token2"));
+    }
+}
@@ -3,78 +3,340 @@
 use std::error::Error as StdError;
 use std::fmt;
 use std::io::Error as IoError;
-use hyper::client::response::Response;
+use std::time::Duration;
+#[cfg(feature = "hyper-transport")]
 use hyper::error::Error as HyperError;
+#[cfg(feature = "hyper-client")]
 use hyper_native_tls::ServerError as HyperNativeTlsServerError;
+#[cfg(feature = "hyper-client")]
 use native_tls::Error as NativeTlsError;
+#[cfg(feature = "reqwest")]
+use reqwest::Error as ReqwestError;
+#[cfg(feature = "pubsub")]
+use tungstenite::Error as WebSocketError;
+use serde_json;
 use serde_json::error::Error as JsonError;
+use transport::Response;
+use ApiVersion;
+use param::{IdOrName, ParamError};
+
+/// Names of the response headers that are captured in the errors of unsuccessful responses,
+/// because they help to diagnose the error.
+pub const HEADERS_OF_INTEREST: [&'static str; 7] = [
+    "Content-Type",
+    "Date",
+    "Retry-After",
+    "Ratelimit-Limit",
+    "Ratelimit-Remaining",
+    "Ratelimit-Reset",
+    "Twitch-Trace-Id",
+];
 
 /// Result type from methods that can have Twitch Client Errors.
 pub type Result<T> = ::std::result::Result<T, Error>;
 
 use self::Error::{
-    Http,
+    NotFound,
+    BadRequest,
+    UnexpectedStatus,
+    Unauthorized,
+    InvalidCommercialLength,
+    CommercialRejected,
+    UnsupportedIdOrName,
+    Param,
+    RateLimited,
+    CircuitOpen,
+    InvalidUrl,
+    UnknownFields,
     Io,
-    Hyper,
-    Tls,
     Deserialization,
 };
+#[cfg(feature = "hyper-transport")]
+use self::Error::Hyper;
+#[cfg(feature = "hyper-client")]
+use self::Error::Tls;
+#[cfg(feature = "reqwest")]
+use self::Error::Reqwest;
+#[cfg(feature = "pubsub")]
+use self::Error::{WebSocket, PubSubRejected};
 
 
 /// Twitch Client error.
 ///
 /// Wraps errors that may occur during communication with Twitch
 /// or when trying to deserialize the JSON response.
+/// Errors of unsuccessful responses own the status, the headers of interest and the body,
+/// so that they can be logged or sent to other threads after the connection was closed.
+/// The error is `Send + Sync + 'static` and exposes the wrapped errors with `source()`.
 ///
 /// This list is intended to grow over time
 /// and it is not recommended to exhaustively match against it.
 #[derive(Debug)]
 pub enum Error {
-    /// An http error while communicating with the twitch server
-    Http(Response),
+    /// The requested resource was not found (`404 Not Found`), containing the response body.
+    NotFound {
+        /// Response headers of interest (see `HEADERS_OF_INTEREST`).
+        headers: Vec<(String, String)>,
+        /// Response body.
+        body: String,
+        /// Error description from Twitch, if the body contained one.
+        api_error: Option<TwitchApiError>,
+    },
+    /// Twitch rejected the request as invalid (`400 Bad Request`), containing the response body.
+    BadRequest {
+        /// Response headers of interest (see `HEADERS_OF_INTEREST`).
+        headers: Vec<(String, String)>,
+        /// Response body.
+        body: String,
+        /// Error description from Twitch, if the body contained one.
+        api_error: Option<TwitchApiError>,
+    },
+    /// Twitch responded with a status code that is not handled otherwise.
+    UnexpectedStatus {
+        /// Http status code.
+        status: u16,
+        /// Response headers of interest (see `HEADERS_OF_INTEREST`).
+        headers: Vec<(String, String)>,
+        /// Response body.
+        body: String,
+        /// Error description from Twitch, if the body contained one.
+        api_error: Option<TwitchApiError>,
+    },
+    /// The request requires an OAuth token with the contained scope,
+    /// but either no token was set or the token does not have the scope.
+    Unauthorized(&'static str),
+    /// The commercial length is not one of the lengths allowed by Twitch (30, 60, 90, 120, 150 or 180 seconds).
+    InvalidCommercialLength(u16),
+    /// Twitch rejected running the commercial (e.g. because the channel is offline or
+    /// the last commercial was less than 8 minutes ago) with the contained message.
+    CommercialRejected(String),
+    /// The channel or user was identified by a name for `ApiVersion::V5` or by an id for `ApiVersion::V3`.
+    ///
+    /// Twitch API version 3 requires names, whereas version 5 requires ids.
+    UnsupportedIdOrName(ApiVersion, IdOrName),
+    /// A parameter value is not allowed by Twitch (see the `validate` methods of the params).
+    Param(ParamError),
+    /// Twitch rejected the request with `429 Too Many Requests`,
+    /// because the rate limit was exceeded (see `TwitchClient::rate_limit_status`).
+    ///
+    /// Only returned if the request is not retried by the `RetryPolicy`.
+    RateLimited {
+        /// Duration to wait before the request may be sent again.
+        retry_after: Duration,
+    },
+    /// The request was not sent, because the circuit breaker is open after consecutive failures of Twitch
+    /// (see `TwitchClientBuilder::with_circuit_breaker`).
+    CircuitOpen {
+        /// Duration until a probe request is let through again.
+        retry_after: Duration,
+    },
+    /// The contained url (e.g. a link of a response) is not a valid url.
+    InvalidUrl(String),
+    /// The response contained fields that are not known by the model (paths like "streams.0.channel.foo"),
+    /// only returned in `StrictMode::Strict`.
+    UnknownFields(Vec<String>),
     /// An `io::Error` that occurred while trying to read or write to a network stream.
     Io(IoError),
     /// An `hyper::error::Error` that occurred while trying to use the hyper library.
+    #[cfg(feature = "hyper-transport")]
     Hyper(HyperError),
-    /// An `hyper::error::Error` that occurred while trying to use the hyper library.
+    /// An `native_tls::Error` that occurred while trying to establish a tls connection.
+    #[cfg(feature = "hyper-client")]
     Tls(NativeTlsError),
+    /// An `reqwest::Error` that occurred while trying to use the reqwest library.
+    #[cfg(feature = "reqwest")]
+    Reqwest(ReqwestError),
+    /// An `tungstenite::Error` that occurred on the WebSocket connection to Twitch PubSub.
+    #[cfg(feature = "pubsub")]
+    WebSocket(WebSocketError),
+    /// Twitch PubSub rejected listening to topics with the contained error (e.g. "ERR_BADAUTH").
+    #[cfg(feature = "pubsub")]
+    PubSubRejected(String),
     /// An `serde_json::error::Error` that occurred while trying to deserialize a json response string.
     Deserialization(JsonError),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(self.description())
+        match *self {
+            NotFound { ref body, ref api_error, .. } | BadRequest { ref body, ref api_error, .. } => {
+                match *api_error {
+                    Some(ref api_error) => write!(f, "{}: {}", self.description(), api_error.message()),
+                    None => write!(f, "{}: {}", self.description(), body),
+                }
+            },
+            UnexpectedStatus { status, ref body, ref api_error, .. } => {
+                match *api_error {
+                    Some(ref api_error) => write!(f, "{}: {} {}", self.description(), status, api_error.message()),
+                    None => write!(f, "{}: {} {}", self.description(), status, body),
+                }
+            },
+            Unauthorized(scope) => write!(f, "{}: \"{}\"", self.description(), scope),
+            InvalidCommercialLength(length) => write!(f, "{}: {}", self.description(), length),
+            CommercialRejected(ref message) => write!(f, "{}: {}", self.description(), message),
+            InvalidUrl(ref url) => write!(f, "{}: {}", self.description(), url),
+            UnknownFields(ref paths) => write!(f, "{}: {}", self.description(), paths.join(", ")),
+            Param(ref error) => write!(f, "{}: {}", self.description(), error),
+            #[cfg(feature = "pubsub")]
+            PubSubRejected(ref error) => write!(f, "{}: {}", self.description(), error),
+            UnsupportedIdOrName(api_version, ref id_or_name) => {
+                write!(f, "{}: {:?} with {:?}", self.description(), id_or_name, api_version)
+            },
+            RateLimited { retry_after } | CircuitOpen { retry_after } => {
+                write!(f, "{}, retry after {} seconds", self.description(), retry_after.as_secs())
+            },
+            _ => f.write_str(self.description()),
+        }
     }
 }
 
 impl StdError for Error {
     fn description(&self) -> &str {
         match *self {
-            Http(ref _response) => "An http error while communicating with the twitch server",
+            NotFound { .. } => "The requested resource was not found",
+            BadRequest { .. } => "Twitch rejected the request as invalid",
+            UnexpectedStatus { .. } => "Unexpected http status code from the twitch server",
+            Unauthorized(_) => "The request requires an OAuth token with the scope",
+            InvalidCommercialLength(_) => "The commercial length is not allowed by Twitch",
+            CommercialRejected(_) => "Twitch rejected running the commercial",
+            UnsupportedIdOrName(_, _) => "The channel or user identifier is not supported by the Twitch API version",
+            Param(_) => "A parameter value is not allowed by Twitch",
+            RateLimited { .. } => "The rate limit of Twitch was exceeded",
+            CircuitOpen { .. } => "The circuit breaker is open after consecutive failures of Twitch",
+            InvalidUrl(_) => "The url is not valid",
+            UnknownFields(_) => "The response contains fields that are unknown to the model",
             Io(ref e) => e.description(),
+            #[cfg(feature = "hyper-transport")]
             Hyper(ref e) => e.description(),
+            #[cfg(feature = "hyper-client")]
             Tls(ref e) => e.description(),
+            #[cfg(feature = "reqwest")]
+            Reqwest(ref e) => e.description(),
+            #[cfg(feature = "pubsub")]
+            WebSocket(ref e) => e.description(),
+            #[cfg(feature = "pubsub")]
+            PubSubRejected(_) => "Twitch PubSub rejected listening to the topics",
             Deserialization(ref e) => e.description(),
         }
     }
 
     fn cause(&self) -> Option<&StdError> {
+        self.source()
+    }
+
+    fn source(&self) -> Option<&(StdError + 'static)> {
         match *self {
             Io(ref error) => Some(error),
+            Param(ref error) => Some(error),
+            #[cfg(feature = "hyper-transport")]
             Hyper(ref error) => Some(error),
+            #[cfg(feature = "hyper-client")]
+            Tls(ref error) => Some(error),
+            #[cfg(feature = "reqwest")]
+            Reqwest(ref error) => Some(error),
+            #[cfg(feature = "pubsub")]
+            WebSocket(ref error) => Some(error),
             Deserialization(ref error) => Some(error),
             _ => None,
         }
     }
 }
 
+impl Error {
+    /// Returns the http status code of the response that caused the error,
+    /// or `None` if the error was not caused by an unsuccessful response.
+    pub fn status(&self) -> Option<u16> {
+        match *self {
+            NotFound { .. } => Some(404),
+            BadRequest { .. } => Some(400),
+            UnexpectedStatus { status, .. } => Some(status),
+            CommercialRejected(_) => Some(422),
+            RateLimited { .. } => Some(429),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the request may succeed when it is sent again,
+    /// i.e. for server errors (5xx), exceeded rate limits and io errors.
+    pub fn is_retryable(&self) -> bool {
+        match *self {
+            UnexpectedStatus { status, .. } => status >= 500,
+            RateLimited { .. } => true,
+            Io(_) => true,
+            #[cfg(feature = "reqwest")]
+            Reqwest(ref error) => error.is_timeout() || error.is_server_error(),
+            _ => false,
+        }
+    }
+
+    /// Converts an unsuccessful response into the matching error.
+    pub(crate) fn from_response(response: Response) -> Error {
+        let api_error = serde_json::from_str(response.body()).ok();
+        let headers = response.headers().iter()
+                .filter(|&&(ref name, _)| {
+                    HEADERS_OF_INTEREST.iter().any(|header_name| header_name.eq_ignore_ascii_case(name))
+                })
+                .cloned()
+                .collect();
+        let status = response.status();
+        let body = response.into_body();
+        match status {
+            404 => NotFound { headers: headers, body: body, api_error: api_error },
+            400 => BadRequest { headers: headers, body: body, api_error: api_error },
+            status => UnexpectedStatus { status: status, headers: headers, body: body, api_error: api_error },
+        }
+    }
+}
+
+
+/// Error description that Twitch sends in the body of unsuccessful responses.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "error": "Unprocessable Entity",
+///   "status": 422,
+///   "message": "Commercials breaks are allowed every 8 min and only when you are online."
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct TwitchApiError {
+    #[serde(default)]
+    error: String,
+    status: u16,
+    #[serde(default)]
+    message: String,
+}
+
+impl TwitchApiError {
+    /// Example value: "Unprocessable Entity"
+    pub fn error(&self) -> &String {
+        &self.error
+    }
+    /// Example value: 422
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+    /// Example value: "Commercials breaks are allowed every 8 min and only when you are online."
+    pub fn message(&self) -> &String {
+        &self.message
+    }
+}
+
+impl From<ParamError> for Error {
+    fn from(err: ParamError) -> Error {
+        Param(err)
+    }
+}
+
 impl From<IoError> for Error {
     fn from(err: IoError) -> Error {
         Io(err)
     }
 }
 
+#[cfg(feature = "hyper-transport")]
 impl From<HyperError> for Error {
     fn from(err: HyperError) -> Error {
         match err {
@@ -84,6 +346,7 @@ impl From<HyperError> for Error {
     }
 }
 
+#[cfg(feature = "hyper-client")]
 impl From<HyperNativeTlsServerError> for Error {
     fn from(err: HyperNativeTlsServerError) -> Error {
         match err {
@@ -93,14 +356,84 @@ impl From<HyperNativeTlsServerError> for Error {
     }
 }
 
+#[cfg(feature = "hyper-client")]
 impl From<NativeTlsError> for Error {
     fn from(err: NativeTlsError) -> Error {
         Tls(err)
     }
 }
 
+#[cfg(feature = "reqwest")]
+impl From<ReqwestError> for Error {
+    fn from(err: ReqwestError) -> Error {
+        Reqwest(err)
+    }
+}
+
+#[cfg(feature = "pubsub")]
+impl From<WebSocketError> for Error {
+    fn from(err: WebSocketError) -> Error {
+        match err {
+            WebSocketError::Io(e) => Io(e),
+            _ => WebSocket(err),
+        }
+    }
+}
+
 impl From<JsonError> for Error {
     fn from(err: JsonError) -> Error {
         Deserialization(err)
     }
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync_static<T: Send + Sync + 'static>() {}
+
+    #[test]
+    fn test_error_should_be_send_sync_static() {
+        assert_send_sync_static::<Error>();
+    }
+
+    #[test]
+    fn test_status_and_is_retryable() {
+        let error = Error::from_response(Response::new(503, Vec::new(), String::new()));
+        assert_eq!(error.status(), Some(503));
+        assert!(error.is_retryable());
+
+        let error = Error::from_response(Response::new(400, Vec::new(), String::new()));
+        assert_eq!(error.status(), Some(400));
+        assert!(!error.is_retryable());
+    }
+
+    #[test]
+    fn test_from_response_should_parse_twitch_api_error() {
+        let body = r#"{"error":"Unprocessable Entity","status":422,"message":"Channel is offline"}"#;
+        let headers = vec![
+            ("Twitch-Trace-Id".to_owned(), "8d2d2f6a1b2c3d4e".to_owned()),
+            ("Set-Cookie".to_owned(), "session=secret".to_owned()),
+        ];
+        let response = Response::new(422, headers, body.to_owned());
+        match Error::from_response(response) {
+            UnexpectedStatus { status, headers, api_error: Some(api_error), .. } => {
+                assert_eq!(status, 422);
+                assert_eq!(headers, vec![("Twitch-Trace-Id".to_owned(), "8d2d2f6a1b2c3d4e".to_owned())]);
+                assert_eq!(api_error.error(), "Unprocessable Entity");
+                assert_eq!(api_error.message(), "Channel is offline");
+            },
+            error => panic!("expecting unexpected status error with api error, but got: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn test_from_response_without_twitch_api_error() {
+        let response = Response::new(404, Vec::new(), "Not Found".to_owned());
+        match Error::from_response(response) {
+            NotFound { body, api_error: None, .. } => assert_eq!(body, "Not Found"),
+            error => panic!("expecting not found error without api error, but got: {:?}", error),
+        }
+    }
+}
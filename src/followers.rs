@@ -0,0 +1,336 @@
+//! Tracking of new followers of a channel.
+//!
+//! Twitch API v3/v5 has no push notifications for new followers,
+//! so the `FollowerTracker` pages through the follows of a channel (newest first) at an interval
+//! until it reaches a follower that it has already seen, and reports the others as `FollowerEvent::NewFollower`.
+//! The seen followers are kept in a `FollowerStore`, which can persist them (e.g. `FileFollowerStore`)
+//! so that followers are not reported again after a restart.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use twitch_client::TwitchClient;
+//! use twitch_client::followers::{FollowerTracker, FollowerEvent, FileFollowerStore};
+//!
+//! let twitch_client = Arc::new(TwitchClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap());
+//!
+//! let handle = FollowerTracker::new(twitch_client, "test_channel")
+//!         .with_store(FileFollowerStore::open("followers.txt").unwrap())
+//!         .spawn(|event| {
+//!             if let FollowerEvent::NewFollower { user, .. } = event {
+//!                 println!("Thanks for the follow, {}!", user.display_name());
+//!             }
+//!         });
+//! # handle.stop();
+//! ```
+
+use std::collections::HashSet;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use error::{Error, Result};
+use model::DateTime;
+use model::id::UserId;
+use model::user::User;
+use param::{IdOrName, FollowsParams, Direction};
+use TwitchClient;
+
+
+const DEFAULT_INTERVAL_SECS: u64 = 60;
+const FOLLOWS_PER_PAGE: u8 = 100;
+/// Limits the requests of a single poll, e.g. after a long downtime of the tracker.
+const MAX_PAGES_PER_POLL: u32 = 10;
+
+
+/// Event of a `FollowerTracker`.
+#[derive(Debug)]
+pub enum FollowerEvent {
+    /// A user followed the channel for the first time since the tracking started.
+    NewFollower {
+        /// Following user.
+        user: User,
+        /// Date when the user followed the channel.
+        followed_at: DateTime,
+    },
+    /// Polling failed. The followers that were not reported yet are reported by the next successful poll.
+    PollFailed(Error),
+}
+
+/// Set of the followers (user ids) that were already seen by a `FollowerTracker`.
+///
+/// Implementations need to be thread safe and may be backed by an external store (e.g. a database).
+pub trait FollowerStore: Send + Sync {
+    /// Whether no followers were seen yet, in which case the current followers are not reported as new.
+    fn is_empty(&self) -> bool;
+
+    /// Whether the follower was already seen.
+    fn contains(&self, user_id: UserId) -> bool;
+
+    /// Marks the follower as seen.
+    fn insert(&self, user_id: UserId) -> Result<()>;
+}
+
+impl<S: FollowerStore + ?Sized> FollowerStore for Arc<S> {
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+
+    fn contains(&self, user_id: UserId) -> bool {
+        (**self).contains(user_id)
+    }
+
+    fn insert(&self, user_id: UserId) -> Result<()> {
+        (**self).insert(user_id)
+    }
+}
+
+
+/// In-memory store of the seen followers, which are lost when the process exits.
+#[derive(Debug, Default)]
+pub struct MemoryFollowerStore {
+    user_ids: Mutex<HashSet<UserId>>,
+}
+
+impl MemoryFollowerStore {
+    /// Creates an empty store.
+    pub fn new() -> MemoryFollowerStore {
+        MemoryFollowerStore::default()
+    }
+}
+
+impl FollowerStore for MemoryFollowerStore {
+    fn is_empty(&self) -> bool {
+        self.user_ids.lock().unwrap().is_empty()
+    }
+
+    fn contains(&self, user_id: UserId) -> bool {
+        self.user_ids.lock().unwrap().contains(&user_id)
+    }
+
+    fn insert(&self, user_id: UserId) -> Result<()> {
+        self.user_ids.lock().unwrap().insert(user_id);
+        Ok(())
+    }
+}
+
+
+/// Store of the seen followers in a file with one user id per line.
+///
+/// New followers are appended to the file, so that they survive a restart.
+#[derive(Debug)]
+pub struct FileFollowerStore {
+    user_ids: MemoryFollowerStore,
+    file: Mutex<File>,
+}
+
+impl FileFollowerStore {
+    /// Opens the file (creating it if it does not exist) and loads the seen followers.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<FileFollowerStore> {
+        let file = try!(OpenOptions::new().read(true).append(true).create(true).open(path));
+        let user_ids = MemoryFollowerStore::new();
+        for line in BufReader::new(&file).lines() {
+            if let Ok(user_id) = try!(line).trim().parse() {
+                user_ids.user_ids.lock().unwrap().insert(user_id);
+            }
+        }
+        Ok(FileFollowerStore {
+            user_ids: user_ids,
+            file: Mutex::new(file),
+        })
+    }
+}
+
+impl FollowerStore for FileFollowerStore {
+    fn is_empty(&self) -> bool {
+        self.user_ids.is_empty()
+    }
+
+    fn contains(&self, user_id: UserId) -> bool {
+        self.user_ids.contains(user_id)
+    }
+
+    fn insert(&self, user_id: UserId) -> Result<()> {
+        if self.user_ids.contains(user_id) {
+            return Ok(());
+        }
+        try!(writeln!(self.file.lock().unwrap(), "{}", user_id));
+        self.user_ids.insert(user_id)
+    }
+}
+
+
+/// Polls the follows of a channel on a background thread (see `followers`).
+pub struct FollowerTracker {
+    client: Arc<TwitchClient>,
+    channel: IdOrName,
+    store: Box<FollowerStore>,
+    interval: Duration,
+}
+
+/// Handle of a running `FollowerTracker` to stop it.
+///
+/// The tracker is stopped when the handle is dropped.
+pub struct TrackerHandle {
+    stop_sender: Sender<()>,
+    thread: JoinHandle<()>,
+}
+
+impl FollowerTracker {
+    /// Constructs a new tracker of the channel with a `MemoryFollowerStore` that polls every 60 seconds.
+    pub fn new<C: Into<IdOrName>>(client: Arc<TwitchClient>, channel: C) -> FollowerTracker {
+        FollowerTracker {
+            client: client,
+            channel: channel.into(),
+            store: Box::new(MemoryFollowerStore::new()),
+            interval: Duration::from_secs(DEFAULT_INTERVAL_SECS),
+        }
+    }
+    /// Sets the store of the seen followers.
+    pub fn with_store<S: FollowerStore + 'static>(mut self, store: S) -> FollowerTracker {
+        self.store = Box::new(store);
+        self
+    }
+    /// Sets the time between two polls.
+    pub fn with_interval(mut self, interval: Duration) -> FollowerTracker {
+        self.interval = interval;
+        self
+    }
+    /// Polls the follows once and returns the new followers, oldest first.
+    ///
+    /// If the store is empty, the newest followers are only marked as seen and not returned.
+    pub fn poll(&self) -> Result<Vec<FollowerEvent>> {
+        let baseline = self.store.is_empty();
+        let mut new_follows = Vec::new();
+
+        'pages: for page in 0..MAX_PAGES_PER_POLL {
+            let params = FollowsParams::new()
+                    .with_offset(page * FOLLOWS_PER_PAGE as u32)
+                    .with_limit(FOLLOWS_PER_PAGE)
+                    .with_direction(Direction::Desc);
+            let follows = try!(self.client.channel_follows(self.channel.clone(), params));
+            for follow in follows.follows() {
+                if let Some(ref user) = *follow.user() {
+                    if self.store.contains(user.id()) {
+                        break 'pages;
+                    }
+                    new_follows.push((user.clone(), follow.created_at().clone()));
+                }
+            }
+            if baseline || follows.follows().len() < FOLLOWS_PER_PAGE as usize {
+                break;
+            }
+        }
+
+        let mut events = Vec::new();
+        for (user, followed_at) in new_follows.into_iter().rev() {
+            try!(self.store.insert(user.id()));
+            if !baseline {
+                events.push(FollowerEvent::NewFollower {
+                    user: user,
+                    followed_at: followed_at,
+                });
+            }
+        }
+        Ok(events)
+    }
+    /// Starts polling on a background thread that calls the callback with every event.
+    pub fn spawn<F: FnMut(FollowerEvent) + Send + 'static>(self, mut callback: F) -> TrackerHandle {
+        let (stop_sender, stop_receiver) = mpsc::channel();
+
+        let thread = thread::spawn(move || {
+            loop {
+                match self.poll() {
+                    Ok(events) => events.into_iter().for_each(&mut callback),
+                    Err(err) => callback(FollowerEvent::PollFailed(err)),
+                }
+                match stop_receiver.recv_timeout(self.interval) {
+                    Err(RecvTimeoutError::Timeout) => {},
+                    _ => break,
+                }
+            }
+        });
+
+        TrackerHandle {
+            stop_sender: stop_sender,
+            thread: thread,
+        }
+    }
+    /// Starts polling on a background thread that sends the events to the returned receiver.
+    ///
+    /// The thread keeps polling until the handle is stopped or dropped, even if the receiver is dropped.
+    pub fn spawn_with_receiver(self) -> (TrackerHandle, Receiver<FollowerEvent>) {
+        let (sender, receiver) = mpsc::channel();
+        let handle = self.spawn(move |event| {
+            let _ = sender.send(event);
+        });
+        (handle, receiver)
+    }
+}
+
+impl TrackerHandle {
+    /// Stops polling and waits until the background thread finished.
+    pub fn stop(self) {
+        let _ = self.stop_sender.send(());
+        let _ = self.thread.join();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use super::*;
+    use testing::MockTransport;
+    use TwitchClientBuilder;
+
+    fn create_tracker<S: FollowerStore + 'static>(store: S) -> FollowerTracker {
+        let client = TwitchClientBuilder::new("client_id")
+                .with_transport(MockTransport::with_fixtures())
+                .build()
+                .unwrap();
+        FollowerTracker::new(Arc::new(client), "test_channel")
+                .with_store(store)
+    }
+
+    #[test]
+    fn test_should_not_report_followers_of_first_poll() {
+        let store = Arc::new(MemoryFollowerStore::new());
+        let tracker = create_tracker(store.clone());
+        assert!(tracker.poll().unwrap().is_empty());
+        assert!(store.contains(UserId::from(21229404)));
+    }
+
+    #[test]
+    fn test_should_report_new_follower_once() {
+        let store = MemoryFollowerStore::new();
+        store.insert(UserId::from(1)).unwrap();
+        let tracker = create_tracker(store);
+
+        match tracker.poll().unwrap().as_slice() {
+            [FollowerEvent::NewFollower { ref user, .. }] => assert_eq!(user.id(), UserId::from(21229404)),
+            events => panic!("expecting new follower event, but got: {:?}", events),
+        }
+        assert!(tracker.poll().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_file_store_should_keep_followers() {
+        let path = env::temp_dir().join("twitch_client_test_file_store_should_keep_followers.txt");
+        let _ = fs::remove_file(&path);
+        {
+            let store = FileFollowerStore::open(&path).unwrap();
+            assert!(store.is_empty());
+            store.insert(UserId::from(21229404)).unwrap();
+        }
+        let store = FileFollowerStore::open(&path).unwrap();
+        assert!(store.contains(UserId::from(21229404)));
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,376 @@
+//! EventSub subscriptions and webhook notifications.
+//!
+//! Subscriptions are managed with the `HelixClient`, which requires an app access token
+//! (see `HelixClient::with_client_secret`).
+//! Twitch then posts the events to the callback url of the subscription.
+//! The notifications can be received with any web framework: verify the signature with `verify_signature`
+//! and deserialize the body with `parse_message`.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use twitch_client::helix::HelixClient;
+//! use twitch_client::helix::eventsub::CreateEventSubSubscriptionParams;
+//!
+//! let helix_client = HelixClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap()
+//!         .with_client_secret("<YOUR_TWITCH_CLIENT_SECRET>");
+//!
+//! let params = CreateEventSubSubscriptionParams::new("stream.online", "1",
+//!                                                    "https://example.com/webhooks/callback",
+//!                                                    "<YOUR_SECRET>")
+//!         .with_condition("broadcaster_user_id", "12826");
+//! let subscriptions = helix_client.create_eventsub_subscription(params).unwrap();
+//! println!("Subscription: {}", subscriptions.data()[0].id());
+//! ```
+
+use serde_json;
+
+use error::Result;
+use param::{IntoRequestBody, QueryParams};
+use signature;
+#[cfg(feature = "blocking")]
+use model::helix::Data;
+use model::helix::eventsub::{Subscription, StreamOnlineEvent, ChannelFollowEvent, ChannelSubscribeEvent};
+#[cfg(feature = "blocking")]
+use super::HelixClient;
+
+
+/// Header that contains the unique id of a webhook message.
+pub const MESSAGE_ID_HEADER: &'static str = "Twitch-Eventsub-Message-Id";
+/// Header that contains the time when the webhook message was sent.
+pub const MESSAGE_TIMESTAMP_HEADER: &'static str = "Twitch-Eventsub-Message-Timestamp";
+/// Header that contains the signature of the webhook message.
+pub const MESSAGE_SIGNATURE_HEADER: &'static str = "Twitch-Eventsub-Message-Signature";
+/// Header that contains the type of the webhook message, required by `parse_message`.
+pub const MESSAGE_TYPE_HEADER: &'static str = "Twitch-Eventsub-Message-Type";
+
+
+#[cfg(feature = "blocking")]
+impl HelixClient {
+
+    /// Create EventSub subscription.
+    ///
+    /// Subscribes the callback url to the events of the subscription type.
+    /// Twitch verifies the callback by sending a `WebhookMessage::Verification`,
+    /// which needs to be answered with the challenge.
+    /// Requires an app access token (see `HelixClient::with_client_secret`).
+    pub fn create_eventsub_subscription(&self, params: CreateEventSubSubscriptionParams)
+            -> Result<Data<Subscription>> {
+        let response = try!(self.http_client.post_unscoped_content_with_body("/eventsub/subscriptions", params));
+        let subscriptions: Data<Subscription> = try!(serde_json::from_str(&response));
+        Ok(subscriptions)
+    }
+
+    /// Get EventSub subscriptions.
+    ///
+    /// Returns the subscriptions of the client id, optionally filtered by status or type.
+    /// Requires an app access token (see `HelixClient::with_client_secret`).
+    pub fn get_eventsub_subscriptions(&self, params: GetEventSubSubscriptionsParams) -> Result<Data<Subscription>> {
+        let response = try!(self.http_client.get_content_with_params("/eventsub/subscriptions", params));
+        let subscriptions: Data<Subscription> = try!(serde_json::from_str(&response));
+        Ok(subscriptions)
+    }
+
+    /// Delete EventSub subscription.
+    ///
+    /// Requires an app access token (see `HelixClient::with_client_secret`).
+    pub fn delete_eventsub_subscription(&self, id: &str) -> Result<()> {
+        let params = SubscriptionIdParams::new(id);
+        try!(self.http_client.delete_unscoped_content_with_params("/eventsub/subscriptions", params));
+        Ok(())
+    }
+
+}
+
+
+/// Parameters for creating an EventSub subscription with webhook transport.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::helix::eventsub::CreateEventSubSubscriptionParams;
+///
+/// let _params = CreateEventSubSubscriptionParams::new("channel.follow", "1",
+///                                                     "https://example.com/webhooks/callback",
+///                                                     "s3cRe7s3cRe7")
+///         .with_condition("broadcaster_user_id", "12826");
+/// ```
+#[derive(Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct CreateEventSubSubscriptionParams {
+    #[serde(rename="type")]
+    subscription_type: String,
+    version: String,
+    condition: serde_json::Map<String, serde_json::Value>,
+    transport: WebhookTransportParams,
+}
+
+#[derive(Serialize, Debug, Clone, Eq, PartialEq)]
+struct WebhookTransportParams {
+    method: &'static str,
+    callback: String,
+    secret: String,
+}
+
+impl CreateEventSubSubscriptionParams {
+    /// Constructs a new instance.
+    ///
+    /// The secret (10 to 100 characters) is used by Twitch to sign the notifications (see `verify_signature`).
+    pub fn new(subscription_type: &str, version: &str, callback: &str, secret: &str)
+            -> CreateEventSubSubscriptionParams {
+        CreateEventSubSubscriptionParams {
+            subscription_type: subscription_type.to_owned(),
+            version: version.to_owned(),
+            condition: serde_json::Map::new(),
+            transport: WebhookTransportParams {
+                method: "webhook",
+                callback: callback.to_owned(),
+                secret: secret.to_owned(),
+            },
+        }
+    }
+    /// Adds a condition of the subscription type (e.g. `broadcaster_user_id`).
+    pub fn with_condition(mut self, key: &str, value: &str) -> CreateEventSubSubscriptionParams {
+        self.condition.insert(key.to_owned(), serde_json::Value::String(value.to_owned()));
+        self
+    }
+}
+
+impl IntoRequestBody for CreateEventSubSubscriptionParams {
+    fn into_request_body(self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+/// Parameters for the EventSub subscriptions.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::helix::eventsub::GetEventSubSubscriptionsParams;
+///
+/// let _default_params = GetEventSubSubscriptionsParams::default();
+/// let _custom_params = GetEventSubSubscriptionsParams::new()
+///         .with_status("enabled")
+///         .with_subscription_type("stream.online");
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct GetEventSubSubscriptionsParams {
+    status: Option<String>,
+    #[serde(rename="type")]
+    subscription_type: Option<String>,
+    after: Option<String>,
+}
+
+impl GetEventSubSubscriptionsParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for GetEventSubSubscriptionsParams::default() but preferred if custom parameters are set.
+    pub fn new() -> GetEventSubSubscriptionsParams {
+        GetEventSubSubscriptionsParams::default()
+    }
+    /// Only returns the subscriptions with the status (e.g. "enabled").
+    pub fn with_status(mut self, status: &str) -> GetEventSubSubscriptionsParams {
+        self.status = Some(status.to_owned());
+        self
+    }
+    /// Only returns the subscriptions of the type (e.g. "stream.online").
+    pub fn with_subscription_type(mut self, subscription_type: &str) -> GetEventSubSubscriptionsParams {
+        self.subscription_type = Some(subscription_type.to_owned());
+        self
+    }
+    /// Cursor for forward pagination, as returned by `Pagination::cursor()`.
+    pub fn with_after(mut self, after: &str) -> GetEventSubSubscriptionsParams {
+        self.after = Some(after.to_owned());
+        self
+    }
+}
+
+impl QueryParams for GetEventSubSubscriptionsParams {}
+
+/// Parameters for deleting an EventSub subscription.
+///
+/// Only used internally by `HelixClient::delete_eventsub_subscription`.
+#[cfg(feature = "blocking")]
+#[derive(Serialize, Debug, Clone, Hash, Eq, PartialEq)]
+struct SubscriptionIdParams {
+    id: String,
+}
+
+#[cfg(feature = "blocking")]
+impl SubscriptionIdParams {
+    fn new(id: &str) -> SubscriptionIdParams {
+        SubscriptionIdParams {
+            id: id.to_owned(),
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl QueryParams for SubscriptionIdParams {}
+
+
+/// Message that Twitch posted to the callback url of a subscription.
+#[derive(Debug, Clone)]
+pub enum WebhookMessage {
+    /// Verification of the callback url of a new subscription.
+    ///
+    /// The challenge needs to be returned as the (plain text) body of a 200 response.
+    Verification {
+        /// Challenge to respond with.
+        challenge: String,
+        /// Subscription that is verified.
+        subscription: Subscription,
+    },
+    /// Event of a subscription.
+    Notification {
+        /// Subscription of the event.
+        subscription: Subscription,
+        /// Event payload.
+        event: Event,
+    },
+    /// Revocation of a subscription, e.g. because the user revoked the authorization.
+    Revocation {
+        /// Revoked subscription with the reason as status.
+        subscription: Subscription,
+    },
+}
+
+/// Event payload of a notification.
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// Event of the `stream.online` subscription type.
+    StreamOnline(StreamOnlineEvent),
+    /// Event of the `channel.follow` subscription type.
+    ChannelFollow(ChannelFollowEvent),
+    /// Event of the `channel.subscribe` subscription type.
+    ChannelSubscribe(ChannelSubscribeEvent),
+    /// Event of a subscription type without a typed model.
+    Other(serde_json::Value),
+}
+
+#[derive(Deserialize)]
+struct WebhookBody {
+    subscription: Subscription,
+    challenge: Option<String>,
+    event: Option<serde_json::Value>,
+}
+
+/// Verifies the signature of a webhook message with the secret of the subscription.
+///
+/// The message id, timestamp and signature are the values of the `MESSAGE_ID_HEADER`,
+/// `MESSAGE_TIMESTAMP_HEADER` and `MESSAGE_SIGNATURE_HEADER` headers
+/// and the body is the raw request body as received.
+/// Messages with an invalid signature were not sent by Twitch and need to be rejected.
+pub fn verify_signature(secret: &str, message_id: &str, timestamp: &str, body: &[u8], signature: &str) -> bool {
+    let mut message = Vec::with_capacity(message_id.len() + timestamp.len() + body.len());
+    message.extend_from_slice(message_id.as_bytes());
+    message.extend_from_slice(timestamp.as_bytes());
+    message.extend_from_slice(body);
+    signature::verify_hmac_sha256(secret.as_bytes(), &message, signature)
+}
+
+/// Deserializes the body of a webhook message.
+///
+/// The message type is the value of the `MESSAGE_TYPE_HEADER` header.
+/// Unknown message types are treated like notifications.
+pub fn parse_message(message_type: &str, body: &str) -> Result<WebhookMessage> {
+    let webhook_body: WebhookBody = try!(serde_json::from_str(body));
+    let subscription = webhook_body.subscription;
+
+    let message = match (message_type, webhook_body.challenge) {
+        ("webhook_callback_verification", Some(challenge)) => WebhookMessage::Verification {
+            challenge: challenge,
+            subscription: subscription,
+        },
+        ("revocation", _) => WebhookMessage::Revocation {
+            subscription: subscription,
+        },
+        _ => {
+            let event = webhook_body.event.unwrap_or(serde_json::Value::Null);
+            let event = try!(parse_event(subscription.subscription_type(), event));
+            WebhookMessage::Notification {
+                subscription: subscription,
+                event: event,
+            }
+        },
+    };
+    Ok(message)
+}
+
+fn parse_event(subscription_type: &str, event: serde_json::Value) -> Result<Event> {
+    let event = match subscription_type {
+        "stream.online" => Event::StreamOnline(try!(serde_json::from_value(event))),
+        "channel.follow" => Event::ChannelFollow(try!(serde_json::from_value(event))),
+        "channel.subscribe" => Event::ChannelSubscribe(try!(serde_json::from_value(event))),
+        _ => Event::Other(event),
+    };
+    Ok(event)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUBSCRIPTION: &'static str = r#"{
+        "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+        "status": "enabled",
+        "type": "channel.follow",
+        "version": "1",
+        "cost": 1,
+        "condition": {"broadcaster_user_id": "12826"},
+        "transport": {"method": "webhook", "callback": "https://example.com/webhooks/callback"},
+        "created_at": "2019-11-16T10:11:12.123Z"
+    }"#;
+
+    #[test]
+    fn test_should_verify_signature() {
+        let signature = "sha256=c2429a54b8b20eb3b2112a71f557206550be7c528abd114deaf369068c2ab027";
+        let message_id = "e76c6bd4-55c9-4987-8304-da1588d8988b";
+        let timestamp = "2019-11-16T10:11:12.123Z";
+        let body = br#"{"hello":"world"}"#;
+        assert!(verify_signature("secret", message_id, timestamp, body, signature));
+        assert!(!verify_signature("secret", message_id, "2019-11-16T10:11:13.123Z", body, signature));
+    }
+
+    #[test]
+    fn test_should_parse_verification() {
+        let body = format!(r#"{{"challenge": "pogchamp-kappa-360noscope-vohiyo", "subscription": {}}}"#,
+                           SUBSCRIPTION);
+        match parse_message("webhook_callback_verification", &body).unwrap() {
+            WebhookMessage::Verification { challenge, .. } => {
+                assert_eq!(challenge, "pogchamp-kappa-360noscope-vohiyo");
+            },
+            message => panic!("expecting verification, but got: {:?}", message),
+        }
+    }
+
+    #[test]
+    fn test_should_parse_channel_follow_notification() {
+        let body = format!(r#"{{"subscription": {}, "event": {{
+            "user_id": "1234",
+            "user_login": "cool_user",
+            "user_name": "Cool_User",
+            "broadcaster_user_id": "12826",
+            "broadcaster_user_login": "twitch",
+            "broadcaster_user_name": "Twitch",
+            "followed_at": "2020-07-15T18:16:11.17106713Z"
+        }}}}"#, SUBSCRIPTION);
+        match parse_message("notification", &body).unwrap() {
+            WebhookMessage::Notification { event: Event::ChannelFollow(follow), .. } => {
+                assert_eq!(follow.user_login(), "cool_user");
+                assert_eq!(follow.broadcaster_user_id(), "12826");
+            },
+            message => panic!("expecting channel follow notification, but got: {:?}", message),
+        }
+    }
+
+    #[test]
+    fn test_create_params_should_serialize_webhook_transport() {
+        let params = CreateEventSubSubscriptionParams::new("stream.online", "1", "https://example.com", "s3cRe7s3cRe7")
+                .with_condition("broadcaster_user_id", "12826");
+        assert_eq!(params.into_request_body(),
+                   r#"{"type":"stream.online","version":"1","condition":{"broadcaster_user_id":"12826"},"#.to_owned()
+                   + r#""transport":{"method":"webhook","callback":"https://example.com","secret":"s3cRe7s3cRe7"}}"#);
+    }
+}
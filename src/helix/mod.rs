@@ -0,0 +1,357 @@
+//! Client for the new Twitch API (Helix).
+//!
+//! The new Twitch API is not a drop-in replacement for the Twitch API v3 used by `TwitchClient`:
+//! resources are identified by ids instead of names and the responses have a different format.
+//! Therefore it is exposed as a separate `HelixClient`.
+
+#[cfg(feature = "hyper-transport")]
+use hyper;
+#[cfg(feature = "reqwest")]
+use reqwest;
+#[cfg(feature = "blocking")]
+use serde_json;
+
+#[cfg(feature = "blocking")]
+use http::{TwitchHttpClient, Api};
+#[cfg(feature = "blocking")]
+use transport::{self, Transport, TransportConfig};
+#[cfg(feature = "blocking")]
+use error::Result;
+#[cfg(feature = "blocking")]
+use rate_limit::RateLimitStatus;
+#[cfg(feature = "blocking")]
+use circuit_breaker::CircuitBreakerPolicy;
+#[cfg(feature = "blocking")]
+use model::helix::Data;
+#[cfg(feature = "blocking")]
+use model::helix::clip::{Clip, CreatedClip};
+#[cfg(feature = "blocking")]
+use model::helix::follow::Follow;
+#[cfg(feature = "blocking")]
+use model::helix::video::Video;
+#[cfg(feature = "blocking")]
+use model::helix::marker::{UserMarkers, Marker};
+#[cfg(feature = "blocking")]
+use model::helix::tag::Tag;
+#[cfg(feature = "blocking")]
+use model::helix::bits::BitsLeaderboard;
+
+pub mod param;
+pub mod eventsub;
+pub mod webhooks;
+
+pub use self::param::*;
+
+
+/// Client for the [new Twitch API].
+///
+/// By using the Helix Client you agree to follow the
+/// [Twitch Developer Services Agreement] and the [Twitch Terms of Service].
+/// This library is in no way affiliated with, authorized, maintained, sponsored
+/// or endorsed by Twitch or any of its affiliates or subsidiaries
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::helix::*;
+///
+/// let helix_client = HelixClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap();
+///
+/// match helix_client.get_clips(GetClipsParams::for_broadcaster_id("67955580")) {
+///     Ok(clips) => println!("Clips: {}", clips.data().len()),
+///     Err(err) => println!("Failed to retrieve clips: {}", err),
+/// }
+/// ```
+///
+/// [new Twitch API]: https://dev.twitch.tv/docs/api
+/// [Twitch Developer Services Agreement]: https://www.twitch.tv/p/developer-agreement
+/// [Twitch Terms of Service]: https://help.twitch.tv/customer/portal/articles/735191-terms-of-service
+#[cfg(feature = "blocking")]
+pub struct HelixClient {
+    http_client: TwitchHttpClient,
+}
+
+#[cfg(feature = "blocking")]
+impl HelixClient {
+
+    /// Constructs a new client instance with the default transport (see `transport`).
+    ///
+    /// Instructions for obtaining a Twitch Client ID can be found at the [Twitch API Documentation].
+    ///
+    /// [Twitch API Documentation]: https://dev.twitch.tv/docs/authentication#registration
+    pub fn new<S: Into<String>>(client_id: S) -> Result<HelixClient> {
+        let http_client = try!(TwitchHttpClient::new(Api::Helix, client_id, &TransportConfig::default()));
+
+        let helix_client = HelixClient {
+            http_client: http_client,
+        };
+        Ok(helix_client)
+    }
+
+    /// Constructs a new client instance using the provided hyper client.
+    ///
+    /// Note that the provided hyper client needs to use a tls connection.
+    #[cfg(feature = "hyper-transport")]
+    pub fn with_hyper_client<S: Into<String>>(client_id: S, hyper_client: hyper::Client) -> HelixClient {
+        let transport = transport::HyperTransport::with_hyper_client(hyper_client);
+        HelixClient::with_transport(client_id, Box::new(transport))
+    }
+
+    /// Constructs a new client instance using the provided reqwest client.
+    #[cfg(feature = "reqwest")]
+    pub fn with_reqwest_client<S: Into<String>>(client_id: S, reqwest_client: reqwest::Client) -> HelixClient {
+        let transport = transport::ReqwestTransport::with_reqwest_client(reqwest_client);
+        HelixClient::with_transport(client_id, Box::new(transport))
+    }
+
+    fn with_transport<S: Into<String>>(client_id: S, transport: Box<Transport>) -> HelixClient {
+        let http_client = TwitchHttpClient::with_transport(Api::Helix, client_id, transport);
+
+        HelixClient {
+            http_client: http_client,
+        }
+    }
+
+    /// Sets the OAuth bearer token that is used to authenticate the requests.
+    ///
+    /// A token is required for requests that access user specific data or modify data (e.g. creating clips).
+    pub fn with_oauth_token<S: Into<String>>(mut self, oauth_token: S) -> HelixClient {
+        self.http_client.set_oauth_token(oauth_token);
+        self
+    }
+
+    /// Sets the `User-Agent` header of the requests (default: name and version of this crate).
+    pub fn with_user_agent<S: Into<String>>(mut self, user_agent: S) -> HelixClient {
+        self.http_client.set_user_agent(user_agent);
+        self
+    }
+
+    /// Sets the client secret that is used to request an app access token with the client credentials flow.
+    ///
+    /// The app access token is used if no OAuth token is set
+    /// and is required for the EventSub subscriptions (see `eventsub`).
+    pub fn with_client_secret<S: Into<String>>(mut self, client_secret: S) -> HelixClient {
+        self.http_client.set_client_secret(client_secret);
+        self
+    }
+
+    /// Sets whether requests should wait until the rate limit bucket is reset
+    /// if the last response reported that no requests are remaining, instead of letting Twitch reject them.
+    pub fn with_rate_limit_waiting(mut self, wait_on_rate_limit: bool) -> HelixClient {
+        self.http_client.set_wait_on_rate_limit(wait_on_rate_limit);
+        self
+    }
+
+    /// Sets the policy of the circuit breaker, which fails requests fast with `Error::CircuitOpen`
+    /// after consecutive server errors or io errors.
+    pub fn with_circuit_breaker(mut self, circuit_breaker_policy: CircuitBreakerPolicy) -> HelixClient {
+        self.http_client.set_circuit_breaker(circuit_breaker_policy);
+        self
+    }
+
+    /// Returns the state of the rate limit bucket as reported by the last response,
+    /// or `None` if no response was received yet.
+    pub fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.http_client.rate_limit_status()
+    }
+
+}
+
+
+#[cfg(feature = "blocking")]
+impl HelixClient {
+
+    /// Get clips.
+    ///
+    /// Returns clips of a broadcaster or game, or the clips with the requested ids.
+    pub fn get_clips(&self, params: GetClipsParams) -> Result<Data<Clip>> {
+        let response = try!(self.http_client.get_content_with_params("/clips", params));
+        let clips: Data<Clip> = try!(serde_json::from_str(&response));
+        Ok(clips)
+    }
+
+    /// Create clip.
+    ///
+    /// Creates a clip programmatically of the broadcaster's live stream,
+    /// optionally with the stream delay of the broadcaster.
+    /// Returns the id and edit url of the new clip, which is processed asynchronously by Twitch.
+    /// Requires an OAuth token with the `clips:edit` scope.
+    pub fn create_clip(&self, broadcaster_id: &str, has_delay: bool) -> Result<Data<CreatedClip>> {
+        let params = param::CreateClipParams::new(broadcaster_id)
+                .with_has_delay(has_delay);
+        let response = try!(self.http_client.post_content_with_params("/clips", params, "clips:edit"));
+        let created_clips: Data<CreatedClip> = try!(serde_json::from_str(&response));
+        Ok(created_clips)
+    }
+
+    /// Get users follows.
+    ///
+    /// Returns the follow relationships between two users,
+    /// the users followed by the `from_id` user or the users following the `to_id` user.
+    /// At least one of `from_id` or `to_id` is required.
+    /// The total count of the matching follows is contained in `Data::total()`
+    /// and the cursor for the next page in `Data::pagination()`.
+    pub fn get_users_follows(&self, from_id: Option<&str>, to_id: Option<&str>, pagination: PaginationParams)
+            -> Result<Data<Follow>> {
+        let params = param::UsersFollowsParams::new(from_id, to_id, pagination);
+        let response = try!(self.http_client.get_content_with_params("/users/follows", params));
+        let follows: Data<Follow> = try!(serde_json::from_str(&response));
+        Ok(follows)
+    }
+
+    /// Get videos.
+    ///
+    /// Returns the videos with the requested ids or the videos of a user or game,
+    /// which can be filtered and paginated.
+    pub fn get_videos(&self, params: GetVideosParams) -> Result<Data<Video>> {
+        let response = try!(self.http_client.get_content_with_params("/videos", params));
+        let videos: Data<Video> = try!(serde_json::from_str(&response));
+        Ok(videos)
+    }
+
+    /// Get stream markers.
+    ///
+    /// Returns the stream markers of the most recent video of a user or of a specific video.
+    /// Requires an OAuth token with the `user:read:broadcast` scope.
+    pub fn get_stream_markers(&self, params: GetStreamMarkersParams) -> Result<Data<UserMarkers>> {
+        let response = try!(self.http_client.get_scoped_content_with_params("/streams/markers", params,
+                                                                           "user:read:broadcast"));
+        let markers: Data<UserMarkers> = try!(serde_json::from_str(&response));
+        Ok(markers)
+    }
+
+    /// Create stream marker.
+    ///
+    /// Creates a marker at the current position of the user's live stream
+    /// with an optional description of up to 140 characters.
+    /// Returns the created marker.
+    /// Requires an OAuth token with the `user:edit:broadcast` scope.
+    pub fn create_stream_marker(&self, user_id: &str, description: Option<&str>) -> Result<Data<Marker>> {
+        let body = param::CreateStreamMarkerBody::new(user_id, description);
+        let response = try!(self.http_client.post_content_with_body("/streams/markers", body, "user:edit:broadcast"));
+        let markers: Data<Marker> = try!(serde_json::from_str(&response));
+        Ok(markers)
+    }
+
+    /// Get all stream tags.
+    ///
+    /// Returns all stream tags defined by Twitch or the requested ones.
+    pub fn get_all_stream_tags(&self, params: GetAllStreamTagsParams) -> Result<Data<Tag>> {
+        let response = try!(self.http_client.get_content_with_params("/tags/streams", params));
+        let tags: Data<Tag> = try!(serde_json::from_str(&response));
+        Ok(tags)
+    }
+
+    /// Get stream tags.
+    ///
+    /// Returns the tags of the broadcaster's stream.
+    pub fn get_stream_tags(&self, broadcaster_id: &str) -> Result<Data<Tag>> {
+        let params = param::BroadcasterParams::new(broadcaster_id);
+        let response = try!(self.http_client.get_content_with_params("/streams/tags", params));
+        let tags: Data<Tag> = try!(serde_json::from_str(&response));
+        Ok(tags)
+    }
+
+    /// Replace stream tags.
+    ///
+    /// Replaces all tags of the broadcaster's stream, except the automatic ones, with up to 5 tags.
+    /// An empty list removes all tags.
+    /// Requires an OAuth token with the `user:edit:broadcast` scope.
+    pub fn replace_stream_tags(&self, broadcaster_id: &str, tag_ids: Vec<String>) -> Result<()> {
+        let params = param::BroadcasterParams::new(broadcaster_id);
+        let body = param::ReplaceStreamTagsBody::new(tag_ids);
+        try!(self.http_client.put_content_with_params_and_body("/streams/tags", params, body, "user:edit:broadcast"));
+        Ok(())
+    }
+
+    /// Get bits leaderboard.
+    ///
+    /// Returns the ranked list of users that cheered the most bits in the channel of the token owner.
+    /// Requires an OAuth token of the broadcaster with the `bits:read` scope.
+    pub fn get_bits_leaderboard(&self, params: BitsLeaderboardParams) -> Result<BitsLeaderboard> {
+        let response = try!(self.http_client.get_scoped_content_with_params("/bits/leaderboard", params, "bits:read"));
+        let bits_leaderboard: BitsLeaderboard = try!(serde_json::from_str(&response));
+        Ok(bits_leaderboard)
+    }
+
+}
+
+
+
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use super::*;
+    use error;
+    use tests::read_test_client_id;
+
+    #[test]
+    fn test_get_clips_for_broadcaster_id() {
+        let client = create_test_helix_client();
+        let params = GetClipsParams::for_broadcaster_id("67955580")
+                .with_first(2);
+        let clips = client.get_clips(params).unwrap();
+        assert!(clips.data().len() <= 2, "clips.data().len() = {} <= 2", clips.data().len());
+    }
+
+    #[test]
+    fn test_create_clip_without_oauth_token() {
+        let client = create_test_helix_client();
+        match client.create_clip("67955580", false) {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "clips:edit"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_get_users_follows_to_id() {
+        let client = create_test_helix_client();
+        let pagination = PaginationParams::new()
+                .with_first(2);
+        let follows = client.get_users_follows(None, Some("23161357"), pagination).unwrap();
+        assert!(follows.total().unwrap_or(0) > 0, "expecting followers for the user");
+        assert_eq!(follows.data().len(), 2);
+    }
+
+    #[test]
+    fn test_get_videos_for_user_id() {
+        let client = create_test_helix_client();
+        let params = GetVideosParams::for_user_id("67955580")
+                .with_first(2)
+                .with_sort(VideoSort::Views);
+        let videos = client.get_videos(params).unwrap();
+        assert!(videos.data().len() <= 2, "videos.data().len() = {} <= 2", videos.data().len());
+    }
+
+    #[test]
+    fn test_get_all_stream_tags() {
+        let client = create_test_helix_client();
+        let params = GetAllStreamTagsParams::new()
+                .with_tag_id("621fb5bf-5498-4d8f-b4ac-db4d40d401bf");
+        let tags = client.get_all_stream_tags(params).unwrap();
+        assert_eq!(tags.data().len(), 1);
+    }
+
+    #[test]
+    fn test_replace_stream_tags_without_oauth_token() {
+        let client = create_test_helix_client();
+        match client.replace_stream_tags("67955580", Vec::new()) {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "user:edit:broadcast"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_get_bits_leaderboard_without_oauth_token() {
+        let client = create_test_helix_client();
+        match client.get_bits_leaderboard(BitsLeaderboardParams::default()) {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "bits:read"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+
+
+    fn create_test_helix_client() -> HelixClient {
+        HelixClient::new(read_test_client_id()).unwrap()
+    }
+}
@@ -0,0 +1,649 @@
+//! Parameters for `HelixClient` methods.
+
+use serde_json;
+
+use param::{IntoRequestBody, QueryParams};
+
+
+/// Parameters for the pagination of endpoints without other optional parameters.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::helix::param::PaginationParams;
+///
+/// let _default_params = PaginationParams::default();
+/// let _custom_params = PaginationParams::new()
+///         .with_first(100)
+///         .with_after("eyJiIjpudWxsLCJhIjoiIn0");
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct PaginationParams {
+    first: Option<u8>,
+    after: Option<String>,
+}
+
+impl PaginationParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for PaginationParams::default() but preferred if custom parameters are set.
+    pub fn new() -> PaginationParams {
+        PaginationParams::default()
+    }
+    /// Maximum number of objects to return.
+    ///
+    /// Twitch defaults to 20 if not set. Maximum is 100.
+    pub fn with_first(mut self, first: u8) -> PaginationParams {
+        self.first = Some(first);
+        self
+    }
+    /// Cursor for forward pagination, as returned by `Pagination::cursor()`.
+    pub fn with_after(mut self, after: &str) -> PaginationParams {
+        self.after = Some(after.to_owned());
+        self
+    }
+}
+
+impl QueryParams for PaginationParams {}
+
+/// Parameters for the clips.
+///
+/// Exactly one of broadcaster id, game id or clip ids is required,
+/// which is why the parameters can only be constructed with one of them.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::helix::param::GetClipsParams;
+///
+/// let _broadcaster_params = GetClipsParams::for_broadcaster_id("67955580")
+///         .with_first(5);
+/// let _game_params = GetClipsParams::for_game_id("488191")
+///         .with_started_at("2017-11-30T00:00:00Z")
+///         .with_ended_at("2017-12-07T00:00:00Z");
+/// let _ids_params = GetClipsParams::for_ids(vec!["AwkwardHelplessSalamanderSwiftRage".to_owned()]);
+/// ```
+#[derive(Serialize, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct GetClipsParams {
+    broadcaster_id: Option<String>,
+    game_id: Option<String>,
+    #[serde(rename="id")]
+    ids: Vec<String>,
+    first: Option<u8>,
+    after: Option<String>,
+    before: Option<String>,
+    started_at: Option<String>,
+    ended_at: Option<String>,
+}
+
+impl GetClipsParams {
+    fn empty() -> GetClipsParams {
+        GetClipsParams {
+            broadcaster_id: None,
+            game_id: None,
+            ids: Vec::new(),
+            first: None,
+            after: None,
+            before: None,
+            started_at: None,
+            ended_at: None,
+        }
+    }
+    /// Constructs a new instance to get the clips of a broadcaster.
+    pub fn for_broadcaster_id(broadcaster_id: &str) -> GetClipsParams {
+        let mut params = GetClipsParams::empty();
+        params.broadcaster_id = Some(broadcaster_id.to_owned());
+        params
+    }
+    /// Constructs a new instance to get the clips of a game.
+    pub fn for_game_id(game_id: &str) -> GetClipsParams {
+        let mut params = GetClipsParams::empty();
+        params.game_id = Some(game_id.to_owned());
+        params
+    }
+    /// Constructs a new instance to get specific clips.
+    ///
+    /// Twitch allows a maximum of 100 ids.
+    pub fn for_ids(ids: Vec<String>) -> GetClipsParams {
+        let mut params = GetClipsParams::empty();
+        params.ids = ids;
+        params
+    }
+    /// Maximum number of objects to return.
+    ///
+    /// Twitch defaults to 20 if not set. Maximum is 100.
+    pub fn with_first(mut self, first: u8) -> GetClipsParams {
+        self.first = Some(first);
+        self
+    }
+    /// Cursor for forward pagination, as returned by `Pagination::cursor()`.
+    pub fn with_after(mut self, after: &str) -> GetClipsParams {
+        self.after = Some(after.to_owned());
+        self
+    }
+    /// Cursor for backward pagination, as returned by `Pagination::cursor()`.
+    pub fn with_before(mut self, before: &str) -> GetClipsParams {
+        self.before = Some(before.to_owned());
+        self
+    }
+    /// Starting date of the clips in RFC 3339 format.
+    ///
+    /// Twitch defaults to the broadcaster's or game's first clip if not set.
+    pub fn with_started_at(mut self, started_at: &str) -> GetClipsParams {
+        self.started_at = Some(started_at.to_owned());
+        self
+    }
+    /// Ending date of the clips in RFC 3339 format.
+    ///
+    /// Twitch defaults to one week after `started_at` if not set.
+    pub fn with_ended_at(mut self, ended_at: &str) -> GetClipsParams {
+        self.ended_at = Some(ended_at.to_owned());
+        self
+    }
+}
+
+impl QueryParams for GetClipsParams {}
+
+/// `VideoPeriod` for `GetVideosParams` to only show videos created in a certain time period.
+#[derive(Serialize, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[serde(rename_all="lowercase")]
+pub enum VideoPeriod {
+    /// Show videos from all time.
+    All,
+    /// Show videos from the last day.
+    Day,
+    /// Show videos from the last week.
+    Week,
+    /// Show videos from the last month.
+    Month,
+}
+
+/// `VideoSort` for `GetVideosParams` to sort the videos.
+#[derive(Serialize, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[serde(rename_all="lowercase")]
+pub enum VideoSort {
+    /// Newest videos first.
+    Time,
+    /// Currently trending videos first.
+    Trending,
+    /// Most viewed videos first.
+    Views,
+}
+
+/// `VideoType` for `GetVideosParams` to only show videos of a certain type.
+#[derive(Serialize, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[serde(rename_all="lowercase")]
+pub enum VideoType {
+    /// Show all videos.
+    All,
+    /// Show only uploaded videos.
+    Upload,
+    /// Show only past broadcasts.
+    Archive,
+    /// Show only highlights.
+    Highlight,
+}
+
+/// Parameters for the videos.
+///
+/// Exactly one of video ids, user id or game id is required,
+/// which is why the parameters can only be constructed with one of them.
+/// The filters and the pagination can only be used with a user id or game id.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::helix::param::{GetVideosParams, VideoPeriod, VideoSort, VideoType};
+///
+/// let _ids_params = GetVideosParams::for_ids(vec!["234482848".to_owned()]);
+/// let _user_params = GetVideosParams::for_user_id("67955580")
+///         .with_first(10)
+///         .with_period(VideoPeriod::Month)
+///         .with_sort(VideoSort::Views)
+///         .with_video_type(VideoType::Archive)
+///         .with_language("en");
+/// ```
+#[derive(Serialize, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct GetVideosParams {
+    #[serde(rename="id")]
+    ids: Vec<String>,
+    user_id: Option<String>,
+    game_id: Option<String>,
+    first: Option<u8>,
+    after: Option<String>,
+    before: Option<String>,
+    language: Option<String>,
+    period: Option<VideoPeriod>,
+    sort: Option<VideoSort>,
+    #[serde(rename="type")]
+    video_type: Option<VideoType>,
+}
+
+impl GetVideosParams {
+    fn empty() -> GetVideosParams {
+        GetVideosParams {
+            ids: Vec::new(),
+            user_id: None,
+            game_id: None,
+            first: None,
+            after: None,
+            before: None,
+            language: None,
+            period: None,
+            sort: None,
+            video_type: None,
+        }
+    }
+    /// Constructs a new instance to get specific videos.
+    ///
+    /// Twitch allows a maximum of 100 ids.
+    pub fn for_ids(ids: Vec<String>) -> GetVideosParams {
+        let mut params = GetVideosParams::empty();
+        params.ids = ids;
+        params
+    }
+    /// Constructs a new instance to get the videos of a user.
+    pub fn for_user_id(user_id: &str) -> GetVideosParams {
+        let mut params = GetVideosParams::empty();
+        params.user_id = Some(user_id.to_owned());
+        params
+    }
+    /// Constructs a new instance to get the videos of a game.
+    pub fn for_game_id(game_id: &str) -> GetVideosParams {
+        let mut params = GetVideosParams::empty();
+        params.game_id = Some(game_id.to_owned());
+        params
+    }
+    /// Maximum number of objects to return.
+    ///
+    /// Twitch defaults to 20 if not set. Maximum is 100.
+    pub fn with_first(mut self, first: u8) -> GetVideosParams {
+        self.first = Some(first);
+        self
+    }
+    /// Cursor for forward pagination, as returned by `Pagination::cursor()`.
+    pub fn with_after(mut self, after: &str) -> GetVideosParams {
+        self.after = Some(after.to_owned());
+        self
+    }
+    /// Cursor for backward pagination, as returned by `Pagination::cursor()`.
+    pub fn with_before(mut self, before: &str) -> GetVideosParams {
+        self.before = Some(before.to_owned());
+        self
+    }
+    /// Only shows videos in a certain language.
+    ///
+    /// Twitch defaults to all languages if not set.
+    pub fn with_language(mut self, language: &str) -> GetVideosParams {
+        self.language = Some(language.to_owned());
+        self
+    }
+    /// Only shows videos created in a certain time period.
+    ///
+    /// Twitch defaults to `VideoPeriod::All` if not set.
+    pub fn with_period(mut self, period: VideoPeriod) -> GetVideosParams {
+        self.period = Some(period);
+        self
+    }
+    /// Sort order of the videos.
+    ///
+    /// Twitch defaults to `VideoSort::Time` if not set.
+    pub fn with_sort(mut self, sort: VideoSort) -> GetVideosParams {
+        self.sort = Some(sort);
+        self
+    }
+    /// Only shows videos of a certain type.
+    ///
+    /// Twitch defaults to `VideoType::All` if not set.
+    pub fn with_video_type(mut self, video_type: VideoType) -> GetVideosParams {
+        self.video_type = Some(video_type);
+        self
+    }
+}
+
+impl QueryParams for GetVideosParams {}
+
+/// Parameters for the stream markers.
+///
+/// Exactly one of user id or video id is required,
+/// which is why the parameters can only be constructed with one of them.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::helix::param::GetStreamMarkersParams;
+///
+/// let _user_params = GetStreamMarkersParams::for_user_id("123")
+///         .with_first(10);
+/// let _video_params = GetStreamMarkersParams::for_video_id("456");
+/// ```
+#[derive(Serialize, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct GetStreamMarkersParams {
+    user_id: Option<String>,
+    video_id: Option<String>,
+    first: Option<u8>,
+    after: Option<String>,
+    before: Option<String>,
+}
+
+impl GetStreamMarkersParams {
+    fn empty() -> GetStreamMarkersParams {
+        GetStreamMarkersParams {
+            user_id: None,
+            video_id: None,
+            first: None,
+            after: None,
+            before: None,
+        }
+    }
+    /// Constructs a new instance to get the markers of the most recent video of a user.
+    pub fn for_user_id(user_id: &str) -> GetStreamMarkersParams {
+        let mut params = GetStreamMarkersParams::empty();
+        params.user_id = Some(user_id.to_owned());
+        params
+    }
+    /// Constructs a new instance to get the markers of a video.
+    pub fn for_video_id(video_id: &str) -> GetStreamMarkersParams {
+        let mut params = GetStreamMarkersParams::empty();
+        params.video_id = Some(video_id.to_owned());
+        params
+    }
+    /// Maximum number of objects to return.
+    ///
+    /// Twitch defaults to 20 if not set. Maximum is 100.
+    pub fn with_first(mut self, first: u8) -> GetStreamMarkersParams {
+        self.first = Some(first);
+        self
+    }
+    /// Cursor for forward pagination, as returned by `Pagination::cursor()`.
+    pub fn with_after(mut self, after: &str) -> GetStreamMarkersParams {
+        self.after = Some(after.to_owned());
+        self
+    }
+    /// Cursor for backward pagination, as returned by `Pagination::cursor()`.
+    pub fn with_before(mut self, before: &str) -> GetStreamMarkersParams {
+        self.before = Some(before.to_owned());
+        self
+    }
+}
+
+impl QueryParams for GetStreamMarkersParams {}
+
+/// Parameters for all stream tags.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::helix::param::GetAllStreamTagsParams;
+///
+/// let _default_params = GetAllStreamTagsParams::default();
+/// let _custom_params = GetAllStreamTagsParams::new()
+///         .with_tag_id("621fb5bf-5498-4d8f-b4ac-db4d40d401bf")
+///         .with_first(1);
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct GetAllStreamTagsParams {
+    #[serde(rename="tag_id")]
+    tag_ids: Vec<String>,
+    first: Option<u8>,
+    after: Option<String>,
+}
+
+impl GetAllStreamTagsParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for GetAllStreamTagsParams::default() but preferred if custom parameters are set.
+    pub fn new() -> GetAllStreamTagsParams {
+        GetAllStreamTagsParams::default()
+    }
+    /// Only return a specific tag.
+    /// Can be called multiple times to specify a list of tags.
+    ///
+    /// Twitch defaults to all tags if not set. Maximum is 100 tags.
+    pub fn with_tag_id(mut self, tag_id: &str) -> GetAllStreamTagsParams {
+        self.tag_ids.push(tag_id.to_owned());
+        self
+    }
+    /// Maximum number of objects to return.
+    ///
+    /// Twitch defaults to 20 if not set. Maximum is 100.
+    pub fn with_first(mut self, first: u8) -> GetAllStreamTagsParams {
+        self.first = Some(first);
+        self
+    }
+    /// Cursor for forward pagination, as returned by `Pagination::cursor()`.
+    pub fn with_after(mut self, after: &str) -> GetAllStreamTagsParams {
+        self.after = Some(after.to_owned());
+        self
+    }
+}
+
+impl QueryParams for GetAllStreamTagsParams {}
+
+/// `LeaderboardPeriod` for `BitsLeaderboardParams` to aggregate the bits of a certain time period.
+#[derive(Serialize, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[serde(rename_all="lowercase")]
+pub enum LeaderboardPeriod {
+    /// Aggregate the bits of the day of `started_at`.
+    Day,
+    /// Aggregate the bits of the week of `started_at`.
+    Week,
+    /// Aggregate the bits of the month of `started_at`.
+    Month,
+    /// Aggregate the bits of the year of `started_at`.
+    Year,
+    /// Aggregate the bits of all time.
+    All,
+}
+
+/// Parameters for the bits leaderboard.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::helix::param::{BitsLeaderboardParams, LeaderboardPeriod};
+///
+/// let _default_params = BitsLeaderboardParams::default();
+/// let _custom_params = BitsLeaderboardParams::new()
+///         .with_count(5)
+///         .with_period(LeaderboardPeriod::Week)
+///         .with_started_at("2018-02-05T08:00:00Z");
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct BitsLeaderboardParams {
+    count: Option<u8>,
+    period: Option<LeaderboardPeriod>,
+    started_at: Option<String>,
+    user_id: Option<String>,
+}
+
+impl BitsLeaderboardParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for BitsLeaderboardParams::default() but preferred if custom parameters are set.
+    pub fn new() -> BitsLeaderboardParams {
+        BitsLeaderboardParams::default()
+    }
+    /// Maximum number of leaderboard entries to return.
+    ///
+    /// Twitch defaults to 10 if not set. Maximum is 100.
+    pub fn with_count(mut self, count: u8) -> BitsLeaderboardParams {
+        self.count = Some(count);
+        self
+    }
+    /// Time period over which the bits are aggregated.
+    ///
+    /// Twitch defaults to `LeaderboardPeriod::All` if not set.
+    pub fn with_period(mut self, period: LeaderboardPeriod) -> BitsLeaderboardParams {
+        self.period = Some(period);
+        self
+    }
+    /// Timestamp in RFC 3339 format within the time period.
+    /// Ignored for `LeaderboardPeriod::All`.
+    ///
+    /// Twitch defaults to the current time period if not set.
+    pub fn with_started_at(mut self, started_at: &str) -> BitsLeaderboardParams {
+        self.started_at = Some(started_at.to_owned());
+        self
+    }
+    /// Only return the leaderboard entry of a specific user (and the entries around it if `count` is set).
+    ///
+    /// Twitch defaults to the top of the leaderboard if not set.
+    pub fn with_user_id(mut self, user_id: &str) -> BitsLeaderboardParams {
+        self.user_id = Some(user_id.to_owned());
+        self
+    }
+}
+
+impl QueryParams for BitsLeaderboardParams {}
+
+/// Parameters for identifying a broadcaster.
+///
+/// Only used internally by the `HelixClient` stream tags methods.
+#[derive(Serialize, Debug, Clone, Hash, Eq, PartialEq)]
+pub(crate) struct BroadcasterParams {
+    broadcaster_id: String,
+}
+
+impl BroadcasterParams {
+    pub(crate) fn new(broadcaster_id: &str) -> BroadcasterParams {
+        BroadcasterParams {
+            broadcaster_id: broadcaster_id.to_owned(),
+        }
+    }
+}
+
+impl QueryParams for BroadcasterParams {}
+
+/// Request body for creating a stream marker.
+///
+/// Only used internally by `HelixClient::create_stream_marker`.
+#[derive(Serialize, Debug, Clone, Hash, Eq, PartialEq)]
+pub(crate) struct CreateStreamMarkerBody {
+    user_id: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    description: Option<String>,
+}
+
+impl CreateStreamMarkerBody {
+    pub(crate) fn new(user_id: &str, description: Option<&str>) -> CreateStreamMarkerBody {
+        CreateStreamMarkerBody {
+            user_id: user_id.to_owned(),
+            description: description.map(|description| description.to_owned()),
+        }
+    }
+}
+
+impl IntoRequestBody for CreateStreamMarkerBody {
+    fn into_request_body(self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+/// Request body for replacing the stream tags.
+///
+/// Only used internally by `HelixClient::replace_stream_tags`.
+#[derive(Serialize, Debug, Clone, Hash, Eq, PartialEq)]
+pub(crate) struct ReplaceStreamTagsBody {
+    tag_ids: Vec<String>,
+}
+
+impl ReplaceStreamTagsBody {
+    pub(crate) fn new(tag_ids: Vec<String>) -> ReplaceStreamTagsBody {
+        ReplaceStreamTagsBody {
+            tag_ids: tag_ids,
+        }
+    }
+}
+
+impl IntoRequestBody for ReplaceStreamTagsBody {
+    fn into_request_body(self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+/// Parameters for the follows between users.
+///
+/// Only used internally by `HelixClient::get_users_follows`.
+#[derive(Serialize, Debug, Clone, Hash, Eq, PartialEq)]
+pub(crate) struct UsersFollowsParams {
+    from_id: Option<String>,
+    to_id: Option<String>,
+    first: Option<u8>,
+    after: Option<String>,
+}
+
+impl UsersFollowsParams {
+    pub(crate) fn new(from_id: Option<&str>, to_id: Option<&str>, pagination: PaginationParams) -> UsersFollowsParams {
+        UsersFollowsParams {
+            from_id: from_id.map(|from_id| from_id.to_owned()),
+            to_id: to_id.map(|to_id| to_id.to_owned()),
+            first: pagination.first,
+            after: pagination.after,
+        }
+    }
+}
+
+impl QueryParams for UsersFollowsParams {}
+
+/// Parameters for creating a clip.
+///
+/// Only used internally by `HelixClient::create_clip`.
+#[derive(Serialize, Debug, Clone, Hash, Eq, PartialEq)]
+pub(crate) struct CreateClipParams {
+    broadcaster_id: String,
+    has_delay: Option<bool>,
+}
+
+impl CreateClipParams {
+    pub(crate) fn new(broadcaster_id: &str) -> CreateClipParams {
+        CreateClipParams {
+            broadcaster_id: broadcaster_id.to_owned(),
+            has_delay: None,
+        }
+    }
+    pub(crate) fn with_has_delay(mut self, has_delay: bool) -> CreateClipParams {
+        self.has_delay = Some(has_delay);
+        self
+    }
+}
+
+impl QueryParams for CreateClipParams {}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use param::{IntoQueryString, IntoRequestBody};
+
+    #[test]
+    fn test_ids_should_be_repeated_query_values() {
+        let params = GetClipsParams::for_ids(vec!["ClipA".to_owned(), "ClipB".to_owned()])
+                .with_first(2);
+        assert_eq!(params.into_query_string(), "?id=ClipA&id=ClipB&first=2");
+    }
+
+    #[test]
+    fn test_users_follows_should_append_pagination() {
+        let pagination = PaginationParams::new()
+                .with_first(100);
+        let params = UsersFollowsParams::new(None, Some("23161357"), pagination);
+        assert_eq!(params.into_query_string(), "?to_id=23161357&first=100");
+    }
+
+    #[test]
+    fn test_video_filters_should_set_correctly() {
+        let params = GetVideosParams::for_user_id("67955580")
+                .with_period(VideoPeriod::Week)
+                .with_sort(VideoSort::Trending)
+                .with_video_type(VideoType::Highlight);
+        assert_eq!(params.into_query_string(), "?user_id=67955580&period=week&sort=trending&type=highlight");
+    }
+
+    #[test]
+    fn test_stream_marker_body_should_skip_missing_description() {
+        let body = CreateStreamMarkerBody::new("123", None);
+        assert_eq!(body.into_request_body(), r#"{"user_id":"123"}"#);
+    }
+}
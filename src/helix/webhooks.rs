@@ -0,0 +1,189 @@
+//! Subscriptions to the Webhooks hub.
+//!
+//! The Webhooks hub pushes notifications about topics (e.g. stream changes or new followers)
+//! to a callback url instead of the application polling for them.
+//! Twitch verifies a subscription by sending a GET request with a `hub.challenge` query parameter
+//! to the callback url, which needs to be returned as the body of a 200 response.
+//! Notifications are posted with an `X-Hub-Signature` header if a secret was provided,
+//! which can be verified with `verify_signature`.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use twitch_client::helix::HelixClient;
+//! use twitch_client::helix::webhooks::Topic;
+//!
+//! let helix_client = HelixClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap();
+//!
+//! helix_client.subscribe_webhook(&Topic::stream_changed("12826"), "https://example.com/webhooks/streams",
+//!                                864000, Some("<YOUR_SECRET>")).unwrap();
+//! ```
+
+#[cfg(feature = "blocking")]
+use serde_json;
+
+#[cfg(feature = "blocking")]
+use error::Result;
+#[cfg(feature = "blocking")]
+use param::IntoRequestBody;
+use signature;
+#[cfg(feature = "blocking")]
+use super::HelixClient;
+
+
+/// Header that contains the signature of a notification.
+pub const SIGNATURE_HEADER: &'static str = "X-Hub-Signature";
+
+/// Topics are urls of the Helix endpoints, independent of the base url used by a `HelixClient`.
+const TOPIC_BASE_URL: &'static str = "https://api.twitch.tv/helix";
+
+
+#[cfg(feature = "blocking")]
+impl HelixClient {
+
+    /// Subscribe to webhook topic.
+    ///
+    /// Subscribes the callback url to the notifications of the topic for up to `lease_seconds` (maximum 864000).
+    /// The subscription is verified asynchronously by Twitch (see `webhooks`).
+    /// If a secret is provided, the notifications are signed with it.
+    pub fn subscribe_webhook(&self, topic: &Topic, callback_url: &str, lease_seconds: u32, secret: Option<&str>)
+            -> Result<()> {
+        let body = HubParams {
+            callback: callback_url.to_owned(),
+            mode: "subscribe",
+            topic: topic.url().to_owned(),
+            lease_seconds: Some(lease_seconds),
+            secret: secret.map(|secret| secret.to_owned()),
+        };
+        try!(self.http_client.post_unscoped_content_with_body("/webhooks/hub", body));
+        Ok(())
+    }
+
+    /// Unsubscribe from webhook topic.
+    ///
+    /// Cancels the subscription of the callback url to the notifications of the topic.
+    pub fn unsubscribe_webhook(&self, topic: &Topic, callback_url: &str) -> Result<()> {
+        let body = HubParams {
+            callback: callback_url.to_owned(),
+            mode: "unsubscribe",
+            topic: topic.url().to_owned(),
+            lease_seconds: None,
+            secret: None,
+        };
+        try!(self.http_client.post_unscoped_content_with_body("/webhooks/hub", body));
+        Ok(())
+    }
+
+}
+
+
+/// Topic of the Webhooks hub, identified by the url of the endpoint whose changes are notified.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::helix::webhooks::Topic;
+///
+/// let topic = Topic::user_follows_to("12826");
+/// assert_eq!(topic.url(), "https://api.twitch.tv/helix/users/follows?first=1&to_id=12826");
+/// ```
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Topic {
+    url: String,
+}
+
+impl Topic {
+    /// Notifies when the stream of the user goes online, changes or goes offline.
+    pub fn stream_changed(user_id: &str) -> Topic {
+        Topic::for_endpoint(&format!("/streams?user_id={}", user_id))
+    }
+    /// Notifies when the user follows someone.
+    pub fn user_follows_from(from_id: &str) -> Topic {
+        Topic::for_endpoint(&format!("/users/follows?first=1&from_id={}", from_id))
+    }
+    /// Notifies when someone follows the user.
+    pub fn user_follows_to(to_id: &str) -> Topic {
+        Topic::for_endpoint(&format!("/users/follows?first=1&to_id={}", to_id))
+    }
+    /// Topic with a custom url, for topics without a constructor.
+    pub fn from_url(url: &str) -> Topic {
+        Topic {
+            url: url.to_owned(),
+        }
+    }
+    /// Example value: "https://api.twitch.tv/helix/streams?user_id=12826"
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn for_endpoint(relative_url: &str) -> Topic {
+        Topic {
+            url: format!("{}{}", TOPIC_BASE_URL, relative_url),
+        }
+    }
+}
+
+/// Request body for the Webhooks hub.
+///
+/// Only used internally by `HelixClient::subscribe_webhook` and `HelixClient::unsubscribe_webhook`.
+#[cfg(feature = "blocking")]
+#[derive(Serialize, Debug, Clone, Hash, Eq, PartialEq)]
+struct HubParams {
+    #[serde(rename="hub.callback")]
+    callback: String,
+    #[serde(rename="hub.mode")]
+    mode: &'static str,
+    #[serde(rename="hub.topic")]
+    topic: String,
+    #[serde(rename="hub.lease_seconds", skip_serializing_if="Option::is_none")]
+    lease_seconds: Option<u32>,
+    #[serde(rename="hub.secret", skip_serializing_if="Option::is_none")]
+    secret: Option<String>,
+}
+
+#[cfg(feature = "blocking")]
+impl IntoRequestBody for HubParams {
+    fn into_request_body(self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+/// Verifies the `X-Hub-Signature` header of a notification with the secret of the subscription.
+///
+/// The body is the raw request body as received.
+/// Notifications with an invalid signature were not sent by Twitch and need to be rejected.
+pub fn verify_signature(secret: &str, body: &[u8], signature: &str) -> bool {
+    signature::verify_hmac_sha256(secret.as_bytes(), body, signature)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_changed_topic() {
+        assert_eq!(Topic::stream_changed("12826").url(), "https://api.twitch.tv/helix/streams?user_id=12826");
+    }
+
+    #[test]
+    fn test_should_verify_signature() {
+        let signature = "sha256=1d4e840702bf2c47e586d78cc52efdf26aa53fb52949666e772a32ff38070197";
+        assert!(verify_signature("secret", br#"{"data":[]}"#, signature));
+        assert!(!verify_signature("secret", br#"{"data":[{}]}"#, signature));
+    }
+
+    #[test]
+    fn test_hub_params_should_serialize_hub_fields() {
+        let params = HubParams {
+            callback: "https://example.com".to_owned(),
+            mode: "unsubscribe",
+            topic: Topic::user_follows_from("1").url().to_owned(),
+            lease_seconds: None,
+            secret: None,
+        };
+        assert_eq!(params.into_request_body(),
+                   r#"{"hub.callback":"https://example.com","hub.mode":"unsubscribe","#.to_owned()
+                   + r#""hub.topic":"https://api.twitch.tv/helix/users/follows?first=1&from_id=1"}"#);
+    }
+}
@@ -1,93 +1,747 @@
-use std::io::Read;
-use hyper;
-use hyper::net::HttpsConnector;
-use hyper::Url;
-use hyper::header::{Headers, Accept, qitem};
-use hyper::mime::{Mime, TopLevel, SubLevel};
-use hyper::status::StatusClass;
-use hyper_native_tls::NativeTlsClient;
+use std::io::{Cursor, Read};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use url::Url;
+use serde_json;
 
 use error::{Result, Error};
+use model::auth::AccessToken;
+use param::{QueryParams, RequestOptions};
+use transport::{self, Transport, TransportConfig, Method, Request, Response, StreamingResponse};
+use transport::{KRAKEN_BASE_URL, DEFAULT_USER_AGENT};
+use retry::RetryPolicy;
+use circuit_breaker::{CircuitBreaker, CircuitBreakerPolicy, CircuitState};
+use credentials::{Credentials, CredentialsProvider};
+use single_flight::SingleFlight;
+use rate_limit::{RateLimitStatus, Throttle};
+use response_meta::{self, ResponseMeta};
+use cache::ResponseCache;
+use metrics::ClientMetrics;
+use ApiVersion;
 
 
-header! { (ClientId, "Client-ID") => [String] }
+const HELIX_BASE_URL: &'static str = "https://api.twitch.tv/helix";
+const AUTH_BASE_URL: &'static str = "https://id.twitch.tv/oauth2";
 
-const BASE_URL: &'static str = "https://api.twitch.tv/kraken";
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Api {
+    Kraken(ApiVersion),
+    Helix,
+    Auth,
+}
+
+impl Api {
+    pub fn base_url(&self) -> &'static str {
+        match *self {
+            Api::Kraken(_) => KRAKEN_BASE_URL,
+            Api::Helix => HELIX_BASE_URL,
+            Api::Auth => AUTH_BASE_URL,
+        }
+    }
 
-pub trait IntoQueryString {
-    fn into_query_string(self) -> String;
+    fn authorization_scheme(&self) -> &'static str {
+        match *self {
+            Api::Kraken(_) | Api::Auth => "OAuth",
+            Api::Helix => "Bearer",
+        }
+    }
 }
 
+
+pub(crate) use param::{IntoQueryString, IntoRequestBody};
+
+/// Http client for one of the Twitch APIs.
+///
+/// Clones share the transport, rate limit status, app access token, response cache and metrics,
+/// so that a clone with different `RequestOptions` is cheap.
+#[derive(Clone)]
 pub struct TwitchHttpClient {
+    api: Api,
+    base_url: Option<String>,
     client_id: String,
-    hyper_client: hyper::Client,
+    user_agent: String,
+    compression: bool,
+    oauth_token: Option<String>,
+    app_credentials: Option<Arc<AppCredentials>>,
+    credentials_provider: Option<Arc<CredentialsProvider>>,
+    retry_policy: Option<RetryPolicy>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    wait_on_rate_limit: bool,
+    rate_limit_status: Arc<Mutex<Option<RateLimitStatus>>>,
+    throttle: Option<Arc<Throttle>>,
+    response_cache: Option<Arc<CacheSettings>>,
+    single_flight: Option<Arc<SingleFlight>>,
+    metrics: Option<Arc<ClientMetrics>>,
+    transport: Arc<Transport>,
+    options: RequestOptions,
 }
 
-impl TwitchHttpClient {
+struct AppCredentials {
+    client_secret: String,
+    app_access_token: Mutex<Option<String>>,
+}
 
-    pub fn new<S: Into<String>>(client_id: S) -> Result<TwitchHttpClient> {
-        let ssl = try!(NativeTlsClient::new());
-        let connector = HttpsConnector::new(ssl);
-        let hyper_client = hyper::Client::with_connector(connector);
+struct CacheSettings {
+    cache: Box<ResponseCache>,
+    ttl: Duration,
+}
 
-        let twitch_http_client = TwitchHttpClient {
-            client_id: client_id.into(),
-            hyper_client: hyper_client,
-        };
-        Ok(twitch_http_client)
+#[derive(Serialize)]
+struct ClientCredentialsParams<'a> {
+    client_id: &'a str,
+    client_secret: &'a str,
+    grant_type: &'static str,
+}
+
+impl<'a> QueryParams for ClientCredentialsParams<'a> {}
+
+impl TwitchHttpClient {
+
+    pub fn new<S: Into<String>>(api: Api, client_id: S, transport_config: &TransportConfig)
+            -> Result<TwitchHttpClient> {
+        let transport = try!(transport::default_transport(transport_config));
+        Ok(TwitchHttpClient::with_transport(api, client_id, transport))
     }
 
-    pub fn with_hyper_client<S: Into<String>>(client_id: S, hyper_client: hyper::Client) -> TwitchHttpClient {
+    pub fn with_transport<S: Into<String>>(api: Api, client_id: S, transport: Box<Transport>) -> TwitchHttpClient {
         TwitchHttpClient {
+            api: api,
+            base_url: None,
             client_id: client_id.into(),
-            hyper_client: hyper_client,
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+            compression: true,
+            oauth_token: None,
+            app_credentials: None,
+            credentials_provider: None,
+            retry_policy: None,
+            circuit_breaker: None,
+            wait_on_rate_limit: false,
+            rate_limit_status: Arc::new(Mutex::new(None)),
+            throttle: None,
+            response_cache: None,
+            single_flight: None,
+            metrics: None,
+            transport: Arc::from(transport),
+            options: RequestOptions::default(),
         }
     }
 
+    pub fn set_oauth_token<S: Into<String>>(&mut self, oauth_token: S) {
+        self.oauth_token = Some(oauth_token.into());
+    }
+
+    pub fn set_credentials_provider(&mut self, credentials_provider: Box<CredentialsProvider>) {
+        self.credentials_provider = Some(Arc::from(credentials_provider));
+    }
+
+    pub fn set_user_agent<S: Into<String>>(&mut self, user_agent: S) {
+        self.user_agent = user_agent.into();
+    }
+
+    pub fn set_compression(&mut self, compression: bool) {
+        self.compression = compression;
+    }
+
+    /// Sets the base url override, failing with `Error::InvalidUrl` if it is no absolute http(s) url.
+    pub fn set_base_url<S: Into<String>>(&mut self, base_url: S) -> Result<()> {
+        self.base_url = Some(try!(transport::normalize_base_url(base_url.into())));
+        Ok(())
+    }
+
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = Some(retry_policy);
+    }
+
+    pub fn set_circuit_breaker(&mut self, circuit_breaker_policy: CircuitBreakerPolicy) {
+        self.circuit_breaker = Some(Arc::new(CircuitBreaker::new(circuit_breaker_policy)));
+    }
+
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.circuit_breaker.as_ref().map(|circuit_breaker| circuit_breaker.state())
+    }
+
+    pub fn set_wait_on_rate_limit(&mut self, wait_on_rate_limit: bool) {
+        self.wait_on_rate_limit = wait_on_rate_limit;
+    }
+
+    pub fn set_max_requests_per_minute(&mut self, max_requests_per_minute: u32) {
+        self.throttle = Some(Arc::new(Throttle::new(max_requests_per_minute)));
+    }
+
+    pub fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        *self.rate_limit_status.lock().unwrap()
+    }
+
+    pub fn set_client_secret<S: Into<String>>(&mut self, client_secret: S) {
+        self.app_credentials = Some(Arc::new(AppCredentials {
+            client_secret: client_secret.into(),
+            app_access_token: Mutex::new(None),
+        }));
+    }
+
+    pub fn set_response_cache(&mut self, cache: Box<ResponseCache>, ttl: Duration) {
+        self.response_cache = Some(Arc::new(CacheSettings {
+            cache: cache,
+            ttl: ttl,
+        }));
+    }
+
+    pub fn set_request_coalescing(&mut self, request_coalescing: bool) {
+        self.single_flight = if request_coalescing { Some(Arc::new(SingleFlight::new())) } else { None };
+    }
+
+    pub fn set_metrics(&mut self, metrics: Box<ClientMetrics>) {
+        self.metrics = Some(Arc::from(metrics));
+    }
+
+    pub fn set_api(&mut self, api: Api) {
+        self.api = api;
+    }
+
+    pub fn set_request_options(&mut self, options: RequestOptions) {
+        self.options = options;
+    }
+
     pub fn get_content(&self, relative_url: &str) -> Result<String> {
-        let url_string = self.create_url_string(&relative_url);
-        let url = Url::parse(&url_string).unwrap();
+        let url = try!(self.create_url(relative_url));
         self.get_content_from_url(url)
     }
 
     pub fn get_content_with_params<Q: IntoQueryString>(&self, relative_url: &str, params: Q) -> Result<String> {
-        let mut url_string = self.create_url_string(&relative_url);
-        url_string.push_str(&params.into_query_string());
-        let url = Url::parse(&url_string).unwrap();
+        let url = try!(self.create_url_with_params(relative_url, params));
         self.get_content_from_url(url)
     }
 
+    /// Gets the content as reader of the response body, which avoids buffering large responses in memory.
+    ///
+    /// The content is buffered anyway if a response cache is set or requests are coalesced.
+    pub fn get_content_reader_with_params<Q: IntoQueryString>(&self, relative_url: &str, params: Q)
+            -> Result<Box<Read + Send>> {
+        let url = try!(self.create_url_with_params(relative_url, params));
+        if self.response_cache.is_some() || self.single_flight.is_some() {
+            let content = try!(self.get_content_from_url(url));
+            return Ok(Box::new(Cursor::new(content.into_bytes())));
+        }
+        self.retry(Method::Get, || self.send_streaming_request_attempt(&url))
+    }
+
+    pub fn get_scoped_content(&self, relative_url: &str, required_scope: &'static str) -> Result<String> {
+        let url = try!(self.create_url(relative_url));
+        self.send_request(Method::Get, url, Some(required_scope))
+    }
+
+    pub fn get_scoped_content_with_params<Q: IntoQueryString>(&self, relative_url: &str, params: Q,
+                                                              required_scope: &'static str) -> Result<String> {
+        let url = try!(self.create_url_with_params(relative_url, params));
+        self.send_request(Method::Get, url, Some(required_scope))
+    }
+
+    pub fn get_optional_content(&self, relative_url: &str, required_scope: Option<&'static str>) -> Result<Option<String>> {
+        let url = try!(self.create_url(relative_url));
+        match self.send_request(Method::Get, url, required_scope) {
+            Ok(content) => Ok(Some(content)),
+            Err(Error::NotFound { .. }) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn put_content(&self, relative_url: &str, required_scope: &'static str) -> Result<String> {
+        let url = try!(self.create_url(relative_url));
+        self.send_request(Method::Put, url, Some(required_scope))
+    }
+
+    pub fn put_content_with_params<Q: IntoQueryString>(&self, relative_url: &str, params: Q,
+                                                       required_scope: &'static str) -> Result<String> {
+        let url = try!(self.create_url_with_params(relative_url, params));
+        self.send_request(Method::Put, url, Some(required_scope))
+    }
+
+    pub fn put_content_with_body<B: IntoRequestBody>(&self, relative_url: &str, body: B,
+                                                      required_scope: &'static str) -> Result<String> {
+        let url = try!(self.create_url(relative_url));
+        self.send_request_with_body(Method::Put, url, Some(body.into_request_body()), Some(required_scope))
+    }
+
+    pub fn post_unscoped_content_with_params<Q: IntoQueryString>(&self, relative_url: &str, params: Q) -> Result<String> {
+        let url = try!(self.create_url_with_params(relative_url, params));
+        self.send_request(Method::Post, url, None)
+    }
+
+    pub fn post_content_with_params<Q: IntoQueryString>(&self, relative_url: &str, params: Q,
+                                                        required_scope: &'static str) -> Result<String> {
+        let url = try!(self.create_url_with_params(relative_url, params));
+        self.send_request(Method::Post, url, Some(required_scope))
+    }
+
+    pub fn post_unscoped_content_with_body<B: IntoRequestBody>(&self, relative_url: &str, body: B) -> Result<String> {
+        let url = try!(self.create_url(relative_url));
+        self.send_request_with_body(Method::Post, url, Some(body.into_request_body()), None)
+    }
+
+    pub fn put_content_with_params_and_body<Q: IntoQueryString, B: IntoRequestBody>(&self, relative_url: &str,
+                                                                                  params: Q, body: B,
+                                                                                  required_scope: &'static str)
+                                                                                  -> Result<String> {
+        let url = try!(self.create_url_with_params(relative_url, params));
+        self.send_request_with_body(Method::Put, url, Some(body.into_request_body()), Some(required_scope))
+    }
+
+    pub fn post_content_with_body<B: IntoRequestBody>(&self, relative_url: &str, body: B,
+                                                       required_scope: &'static str) -> Result<String> {
+        let url = try!(self.create_url(relative_url));
+        self.send_request_with_body(Method::Post, url, Some(body.into_request_body()), Some(required_scope))
+    }
+
+    pub fn post_content_with_params_and_body<Q: IntoQueryString, B: IntoRequestBody>(&self, relative_url: &str,
+                                                                                   params: Q, body: B,
+                                                                                   required_scope: &'static str)
+                                                                                   -> Result<String> {
+        let url = try!(self.create_url_with_params(relative_url, params));
+        self.send_request_with_body(Method::Post, url, Some(body.into_request_body()), Some(required_scope))
+    }
+
+    pub fn delete_content_with_params<Q: IntoQueryString>(&self, relative_url: &str, params: Q,
+                                                          required_scope: &'static str) -> Result<String> {
+        let url = try!(self.create_url_with_params(relative_url, params));
+        self.send_request(Method::Delete, url, Some(required_scope))
+    }
+
+    pub fn delete_unscoped_content_with_params<Q: IntoQueryString>(&self, relative_url: &str, params: Q)
+            -> Result<String> {
+        let url = try!(self.create_url_with_params(relative_url, params));
+        self.send_request(Method::Delete, url, None)
+    }
+
+    pub fn delete_content(&self, relative_url: &str, required_scope: &'static str) -> Result<String> {
+        let url = try!(self.create_url(relative_url));
+        self.send_request(Method::Delete, url, Some(required_scope))
+    }
+
     pub fn create_url_string(&self, relative_url: &str) -> String {
-        let mut url_string = String::from(BASE_URL);
+        let mut url_string = match self.base_url {
+            Some(ref base_url) => base_url.clone(),
+            None => String::from(self.api.base_url()),
+        };
         url_string.push_str(relative_url);
         url_string
     }
 
+    /// Gets the content of an absolute url that was linked by a previous response.
+    ///
+    /// Links to the api base url are redirected to the base url override (if set).
+    pub fn get_content_from_link(&self, link: &str) -> Result<String> {
+        let link = match self.base_url {
+            Some(ref base_url) if link.starts_with(self.api.base_url()) => {
+                format!("{}{}", base_url, &link[self.api.base_url().len()..])
+            },
+            _ => link.to_owned(),
+        };
+        let url = try!(parse_url(link));
+        self.get_content_from_url(url)
+    }
+
+    fn create_url(&self, relative_url: &str) -> Result<Url> {
+        let url_string = self.create_url_string(relative_url);
+        parse_url(url_string)
+    }
+
+    fn create_url_with_params<Q: IntoQueryString>(&self, relative_url: &str, params: Q) -> Result<Url> {
+        let mut url_string = self.create_url_string(relative_url);
+        url_string.push_str(&params.into_query_string());
+        parse_url(url_string)
+    }
+
     fn get_content_from_url(&self, url: Url) -> Result<String> {
-        let headers = self.create_default_headers();
-        let request = self.hyper_client.get(url.clone()).headers(headers);
-        let mut response = try!(request.send());
-
-        match response.status.class() {
-            StatusClass::Success => {
-                let mut response_body = String::new();
-                try!(response.read_to_string(&mut response_body));
-                Ok(response_body)
+        self.send_request(Method::Get, url, None)
+    }
+
+    fn send_request(&self, method: Method, url: Url, required_scope: Option<&'static str>) -> Result<String> {
+        self.send_request_with_body(method, url, None, required_scope)
+    }
+
+    fn send_request_with_body(&self, method: Method, url: Url, body: Option<String>,
+                              required_scope: Option<&'static str>) -> Result<String> {
+        if method != Method::Get || required_scope.is_some()
+                || (self.response_cache.is_none() && self.single_flight.is_none()) {
+            return self.send_request_with_retries(method, url, body, required_scope);
+        }
+        // Responses differ between api versions, so these must not share cache entries.
+        let cache_key = match self.api {
+            Api::Kraken(api_version) => format!("{} {}", api_version.to_media_type_version(), url.as_str()),
+            _ => url.as_str().to_owned(),
+        };
+        if let Some(ref cache_settings) = self.response_cache {
+            if let Some(content) = cache_settings.cache.get(&cache_key) {
+                return Ok(content);
+            }
+        }
+        let result = match self.single_flight {
+            Some(ref single_flight) => single_flight.execute(&cache_key, || {
+                self.send_request_with_retries(method, url.clone(), body.clone(), required_scope)
+            }),
+            None => self.send_request_with_retries(method, url, body, required_scope),
+        };
+        let content = match (result, &self.response_cache) {
+            (Ok(content), _) => content,
+            // Fall back to an expired response if Twitch is unavailable.
+            (Err(error), &Some(ref cache_settings)) if is_unavailable(&error) => {
+                match cache_settings.cache.get_stale(&cache_key) {
+                    Some(content) => return Ok(content),
+                    None => return Err(error),
+                }
+            },
+            (Err(error), _) => return Err(error),
+        };
+        if let Some(ref cache_settings) = self.response_cache {
+            cache_settings.cache.put(&cache_key, content.clone(), cache_settings.ttl);
+        }
+        Ok(content)
+    }
+
+    fn send_request_with_retries(&self, method: Method, url: Url, body: Option<String>,
+                                 required_scope: Option<&'static str>) -> Result<String> {
+        self.retry(method, || self.send_request_attempt(method, &url, body.clone(), required_scope))
+    }
+
+    fn retry<T, F: Fn() -> Result<T>>(&self, method: Method, send_attempt: F) -> Result<T> {
+        let mut attempt = 1;
+        loop {
+            let result = self.send_attempt_through_circuit_breaker(&send_attempt);
+            let retry_delay = match (&result, &self.retry_policy) {
+                (&Err(Error::RateLimited { retry_after }), &Some(ref retry_policy))
+                        if attempt < retry_policy.max_attempts() => {
+                    retry_after
+                },
+                (&Err(ref error), &Some(ref retry_policy)) if method == Method::Get
+                        && attempt < retry_policy.max_attempts() && error.is_retryable() => {
+                    retry_policy.delay(attempt)
+                },
+                _ => return result,
+            };
+            thread::sleep(retry_delay);
+            attempt += 1;
+        }
+    }
+
+    fn send_attempt_through_circuit_breaker<T, F: Fn() -> Result<T>>(&self, send_attempt: &F) -> Result<T> {
+        match self.circuit_breaker {
+            Some(ref circuit_breaker) => {
+                try!(circuit_breaker.before_request());
+                let result = send_attempt();
+                circuit_breaker.after_request(&result);
+                result
+            },
+            None => send_attempt(),
+        }
+    }
+
+    fn send_request_attempt(&self, method: Method, url: &Url, body: Option<String>,
+                            required_scope: Option<&'static str>) -> Result<String> {
+        if let Some(scope) = required_scope {
+            if self.oauth_token.is_none() && self.credentials_provider.is_none() {
+                return Err(Error::Unauthorized(scope));
+            }
+        }
+
+        let credentials = try!(self.credentials());
+        if let (Some(scope), None) = (required_scope, credentials.oauth_token()) {
+            return Err(Error::Unauthorized(scope));
+        }
+        let response = try!(self.send_transport_request(method, url, body.clone(), &credentials));
+        let response = try!(self.check_response(response, method, url, body, required_scope));
+        Ok(response.into_body())
+    }
+
+    /// Sends a GET request without a scope and returns the unread body of a successful response.
+    fn send_streaming_request_attempt(&self, url: &Url) -> Result<Box<Read + Send>> {
+        let credentials = try!(self.credentials());
+        let request = self.create_request(Method::Get, url, None, &credentials);
+
+        self.wait_before_request();
+        log_request(Method::Get, url);
+        let started_at = Instant::now();
+        let result = self.transport.send_streaming(request);
+        let latency = started_at.elapsed();
+        log_streaming_response(Method::Get, url, &result, latency);
+        let status_and_headers = result.as_ref().ok().map(|response| (response.status(), response.headers()));
+        self.record_response(url, status_and_headers, latency);
+
+        let response = try!(result);
+        if response.is_success() {
+            return Ok(response.into_body());
+        }
+        let response = try!(response.into_response());
+        let response = try!(self.check_response(response, Method::Get, url, None, None));
+        Ok(Box::new(Cursor::new(response.into_body().into_bytes())))
+    }
+
+    /// Retries once with a new app access token if Twitch rejected the app access token
+    /// and maps unsuccessful responses to errors.
+    fn check_response(&self, mut response: Response, method: Method, url: &Url, body: Option<String>,
+                      required_scope: Option<&'static str>) -> Result<Response> {
+        if response.status() == 401 && self.is_using_app_access_token() && is_invalid_token_message(response.body()) {
+            let app_access_token = try!(self.refresh_app_access_token());
+            let credentials = Credentials::new(self.client_id.as_str()).with_oauth_token(app_access_token);
+            response = try!(self.send_transport_request(method, url, body, &credentials));
+        }
+
+        if response.is_success() {
+            return Ok(response);
+        }
+        match (response.status(), required_scope) {
+            (401, Some(scope)) | (403, Some(scope)) => Err(Error::Unauthorized(scope)),
+            (429, _) => Err(Error::RateLimited { retry_after: retry_after(&response) }),
+            _ => Err(Error::from_response(response)),
+        }
+    }
+
+    fn send_transport_request(&self, method: Method, url: &Url, body: Option<String>, credentials: &Credentials)
+            -> Result<Response> {
+        let request = self.create_request(method, url, body, credentials);
+
+        self.wait_before_request();
+        log_request(method, url);
+        let started_at = Instant::now();
+        let result = self.transport.send(request);
+        let latency = started_at.elapsed();
+        log_response(method, url, &result, latency);
+        let status_and_headers = result.as_ref().ok().map(|response| (response.status(), response.headers()));
+        self.record_response(url, status_and_headers, latency);
+        result
+    }
+
+    fn create_request(&self, method: Method, url: &Url, body: Option<String>, credentials: &Credentials)
+            -> Request {
+        let mut headers = self.create_default_headers(credentials);
+        if body.is_some() {
+            headers.push(("Content-Type".to_owned(), "application/json".to_owned()));
+        }
+        let mut request = Request::new(method, url.as_str().to_owned(), headers, body);
+        request.set_timeout(self.options.timeout());
+        request
+    }
+
+    /// Waits for the throttle and, if enabled, until the exhausted rate limit bucket is reset.
+    fn wait_before_request(&self) {
+        if let Some(ref throttle) = self.throttle {
+            throttle.acquire();
+        }
+        if self.wait_on_rate_limit {
+            if let Some(rate_limit_status) = self.rate_limit_status() {
+                if rate_limit_status.is_exhausted() {
+                    thread::sleep(rate_limit_status.duration_until_reset());
+                }
+            }
+        }
+    }
+
+    /// Reports the request to the metrics and updates the rate limit status from the response headers.
+    fn record_response(&self, url: &Url, status_and_headers: Option<(u16, &[(String, String)])>, latency: Duration) {
+        if let Some(ref metrics) = self.metrics {
+            metrics.on_request_complete(url.path(), status_and_headers.map(|(status, _)| status), latency);
+        }
+        if let Some((status, headers)) = status_and_headers {
+            let response_meta = ResponseMeta::from_headers(status, headers);
+            if let Some(rate_limit_status) = response_meta.rate_limit_status() {
+                *self.rate_limit_status.lock().unwrap() = Some(rate_limit_status);
             }
-            _ => Err(Error::Http(response))
+            response_meta::set_last_response_meta(response_meta);
+        }
+    }
+
+    /// Returns the credentials of the provider if set, otherwise the Client ID with the OAuth token.
+    fn credentials(&self) -> Result<Credentials> {
+        if let Some(ref credentials_provider) = self.credentials_provider {
+            return Ok(credentials_provider.credentials());
+        }
+        let credentials = Credentials::new(self.client_id.as_str());
+        match try!(self.oauth_token()) {
+            Some(oauth_token) => Ok(credentials.with_oauth_token(oauth_token)),
+            None => Ok(credentials),
         }
     }
 
-    pub fn create_default_headers(&self) -> Headers {
-        let mut headers = Headers::new();
+    /// Returns the user access token if set, otherwise the (lazily requested) app access token if available.
+    fn oauth_token(&self) -> Result<Option<String>> {
+        if let Some(ref oauth_token) = self.oauth_token {
+            return Ok(Some(oauth_token.clone()));
+        }
+
+        match self.app_credentials {
+            Some(ref app_credentials) => {
+                let cached_app_access_token = app_credentials.app_access_token.lock().unwrap().clone();
+                match cached_app_access_token {
+                    Some(app_access_token) => Ok(Some(app_access_token)),
+                    None => Ok(Some(try!(self.refresh_app_access_token()))),
+                }
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn is_using_app_access_token(&self) -> bool {
+        self.oauth_token.is_none() && self.credentials_provider.is_none() && self.app_credentials.is_some()
+    }
+
+    /// Requests a new app access token with the client credentials flow and caches it.
+    fn refresh_app_access_token(&self) -> Result<String> {
+        let app_credentials = self.app_credentials.as_ref().expect("app credentials required");
 
-        headers.set(Accept(vec![
-            qitem(Mime(TopLevel::Application, SubLevel::Ext("vnd.twitchtv.v3+json".to_owned()), vec![])),
-        ]));
-        headers.set(ClientId(self.client_id.clone()));
+        let mut url_string = String::from(Api::Auth.base_url());
+        url_string.push_str("/token");
+        let params = ClientCredentialsParams {
+            client_id: &self.client_id,
+            client_secret: &app_credentials.client_secret,
+            grant_type: "client_credentials",
+        };
+        url_string.push_str(&params.into_query_string());
+        let headers = vec![("User-Agent".to_owned(), self.user_agent.clone())];
+        let request = Request::new(Method::Post, url_string, headers, None);
+
+        let response = try!(self.transport.send(request));
+        if !response.is_success() {
+            return Err(Error::from_response(response));
+        }
+        let access_token: AccessToken = try!(serde_json::from_str(response.body()));
+
+        let app_access_token = access_token.access_token().clone();
+        *app_credentials.app_access_token.lock().unwrap() = Some(app_access_token.clone());
+        Ok(app_access_token)
+    }
+
+    fn create_default_headers(&self, credentials: &Credentials) -> Vec<(String, String)> {
+        let mut headers = Vec::new();
+
+        if let Api::Kraken(api_version) = self.api {
+            let accept = format!("application/vnd.twitchtv.{}+json", api_version.to_media_type_version());
+            headers.push(("Accept".to_owned(), accept));
+        }
+        headers.push(("Client-ID".to_owned(), credentials.client_id().to_owned()));
+        headers.push(("User-Agent".to_owned(), self.user_agent.clone()));
+        if self.compression {
+            headers.push(("Accept-Encoding".to_owned(), "gzip, deflate".to_owned()));
+        }
+        if let Some(oauth_token) = credentials.oauth_token() {
+            let authorization = format!("{} {}", self.api.authorization_scheme(), oauth_token);
+            headers.push(("Authorization".to_owned(), authorization));
+        }
+        for &(ref name, ref value) in self.options.headers() {
+            headers.retain(|&(ref existing_name, _)| !existing_name.eq_ignore_ascii_case(name));
+            headers.push((name.clone(), value.clone()));
+        }
 
         headers
     }
 
 }
+
+
+#[cfg(feature = "logging")]
+fn log_request(method: Method, url: &Url) {
+    trace!("Sending {:?} {}", method, redacted_url(url));
+}
+
+#[cfg(not(feature = "logging"))]
+fn log_request(_method: Method, _url: &Url) {}
+
+#[cfg(feature = "logging")]
+fn log_response(method: Method, url: &Url, result: &Result<Response>, latency: Duration) {
+    let latency_millis = latency.as_secs() * 1000 + (latency.subsec_nanos() / 1_000_000) as u64;
+    match *result {
+        Ok(ref response) => {
+            debug!("{:?} {} -> {} in {} ms ({} bytes)", method, redacted_url(url), response.status(),
+                    latency_millis, response.body().len());
+        },
+        Err(ref error) => {
+            debug!("{:?} {} failed after {} ms: {}", method, redacted_url(url), latency_millis, error);
+        },
+    }
+}
+
+#[cfg(not(feature = "logging"))]
+fn log_response(_method: Method, _url: &Url, _result: &Result<Response>, _latency: Duration) {}
+
+#[cfg(feature = "logging")]
+fn log_streaming_response(method: Method, url: &Url, result: &Result<StreamingResponse>, latency: Duration) {
+    let latency_millis = latency.as_secs() * 1000 + (latency.subsec_nanos() / 1_000_000) as u64;
+    match *result {
+        Ok(ref response) => {
+            debug!("{:?} {} -> {} in {} ms (streaming)", method, redacted_url(url), response.status(), latency_millis);
+        },
+        Err(ref error) => {
+            debug!("{:?} {} failed after {} ms: {}", method, redacted_url(url), latency_millis, error);
+        },
+    }
+}
+
+#[cfg(not(feature = "logging"))]
+fn log_streaming_response(_method: Method, _url: &Url, _result: &Result<StreamingResponse>, _latency: Duration) {}
+
+/// Returns the url with the value of the `client_id` query parameter replaced,
+/// so that it does not end up in log files.
+#[cfg(feature = "logging")]
+fn redacted_url(url: &Url) -> String {
+    if !url.query_pairs().any(|(name, _)| name == "client_id") {
+        return url.to_string();
+    }
+    let query_pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    let mut redacted_url = url.clone();
+    redacted_url.query_pairs_mut()
+            .clear()
+            .extend_pairs(query_pairs.iter().map(|&(ref name, ref value)| {
+                if name == "client_id" {
+                    (name.as_str(), "REDACTED")
+                } else {
+                    (name.as_str(), value.as_str())
+                }
+            }));
+    redacted_url.to_string()
+}
+
+/// Parses the url, failing with `Error::InvalidUrl` instead of panicking.
+fn parse_url(url_string: String) -> Result<Url> {
+    match Url::parse(&url_string) {
+        Ok(url) => Ok(url),
+        Err(_) => Err(Error::InvalidUrl(url_string)),
+    }
+}
+
+/// Whether the error indicates that Twitch is unavailable (e.g. while offline or during an outage).
+fn is_unavailable(error: &Error) -> bool {
+    match *error {
+        Error::CircuitOpen { .. } => true,
+        ref error => error.is_retryable(),
+    }
+}
+
+/// Returns the duration from the `Retry-After` header in seconds,
+/// falling back to the reset of the rate limit bucket and one second.
+fn retry_after(response: &Response) -> Duration {
+    let retry_after_seconds = response.header("Retry-After").and_then(|value| value.trim().parse().ok());
+    match retry_after_seconds {
+        Some(retry_after_seconds) => Duration::from_secs(retry_after_seconds),
+        None => match RateLimitStatus::from_response(response) {
+            Some(rate_limit_status) => rate_limit_status.duration_until_reset(),
+            None => Duration::from_secs(1),
+        },
+    }
+}
+
+fn is_invalid_token_message(response_body: &str) -> bool {
+    let response_body = response_body.to_lowercase();
+    response_body.contains("invalid oauth token") || response_body.contains("expired")
+}
+
+
+#[cfg(all(test, feature = "logging"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacted_url_should_hide_client_id() {
+        let url = Url::parse("https://api.twitch.tv/kraken/streams?client_id=abc123&limit=10").unwrap();
+        assert_eq!(redacted_url(&url), "https://api.twitch.tv/kraken/streams?client_id=REDACTED&limit=10");
+    }
+}
@@ -7,9 +7,14 @@
 //!
 //! Rust Twitch Client is a library for the [Twitch REST API] written in Rust!
 //!
-//! It uses [hyper] with [native_tls] as https client
+//! It uses [hyper] with [native_tls] as https client by default (or [rustls] with the `rustls` cargo feature,
+//! or [reqwest] with the `reqwest` cargo feature)
 //! and [serde] for the serialization and deserialization of the REST requests and responses.
 //!
+//! The models, params and errors are always available, whereas the blocking `TwitchClient` and `HelixClient`
+//! require the `blocking` cargo feature (enabled by the default `hyper-client` feature)
+//! and the futures based `async_client::AsyncTwitchClient` requires the `async` cargo feature.
+//!
 //! # Examples
 //!
 //! ```
@@ -35,28 +40,181 @@
 //! [Twitch REST API]: https://dev.twitch.tv/docs
 //! [hyper]: https://hyper.rs/
 //! [native_tls]: https://docs.rs/crate/native-tls
+//! [rustls]: https://docs.rs/crate/rustls
+//! [reqwest]: https://docs.rs/crate/reqwest
 //! [serde]: https://serde.rs/
 
-#[macro_use] extern crate hyper;
+#[cfg(feature = "hyper-transport")]
+extern crate hyper;
+#[cfg(feature = "hyper-client")]
 extern crate hyper_native_tls;
+#[cfg(feature = "hyper-client")]
 extern crate native_tls;
+#[cfg(feature = "rustls")]
+extern crate hyper_rustls;
+#[cfg(feature = "reqwest")]
+extern crate reqwest;
+#[cfg(feature = "blocking")]
+extern crate flate2;
 extern crate url;
+#[cfg(feature = "blocking")]
+extern crate crossbeam;
+extern crate serde_ignored;
+extern crate hmac;
+extern crate sha2;
+#[cfg(feature = "chrono")]
+extern crate chrono;
+#[cfg(feature = "pubsub")]
+extern crate tungstenite;
+#[cfg(feature = "async")]
+extern crate futures;
 extern crate serde;
 #[macro_use] extern crate serde_derive;
 extern crate serde_json;
+extern crate serde_urlencoded;
+#[cfg(feature = "logging")]
+#[macro_use] extern crate log;
 
+// Core: models, params and errors, which are usable without a http client (e.g. to parse webhook payloads).
 pub mod model;
 pub mod error;
-mod http;
 pub mod param;
+pub mod transport;
+pub mod rate_limit;
+pub mod response_meta;
+pub mod paging;
+pub mod helix;
+mod signature;
+mod query_string;
+
+// Blocking client.
+#[cfg(feature = "blocking")]
+mod http;
+#[cfg(feature = "blocking")]
+pub mod auth;
+#[cfg(feature = "blocking")]
+pub mod retry;
+#[cfg(feature = "blocking")]
+pub mod circuit_breaker;
+#[cfg(feature = "blocking")]
+pub mod credentials;
+#[cfg(feature = "blocking")]
+pub mod resolver;
+#[cfg(feature = "blocking")]
+pub mod cache;
+#[cfg(feature = "blocking")]
+pub mod metrics;
+#[cfg(feature = "blocking")]
+pub mod usher;
+#[cfg(feature = "blocking")]
+pub mod watch;
+#[cfg(feature = "blocking")]
+pub mod followers;
+#[cfg(feature = "blocking")]
+pub mod overview;
+#[cfg(all(feature = "blocking", feature = "unofficial"))]
+pub mod unofficial;
+#[cfg(all(feature = "blocking", any(test, feature = "testing")))]
+pub mod testing;
+#[cfg(feature = "blocking")]
+mod batch;
+#[cfg(feature = "blocking")]
+mod single_flight;
+
+// Async client.
+#[cfg(feature = "async")]
+pub mod async_client;
+
+#[cfg(feature = "pubsub")]
+pub mod pubsub;
+
+#[cfg(all(feature = "blocking", not(any(feature = "hyper-transport", feature = "reqwest"))))]
+compile_error!("the `blocking` feature needs either the `hyper-client`, `rustls` or `reqwest` feature");
+#[cfg(all(feature = "reqwest", not(any(feature = "blocking", feature = "async"))))]
+compile_error!("the `reqwest` feature needs the `blocking` or `async` feature");
 
 pub use param::*;
-use http::TwitchHttpClient;
-use error::Result;
+#[cfg(feature = "blocking")]
+use std::cmp;
+#[cfg(feature = "blocking")]
+use std::collections::HashMap;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
+#[cfg(feature = "blocking")]
+use std::thread;
+#[cfg(feature = "blocking")]
+use std::time::Duration;
+#[cfg(feature = "blocking")]
+use http::{TwitchHttpClient, Api};
+#[cfg(feature = "blocking")]
+use paging::{PageIterator, Paginated, CursorPaginated};
+#[cfg(feature = "blocking")]
+use error::{Result, Error};
+
+/// Version of the [Twitch REST API] that is used by the `TwitchClient`.
+///
+/// The version is sent in the `Accept` header of every request.
+///
+/// [Twitch REST API]: https://dev.twitch.tv/docs
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum ApiVersion {
+    /// [Twitch API version 3] where channels and users are identified by their names.
+    ///
+    /// [Twitch API version 3]: https://dev.twitch.tv/docs/v3
+    V3,
+    /// [Twitch API version 5] where channels and users are identified by their ids.
+    ///
+    /// Note that the response models are modelled after version 3
+    /// and not every field is guaranteed to be present in version 5.
+    ///
+    /// [Twitch API version 5]: https://dev.twitch.tv/docs/v5
+    V5,
+}
+
+impl ApiVersion {
+    fn to_media_type_version(&self) -> &'static str {
+        match *self {
+            ApiVersion::V3 => "v3",
+            ApiVersion::V5 => "v5",
+        }
+    }
+}
+
+/// Handling of response fields that are not known by the models of this crate.
+///
+/// Twitch adds and removes fields without notice,
+/// so fields that are not essential default to `None` (or `false` / `0`) if they are missing in both modes.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum StrictMode {
+    /// Unknown fields are ignored (default).
+    Lenient,
+    /// Unknown fields fail the request with `Error::UnknownFields`,
+    /// e.g. for canary builds that detect changes of the Twitch API.
+    ///
+    /// `_links` are not reported, because most models deliberately omit them.
+    Strict,
+}
+
+impl Default for StrictMode {
+    fn default() -> StrictMode {
+        StrictMode::Lenient
+    }
+}
+
+/// Commercial lengths in seconds that are allowed by Twitch.
+pub const COMMERCIAL_LENGTHS: [u16; 6] = [30, 60, 90, 120, 150, 180];
+
+#[cfg(feature = "blocking")]
+const GLOBAL_BADGES_URL: &'static str = "https://badges.twitch.tv/v1/badges/global/display";
+#[cfg(feature = "blocking")]
+const CHANNEL_BADGES_BASE_URL: &'static str = "https://badges.twitch.tv/v1/badges/channels";
 
 /// Readonly client for the [Twitch REST API].
 ///
-/// Currently [Twitch API version 3] is used.
+/// By default [Twitch API version 3] is used, which can be changed with `TwitchClientBuilder::with_api_version`.
+///
+/// The client is `Send + Sync` and cheap to clone: clones share the transport (connections),
+/// the rate limit state, the response cache and the metrics, so it can be passed to other threads directly.
 ///
 /// By using the Twitch Client you agree to follow the
 /// [Twitch Developer Services Agreement] and the [Twitch Terms of Service].
@@ -80,13 +238,23 @@ use error::Result;
 /// [Twitch API version 3]: https://dev.twitch.tv/docs/v3
 /// [Twitch Developer Services Agreement]: https://www.twitch.tv/p/developer-agreement
 /// [Twitch Terms of Service]: https://help.twitch.tv/customer/portal/articles/735191-terms-of-service
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
 pub struct TwitchClient {
+    api_version: ApiVersion,
+    strict_mode: StrictMode,
     http_client: TwitchHttpClient,
+    user_id_resolver: Option<Arc<resolver::UserIdResolver>>,
 }
 
+#[cfg(feature = "blocking")]
 impl TwitchClient {
 
-    /// Constructs a new client instance with a new hyper https client using native tls.
+    /// Constructs a new client instance with the default transport
+    /// (a new hyper https client using native tls or a new reqwest client, see `transport`).
+    ///
+    /// Shorthand for `TwitchClientBuilder::new(client_id).build()`,
+    /// use the `TwitchClientBuilder` for any other settings.
     ///
     /// Since [2016-08-06] a Twitch Client ID is required.
     /// Instructions for obtaining it can be found at the [Twitch API Documentation].
@@ -94,35 +262,499 @@ impl TwitchClient {
     /// [2016-08-06]: https://blog.twitch.tv/client-id-required-for-kraken-api-calls-afbb8e95f843
     /// [Twitch API Documentation]: https://dev.twitch.tv/docs/v5/guides/using-the-twitch-api/#getting-a-client-id
     pub fn new<S: Into<String>>(client_id: S) -> Result<TwitchClient> {
-        let http_client = try!(TwitchHttpClient::new(client_id));
+        TwitchClientBuilder::new(client_id).build()
+    }
 
-        let twitch_client = TwitchClient {
-            http_client: http_client,
-        };
-        Ok(twitch_client)
+    /// Returns the state of the rate limit bucket as reported by the last response,
+    /// or `None` if no response contained the rate limit headers yet.
+    pub fn rate_limit_status(&self) -> Option<rate_limit::RateLimitStatus> {
+        self.http_client.rate_limit_status()
+    }
+
+    /// Returns the state of the circuit breaker, or `None` if the client has no circuit breaker.
+    pub fn circuit_state(&self) -> Option<circuit_breaker::CircuitState> {
+        self.http_client.circuit_state()
+    }
+
+    /// Returns the metadata (e.g. the request id) of the last response that was received on the current thread,
+    /// or `None` if no response was received on the current thread yet.
+    ///
+    /// The metadata is kept per thread (and not per client), so that it belongs to the last call of the caller
+    /// even if the client is shared between threads.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use twitch_client::*;
+    ///
+    /// let twitch_client = TwitchClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap();
+    /// if let Err(error) = twitch_client.top_games(TopGamesParams::default()) {
+    ///     let request_id = twitch_client.last_response_meta().and_then(|meta| meta.request_id().clone());
+    ///     println!("request {:?} failed: {}", request_id, error);
+    /// }
+    /// ```
+    pub fn last_response_meta(&self) -> Option<response_meta::ResponseMeta> {
+        response_meta::last_response_meta()
+    }
+
+    /// Returns a clone of this client that applies the options to all of its requests.
+    ///
+    /// The clone shares the transport, rate limit state, response cache and metrics with this client,
+    /// so it is cheap to create one for a single call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use twitch_client::*;
+    ///
+    /// let twitch_client = TwitchClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap();
+    /// let options = RequestOptions::new().with_timeout(Duration::from_secs(60));
+    ///
+    /// let _follows = twitch_client.with_options(options)
+    ///         .channel_follows("test_channel", FollowsParams::default());
+    /// ```
+    pub fn with_options(&self, options: RequestOptions) -> TwitchClient {
+        let mut twitch_client = self.clone();
+        if let Some(api_version) = options.api_version() {
+            twitch_client.api_version = api_version;
+            twitch_client.http_client.set_api(Api::Kraken(api_version));
+        }
+        twitch_client.http_client.set_request_options(options);
+        twitch_client
+    }
+
+    /// Get the raw response body of an endpoint that is not modeled by this crate yet,
+    /// reusing the headers, authentication, transport and error handling of the client.
+    ///
+    /// The path is relative to the base url (e.g. "/streams/summary")
+    /// and the query parameters are percent encoded.
+    pub fn get_raw(&self, path: &str, query: &[(&str, &str)]) -> Result<String> {
+        self.http_client.get_content_with_params(path, query)
+    }
+
+    /// Get the response of an endpoint that is not modeled by this crate yet as JSON value.
+    ///
+    /// See `get_raw`.
+    pub fn get_json(&self, path: &str, query: &[(&str, &str)]) -> Result<serde_json::Value> {
+        let response = try!(self.get_raw(path, query));
+        let value: serde_json::Value = try!(serde_json::from_str(&response));
+        Ok(value)
     }
 
-    /// Constructs a new client instance using the provided hyper client.
+    /// Executes a call for each of the items with up to `max_concurrency` calls running at the same time
+    /// and returns the results in the order of the items.
+    ///
+    /// Intended for applications that need many resources at once (e.g. dashboards).
+    /// No new call is started while the rate limit bucket is exhausted (see `rate_limit_status`),
+    /// instead the threads wait until the bucket is reset.
+    ///
+    /// # Examples
     ///
-    /// Note that the provided hyper client needs to use a tls connection.
+    /// ```
+    /// use twitch_client::*;
+    ///
+    /// let twitch_client = TwitchClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap();
+    ///
+    /// let streams = twitch_client.execute_batch(vec!["test_channel", "test_channel2"], 8, |client, channel| {
+    ///     client.stream(channel)
+    /// });
+    /// assert_eq!(streams.len(), 2);
+    /// ```
+    pub fn execute_batch<T, R, F>(&self, items: Vec<T>, max_concurrency: usize, call: F) -> Vec<R>
+            where T: Send, R: Send, F: Fn(&TwitchClient, T) -> R + Sync {
+        batch::map_with_max_threads(items, cmp::max(max_concurrency, 1), |item| {
+            if let Some(rate_limit_status) = self.rate_limit_status() {
+                if rate_limit_status.is_exhausted() {
+                    thread::sleep(rate_limit_status.duration_until_reset());
+                }
+            }
+            call(self, item)
+        })
+    }
+
+    fn resolve_id_or_name(&self, id_or_name: IdOrName) -> Result<String> {
+        match (self.api_version, id_or_name, &self.user_id_resolver) {
+            (ApiVersion::V3, IdOrName::Name(name), _) => Ok(name),
+            (ApiVersion::V5, IdOrName::Id(id), _) => Ok(id.to_string()),
+            (ApiVersion::V5, IdOrName::Name(name), &Some(ref user_id_resolver)) => {
+                let id = try!(user_id_resolver.resolve(&name, |name| self.user_id_by_login(name)));
+                match id {
+                    Some(id) => Ok(id.to_string()),
+                    None => Err(Error::NotFound {
+                        headers: Vec::new(),
+                        body: format!("No user with the login name \"{}\"", name),
+                        api_error: None,
+                    }),
+                }
+            },
+            (api_version, id_or_name, _) => Err(Error::UnsupportedIdOrName(api_version, id_or_name)),
+        }
+    }
+
+    fn user_id_by_login(&self, name: &str) -> Result<Option<model::id::UserId>> {
+        let users = try!(self.users_by_login(&[name]));
+        let user = users.users().iter().find(|user| user.name().eq_ignore_ascii_case(name));
+        Ok(user.map(|user| user.id()))
+    }
+
+    fn authenticated_user(&self) -> Result<IdOrName> {
+        let response = try!(self.http_client.get_scoped_content("/user", "user_read"));
+        let user: model::user::User = try!(model::from_json(&response, self.strict_mode));
+        match self.api_version {
+            ApiVersion::V3 => Ok(IdOrName::Name(user.name().clone())),
+            ApiVersion::V5 => Ok(IdOrName::Id(user.id().value())),
+        }
+    }
+
+}
+
+
+/// Builder for a `TwitchClient`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use twitch_client::*;
+///
+/// let twitch_client = TwitchClientBuilder::new("<YOUR_TWITCH_CLIENT_ID>")
+///         .with_oauth_token("<YOUR_OAUTH_TOKEN>")
+///         .with_api_version(ApiVersion::V5)
+///         .with_connect_timeout(Duration::from_secs(5))
+///         .with_read_timeout(Duration::from_secs(30))
+///         .build()
+///         .unwrap();
+/// ```
+#[cfg(feature = "blocking")]
+pub struct TwitchClientBuilder {
+    client_id: String,
+    oauth_token: Option<String>,
+    client_secret: Option<String>,
+    user_agent: Option<String>,
+    compression: bool,
+    api_version: ApiVersion,
+    strict_mode: StrictMode,
+    base_url: Option<String>,
+    retry_policy: Option<retry::RetryPolicy>,
+    circuit_breaker_policy: Option<circuit_breaker::CircuitBreakerPolicy>,
+    credentials_provider: Option<Box<credentials::CredentialsProvider>>,
+    wait_on_rate_limit: bool,
+    max_requests_per_minute: Option<u32>,
+    response_cache: Option<(Box<cache::ResponseCache>, Duration)>,
+    request_coalescing: bool,
+    user_id_resolver: Option<resolver::UserIdResolver>,
+    metrics: Option<Box<metrics::ClientMetrics>>,
+    transport_config: transport::TransportConfig,
+    transport: Option<Box<transport::Transport>>,
+    #[cfg(feature = "hyper-transport")]
+    hyper_transport_factory: Option<Box<FnMut(&transport::TransportConfig) -> Result<transport::HyperTransport>>>,
+}
+
+#[cfg(feature = "blocking")]
+impl TwitchClientBuilder {
+    /// Creates a builder with the Twitch Client ID.
     ///
     /// Since [2016-08-06] a Twitch Client ID is required.
     /// Instructions for obtaining it can be found at the [Twitch API Documentation].
     ///
     /// [2016-08-06]: https://blog.twitch.tv/client-id-required-for-kraken-api-calls-afbb8e95f843
     /// [Twitch API Documentation]: https://dev.twitch.tv/docs/v5/guides/using-the-twitch-api/#getting-a-client-id
-    pub fn with_hyper_client<S: Into<String>>(client_id: S, hyper_client: hyper::Client) -> TwitchClient {
-        let http_client = TwitchHttpClient::with_hyper_client(client_id, hyper_client);
+    pub fn new<S: Into<String>>(client_id: S) -> TwitchClientBuilder {
+        TwitchClientBuilder {
+            client_id: client_id.into(),
+            oauth_token: None,
+            client_secret: None,
+            user_agent: None,
+            compression: true,
+            api_version: ApiVersion::V3,
+            strict_mode: StrictMode::default(),
+            base_url: None,
+            retry_policy: None,
+            circuit_breaker_policy: None,
+            credentials_provider: None,
+            wait_on_rate_limit: false,
+            max_requests_per_minute: None,
+            response_cache: None,
+            request_coalescing: false,
+            user_id_resolver: None,
+            metrics: None,
+            transport_config: transport::TransportConfig::default(),
+            transport: None,
+            #[cfg(feature = "hyper-transport")]
+            hyper_transport_factory: None,
+        }
+    }
+    /// Sets the OAuth token that is used to authenticate the requests.
+    ///
+    /// A token is required for requests that access user specific data (e.g. the followed streams).
+    /// Instructions for obtaining it can be found at the [Twitch Authentication Documentation].
+    ///
+    /// [Twitch Authentication Documentation]: https://dev.twitch.tv/docs/v3/guides/authentication
+    pub fn with_oauth_token<S: Into<String>>(mut self, oauth_token: S) -> TwitchClientBuilder {
+        self.oauth_token = Some(oauth_token.into());
+        self
+    }
+    /// Sets the client secret to authenticate the requests with an app access token.
+    ///
+    /// The app access token is obtained with the [OAuth client credentials flow] on the first request
+    /// and transparently refreshed if Twitch rejects it as expired, retrying the original request once.
+    /// App access tokens have no user scopes, so scoped endpoints still require `with_oauth_token`.
+    ///
+    /// [OAuth client credentials flow]: https://dev.twitch.tv/docs/authentication/getting-tokens-oauth#oauth-client-credentials-flow
+    pub fn with_client_secret<S: Into<String>>(mut self, client_secret: S) -> TwitchClientBuilder {
+        self.client_secret = Some(client_secret.into());
+        self
+    }
+    /// Sets the `User-Agent` header of the requests,
+    /// ideally identifying the application and a contact (e.g. "my-bot/1.2 (admin@example.com)").
+    ///
+    /// Defaults to the name and version of this crate (e.g. "twitch-client/0.1.0").
+    pub fn with_user_agent<S: Into<String>>(mut self, user_agent: S) -> TwitchClientBuilder {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+    /// Sets whether gzip and deflate compressed responses are requested and transparently decompressed
+    /// (default: `true`), which considerably reduces the transferred bytes of large lists.
+    pub fn with_compression(mut self, compression: bool) -> TwitchClientBuilder {
+        self.compression = compression;
+        self.transport_config.disable_compression = !compression;
+        self
+    }
+    /// Sets the version of the Twitch API that is used for the requests (default: `ApiVersion::V3`).
+    ///
+    /// Channels and users need to be passed by name for `ApiVersion::V3` and by id for `ApiVersion::V5`.
+    pub fn with_api_version(mut self, api_version: ApiVersion) -> TwitchClientBuilder {
+        self.api_version = api_version;
+        self
+    }
+    /// Sets whether responses with fields that are unknown to the models fail (default: `StrictMode::Lenient`).
+    pub fn with_strict_mode(mut self, strict_mode: StrictMode) -> TwitchClientBuilder {
+        self.strict_mode = strict_mode;
+        self
+    }
+    /// Sets the base url that replaces `https://api.twitch.tv/kraken`,
+    /// e.g. to point to a local mock server for integration tests or to route the requests through an API gateway.
+    ///
+    /// `build` fails with `Error::InvalidUrl` if the base url is no absolute http(s) url.
+    pub fn with_base_url<S: Into<String>>(mut self, base_url: S) -> TwitchClientBuilder {
+        self.base_url = Some(base_url.into());
+        self
+    }
+    /// Sets the policy for retrying GET requests that failed with a server error or an io error.
+    ///
+    /// By default failed requests are not retried.
+    pub fn with_retry_policy(mut self, retry_policy: retry::RetryPolicy) -> TwitchClientBuilder {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+    /// Sets the policy of the circuit breaker, which fails requests fast with `Error::CircuitOpen`
+    /// after consecutive server errors or io errors, so that the application degrades gracefully during outages.
+    ///
+    /// The circuit breaker is shared by the clones of the client. By default there is no circuit breaker.
+    pub fn with_circuit_breaker(mut self, circuit_breaker_policy: circuit_breaker::CircuitBreakerPolicy)
+            -> TwitchClientBuilder {
+        self.circuit_breaker_policy = Some(circuit_breaker_policy);
+        self
+    }
+    /// Sets the provider of the Client ID and OAuth token of each request,
+    /// e.g. `RoundRobinCredentials` to spread the requests over several Client IDs and their rate limits.
+    ///
+    /// The credentials of the provider replace the Client ID and OAuth token of the builder.
+    pub fn with_credentials_provider<P: credentials::CredentialsProvider + 'static>(mut self, credentials_provider: P)
+            -> TwitchClientBuilder {
+        self.credentials_provider = Some(Box::new(credentials_provider));
+        self
+    }
+    /// Sets whether requests should wait until the rate limit bucket is reset
+    /// if the last response reported that no requests are remaining, instead of letting Twitch reject them.
+    ///
+    /// Disabled by default.
+    pub fn with_rate_limit_waiting(mut self, wait_on_rate_limit: bool) -> TwitchClientBuilder {
+        self.wait_on_rate_limit = wait_on_rate_limit;
+        self
+    }
+    /// Limits the requests to at most `max_requests_per_minute`, which are spread evenly over the minute.
+    ///
+    /// Requests beyond the budget wait until it is their turn, so that several features
+    /// that share a Client ID stay below the rate limit of Twitch instead of being rejected.
+    /// The budget is shared by the clones of the client and applies to every attempt, including retries.
+    /// By default requests are not throttled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_requests_per_minute` is zero.
+    pub fn with_max_requests_per_minute(mut self, max_requests_per_minute: u32) -> TwitchClientBuilder {
+        assert!(max_requests_per_minute > 0, "max_requests_per_minute must be greater than zero");
+        self.max_requests_per_minute = Some(max_requests_per_minute);
+        self
+    }
+    /// Sets the cache for the responses of GET requests that do not require a scope,
+    /// which are cached for the time to live.
+    ///
+    /// By default responses are not cached.
+    pub fn with_response_cache<C: cache::ResponseCache + 'static>(mut self, response_cache: C, ttl: Duration)
+            -> TwitchClientBuilder {
+        self.response_cache = Some((Box::new(response_cache), ttl));
+        self
+    }
+    /// Sets whether identical GET requests that do not require a scope are coalesced while they are in flight,
+    /// so that threads requesting the same url at the same time (e.g. in a web server) share one request to Twitch.
+    ///
+    /// Coalesced requests are shared by the clones of the client.
+    /// If the shared request fails, the waiting threads send their own request. Disabled by default.
+    pub fn with_request_coalescing(mut self, request_coalescing: bool) -> TwitchClientBuilder {
+        self.request_coalescing = request_coalescing;
+        self
+    }
+    /// Sets the resolver that looks up the ids of channels and users that are passed by name with `ApiVersion::V5`,
+    /// so that code passing names keeps working after switching from `ApiVersion::V3`.
+    ///
+    /// The resolved ids are shared by the clones of the client.
+    /// By default passing names with `ApiVersion::V5` fails with `Error::UnsupportedIdOrName`.
+    pub fn with_user_id_resolver(mut self, user_id_resolver: resolver::UserIdResolver) -> TwitchClientBuilder {
+        self.user_id_resolver = Some(user_id_resolver);
+        self
+    }
+    /// Sets the metrics that receive the endpoint, status and latency of every request.
+    pub fn with_metrics<M: metrics::ClientMetrics + 'static>(mut self, metrics: M) -> TwitchClientBuilder {
+        self.metrics = Some(Box::new(metrics));
+        self
+    }
+    /// Sets the maximum duration for establishing a connection to Twitch.
+    ///
+    /// Only applies to the default transport.
+    pub fn with_connect_timeout(mut self, connect_timeout: Duration) -> TwitchClientBuilder {
+        self.transport_config.connect_timeout = Some(connect_timeout);
+        self
+    }
+    /// Sets the maximum duration for waiting on response data from Twitch.
+    ///
+    /// Only applies to the default transport.
+    pub fn with_read_timeout(mut self, read_timeout: Duration) -> TwitchClientBuilder {
+        self.transport_config.read_timeout = Some(read_timeout);
+        self
+    }
+    /// Sets the maximum number of idle connections per host that are kept open for reuse
+    /// (default: 5 for hyper, unlimited for reqwest).
+    ///
+    /// Only applies to the default transport.
+    pub fn with_max_idle_connections_per_host(mut self, max_idle_connections: usize) -> TwitchClientBuilder {
+        self.transport_config.max_idle_connections_per_host = Some(max_idle_connections);
+        self
+    }
+    /// Sets the duration after which idle connections are closed instead of reused (default: none).
+    ///
+    /// Only applies to the default hyper transport.
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> TwitchClientBuilder {
+        self.transport_config.idle_timeout = Some(idle_timeout);
+        self
+    }
+    /// Sets whether connections are kept open and reused for later requests (default: `true`).
+    ///
+    /// Polling many channels benefits from reused connections,
+    /// whereas occasional requests may prefer not to keep sockets open.
+    /// Only applies to the default transport.
+    pub fn with_keep_alive(mut self, keep_alive: bool) -> TwitchClientBuilder {
+        self.transport_config.disable_keep_alive = !keep_alive;
+        self
+    }
+    /// Sets the transport that sends the requests instead of the default transport,
+    /// e.g. a `HyperTransport` with a custom hyper client.
+    pub fn with_transport<T: transport::Transport + 'static>(mut self, transport: T) -> TwitchClientBuilder {
+        self.transport = Some(Box::new(transport));
+        self
+    }
+    /// Sets the hyper connector that opens the connections of the default hyper transport,
+    /// e.g. to connect through a SOCKS5 proxy or to capture the traffic.
+    ///
+    /// The connector only establishes the plain connection, TLS and the headers are still handled by the client.
+    /// The connect timeout is up to the connector, the other transport settings still apply.
+    /// Ignored if a transport is set with `with_transport`.
+    #[cfg(feature = "hyper-transport")]
+    pub fn with_hyper_connector<C>(mut self, connector: C) -> TwitchClientBuilder
+            where C: hyper::net::NetworkConnector + Send + Sync + 'static,
+                  C::Stream: hyper::net::NetworkStream + Send + Sync + Clone + std::fmt::Debug {
+        let mut connector = Some(connector);
+        self.hyper_transport_factory = Some(Box::new(move |config| {
+            let connector = connector.take().expect("hyper connector is only used once");
+            transport::HyperTransport::with_config_and_connector(config, connector)
+        }));
+        self
+    }
+    /// Builds the client.
+    pub fn build(mut self) -> Result<TwitchClient> {
+        let transport = try!(self.build_transport());
+        let api = Api::Kraken(self.api_version);
+        let mut http_client = TwitchHttpClient::with_transport(api, self.client_id, transport);
+        if let Some(oauth_token) = self.oauth_token {
+            http_client.set_oauth_token(oauth_token);
+        }
+        if let Some(client_secret) = self.client_secret {
+            http_client.set_client_secret(client_secret);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http_client.set_user_agent(user_agent);
+        }
+        http_client.set_compression(self.compression);
+        if let Some(base_url) = self.base_url {
+            try!(http_client.set_base_url(base_url));
+        }
+        if let Some(retry_policy) = self.retry_policy {
+            http_client.set_retry_policy(retry_policy);
+        }
+        if let Some(circuit_breaker_policy) = self.circuit_breaker_policy {
+            http_client.set_circuit_breaker(circuit_breaker_policy);
+        }
+        if let Some(credentials_provider) = self.credentials_provider {
+            http_client.set_credentials_provider(credentials_provider);
+        }
+        http_client.set_wait_on_rate_limit(self.wait_on_rate_limit);
+        if let Some(max_requests_per_minute) = self.max_requests_per_minute {
+            http_client.set_max_requests_per_minute(max_requests_per_minute);
+        }
+        if let Some((response_cache, ttl)) = self.response_cache {
+            http_client.set_response_cache(response_cache, ttl);
+        }
+        http_client.set_request_coalescing(self.request_coalescing);
+        if let Some(metrics) = self.metrics {
+            http_client.set_metrics(metrics);
+        }
 
         let twitch_client = TwitchClient {
+            api_version: self.api_version,
+            strict_mode: self.strict_mode,
             http_client: http_client,
+            user_id_resolver: self.user_id_resolver.map(Arc::new),
         };
-        twitch_client
+        Ok(twitch_client)
+    }
+
+    #[cfg(feature = "hyper-transport")]
+    fn build_transport(&mut self) -> Result<Box<transport::Transport>> {
+        if let Some(transport) = self.transport.take() {
+            return Ok(transport);
+        }
+        match self.hyper_transport_factory.take() {
+            Some(mut hyper_transport_factory) => {
+                let transport = try!(hyper_transport_factory(&self.transport_config));
+                Ok(Box::new(transport))
+            },
+            None => transport::default_transport(&self.transport_config),
+        }
     }
 
+    #[cfg(not(feature = "hyper-transport"))]
+    fn build_transport(&mut self) -> Result<Box<transport::Transport>> {
+        match self.transport.take() {
+            Some(transport) => Ok(transport),
+            None => transport::default_transport(&self.transport_config),
+        }
+    }
 }
 
 
+#[cfg(feature = "blocking")]
 impl TwitchClient {
 
     /// Get games by number of viewers.
@@ -130,16 +762,56 @@ impl TwitchClient {
     /// Returns a list of games objects sorted by number of current viewers on Twitch, most popular first.
     pub fn top_games(&self, params: TopGamesParams) -> Result<model::game::TopGames> {
         let response = try!(self.http_client.get_content_with_params("/games/top", params));
-        let top_games: model::game::TopGames = try!(serde_json::from_str(&response));
+        let top_games: model::game::TopGames = try!(model::from_json(&response, self.strict_mode));
         Ok(top_games)
     }
 
+    /// Iterate over the games sorted by number of current viewers on Twitch, most popular first.
+    ///
+    /// Lazily fetches the pages starting at the offset of the params with the limit of the params as page size.
+    pub fn top_games_iter<'a>(&'a self, params: TopGamesParams) -> PageIterator<'a, model::game::GameInfo> {
+        let offset = params.offset().unwrap_or(0);
+        PageIterator::new(offset, move |offset| {
+            let top_games = try!(self.top_games(params.clone().with_offset(offset)));
+            Ok(top_games.top().clone())
+        })
+    }
+
+    /// Get the games sorted by number of current viewers on Twitch, most popular first,
+    /// fetching pages until `max_items` games were received or there are no more games.
+    ///
+    /// Fails with the first error of any page.
+    pub fn all_top_games(&self, max_items: usize) -> Result<Vec<model::game::GameInfo>> {
+        let params = TopGamesParams::new().with_limit(page_limit(max_items));
+        self.top_games_iter(params).take(max_items).collect()
+    }
+
+    /// Search games by name.
+    ///
+    /// Returns the games whose names match the query, most popular first,
+    /// optionally only games that are currently streamed.
+    pub fn search_games(&self, query: &str, live_only: bool) -> Result<model::game::GameSearchResults> {
+        let params = param::SearchGamesParams::new(query, live_only);
+        let response = try!(self.http_client.get_content_with_params("/search/games", params));
+        let games: model::game::GameSearchResults = try!(model::from_json(&response, self.strict_mode));
+        Ok(games)
+    }
+
+    /// Find the game with the name, ignoring the case (e.g. "counter-strike: global offensive"),
+    /// to resolve names entered by humans to the game on Twitch.
+    ///
+    /// Returns `None` if no game with exactly this name exists.
+    pub fn find_game_exact(&self, name: &str) -> Result<Option<model::game::Game>> {
+        let games = try!(self.search_games(name, false));
+        Ok(games.find_exact(name).cloned())
+    }
+
     /// Get list of ingests.
     ///
     /// Returns a list of ingest objects.
     pub fn ingests(&self) -> Result<model::ingest::Ingests> {
         let response = try!(self.http_client.get_content("/ingests"));
-        let ingests: model::ingest::Ingests = try!(serde_json::from_str(&response));
+        let ingests: model::ingest::Ingests = try!(model::from_json(&response, self.strict_mode));
         Ok(ingests)
     }
 
@@ -149,17 +821,18 @@ impl TwitchClient {
     /// If you are authenticated, the response includes the status of your token and links to other related resources.
     pub fn basic_info(&self) -> Result<model::root::BasicInfo> {
         let response = try!(self.http_client.get_content("/"));
-        let basic_info: model::root::BasicInfo = try!(serde_json::from_str(&response));
+        let basic_info: model::root::BasicInfo = try!(model::from_json(&response, self.strict_mode));
         Ok(basic_info)
     }
 
     /// Get stream object.
     ///
     /// Returns a stream object if live.
-    pub fn stream(&self, channel: &str) -> Result<model::stream::ChannelStream> {
+    pub fn stream<C: Into<IdOrName>>(&self, channel: C) -> Result<model::stream::ChannelStream> {
+        let channel = try!(self.resolve_id_or_name(channel.into()));
         let url = format!("/streams/{}", channel);
         let response = try!(self.http_client.get_content(&url));
-        let channel_stream: model::stream::ChannelStream = try!(serde_json::from_str(&response));
+        let channel_stream: model::stream::ChannelStream = try!(model::from_json(&response, self.strict_mode));
         Ok(channel_stream)
     }
 
@@ -169,7 +842,91 @@ impl TwitchClient {
     /// sorted by number of viewers descending.
     pub fn streams(&self, params: StreamsParams) -> Result<model::stream::Streams> {
         let response = try!(self.http_client.get_content_with_params("/streams", params));
-        let streams: model::stream::Streams = try!(serde_json::from_str(&response));
+        let streams: model::stream::Streams = try!(model::from_json(&response, self.strict_mode));
+        Ok(streams)
+    }
+
+    /// Get live streams like `streams`, but borrow their strings from the response body.
+    ///
+    /// The response body is stored in the buffer, which can be reused for the next request.
+    /// Avoids most of the allocations of `streams` for applications that poll many streams
+    /// (see `model::borrowed`).
+    pub fn streams_borrowed<'b>(&self, params: StreamsParams, buffer: &'b mut String)
+            -> Result<model::borrowed::Streams<'b>> {
+        *buffer = try!(self.http_client.get_content_with_params("/streams", params));
+        let streams: model::borrowed::Streams = try!(model::from_json_borrowed(buffer));
+        Ok(streams)
+    }
+
+    /// Get the live streams of the channels.
+    ///
+    /// Twitch limits the number of channels per request,
+    /// so the channels are split into multiple requests which are sent concurrently
+    /// and the live streams are merged into one `Streams` (without `_links`).
+    pub fn streams_for_channels(&self, channels: &[&str]) -> Result<model::stream::Streams> {
+        let pages = batch::map_concurrently(channels.chunks(batch::MAX_CHANNELS_PER_REQUEST).collect(), |channels| {
+            let params = StreamsParams::new()
+                    .with_channels(channels.iter().map(|channel| channel.to_string()).collect())
+                    .with_limit(batch::MAX_CHANNELS_PER_REQUEST as u8);
+            self.streams(params)
+        });
+        let pages: Vec<_> = try!(pages.into_iter().collect());
+        Ok(model::stream::Streams::merge(pages))
+    }
+
+    /// Returns whether the channel is live.
+    ///
+    /// Shorthand for checking the stream of `stream`, which is `None` if the channel is offline.
+    pub fn is_live<C: Into<IdOrName>>(&self, channel: C) -> Result<bool> {
+        let channel_stream = try!(self.stream(channel));
+        Ok(channel_stream.stream().is_some())
+    }
+
+    /// Get the live stream of each channel, or `None` if the channel is offline.
+    ///
+    /// Contains an entry for every given channel name. The streams are requested with `streams_for_channels`,
+    /// so that checking many channels only takes a request per 100 channels.
+    pub fn live_streams_of(&self, channels: &[&str]) -> Result<HashMap<String, Option<model::stream::Stream>>> {
+        let streams = try!(self.streams_for_channels(channels));
+        let live_streams: HashMap<String, model::stream::Stream> = streams.streams().iter()
+                .map(|stream| (stream.channel().name().to_lowercase(), stream.clone()))
+                .collect();
+        Ok(channels.iter()
+                .map(|channel| (channel.to_string(), live_streams.get(&channel.to_lowercase()).cloned()))
+                .collect())
+    }
+
+    /// Iterate over the live streams.
+    ///
+    /// Lazily fetches the pages starting at the offset of the params with the limit of the params as page size.
+    pub fn streams_iter<'a>(&'a self, params: StreamsParams) -> PageIterator<'a, model::stream::Stream> {
+        let offset = params.offset().unwrap_or(0);
+        PageIterator::new(offset, move |offset| {
+            let streams = try!(self.streams(params.clone().with_offset(offset)));
+            Ok(streams.streams().clone())
+        })
+    }
+
+    /// Get the live streams matching the params,
+    /// fetching pages until `max_items` streams were received or there are no more streams.
+    ///
+    /// The limit of the params is used as page size, which defaults to the maximum page size.
+    /// Fails with the first error of any page.
+    pub fn all_streams(&self, params: StreamsParams, max_items: usize) -> Result<Vec<model::stream::Stream>> {
+        let params = match params.limit() {
+            Some(_) => params,
+            None => params.with_limit(page_limit(max_items)),
+        };
+        self.streams_iter(params).take(max_items).collect()
+    }
+
+    /// Get streams that the authenticated user is following.
+    ///
+    /// Returns a list of stream objects that the authenticated user is following.
+    /// Requires an OAuth token with the `user_read` scope.
+    pub fn followed_streams(&self, params: FollowedStreamsParams) -> Result<model::stream::Streams> {
+        let response = try!(self.http_client.get_scoped_content_with_params("/streams/followed", params, "user_read"));
+        let streams: model::stream::Streams = try!(model::from_json(&response, self.strict_mode));
         Ok(streams)
     }
 
@@ -178,81 +935,811 @@ impl TwitchClient {
     /// Returns a list of featured (promoted) stream objects.
     pub fn featured_streams(&self, params: FeaturedStreamsParams) -> Result<model::stream::FeaturedStreams> {
         let response = try!(self.http_client.get_content_with_params("/streams/featured", params));
-        let featured_streams: model::stream::FeaturedStreams = try!(serde_json::from_str(&response));
+        let featured_streams: model::stream::FeaturedStreams = try!(model::from_json(&response, self.strict_mode));
         Ok(featured_streams)
     }
 
+    /// Iterate over the featured (promoted) streams.
+    ///
+    /// Lazily fetches the pages starting at the offset of the params with the limit of the params as page size.
+    pub fn featured_streams_iter<'a>(&'a self, params: FeaturedStreamsParams)
+            -> PageIterator<'a, model::stream::FeaturedStream> {
+        let offset = params.offset().unwrap_or(0);
+        PageIterator::new(offset, move |offset| {
+            let featured_streams = try!(self.featured_streams(params.clone().with_offset(offset)));
+            Ok(featured_streams.featured().clone())
+        })
+    }
+
+    /// Get the page following a previously returned page (e.g. `TopGames` or `Streams`).
+    ///
+    /// Follows the `next` link of the page, so that the offset does not have to be tracked.
+    /// Returns `None` if the page has no more items after it (see `Paginated::has_more`).
+    pub fn next_page<T, P: Paginated<T>>(&self, current: &P) -> Result<Option<P>> {
+        if !current.has_more() {
+            return Ok(None);
+        }
+        let next_link = match current.link("next") {
+            Some(next_link) => next_link.to_string(),
+            None => return Ok(None),
+        };
+        let response = try!(self.http_client.get_content_from_link(&next_link));
+        let next_page: P = try!(model::from_json(&response, self.strict_mode));
+        Ok(Some(next_page))
+    }
+
     /// Get a summary of streams.
     ///
     /// Returns a summary of current streams.
     pub fn streams_summary(&self, params: StreamsSummaryParams) -> Result<model::stream::StreamsSummary> {
         let response = try!(self.http_client.get_content_with_params("/streams/summary", params));
-        let streams_summary: model::stream::StreamsSummary = try!(serde_json::from_str(&response));
+        let streams_summary: model::stream::StreamsSummary = try!(model::from_json(&response, self.strict_mode));
         Ok(streams_summary)
     }
 
     /// Get channel object.
     ///
     /// Returns a channel object.
-    pub fn channel(&self, channel: &str) -> Result<model::channel::Channel> {
+    pub fn channel<C: Into<IdOrName>>(&self, channel: C) -> Result<model::channel::Channel> {
+        let channel = try!(self.resolve_id_or_name(channel.into()));
         let url = format!("/channels/{}", channel);
         let response = try!(self.http_client.get_content(&url));
-        let channel: model::channel::Channel = try!(serde_json::from_str(&response));
+        let channel: model::channel::Channel = try!(model::from_json(&response, self.strict_mode));
         Ok(channel)
     }
-}
-
 
+    /// Get the channel objects of the channels by name.
+    ///
+    /// The channels are requested concurrently and returned in the order of the given names.
+    /// Fails with the first error if any of the channels could not be retrieved.
+    /// Channels can only be identified by name with `ApiVersion::V3`.
+    pub fn channels(&self, channels: &[&str]) -> Result<Vec<model::channel::Channel>> {
+        let channels = batch::map_concurrently(channels.to_vec(), |channel| self.channel(channel));
+        channels.into_iter().collect()
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::env;
-    use std::fs::File;
-    use std::io::Read;
-    use serde_json;
+    /// Get an overview of the channel with its live stream, recent videos and follower count.
+    ///
+    /// The channel, stream, videos and follows are requested concurrently.
+    /// Fails with the first error if any of them could not be retrieved.
+    pub fn channel_overview<C: Into<IdOrName>>(&self, channel: C) -> Result<overview::ChannelOverview> {
+        let channel = channel.into();
+        let (channel_object, channel_stream, videos, follows) = crossbeam::scope(|scope| {
+            let channel_stream = scope.spawn(|| self.stream(channel.clone()));
+            let videos = scope.spawn(|| {
+                let params = ChannelVideosParams::new().with_limit(overview::RECENT_VIDEOS_LIMIT);
+                self.channel_videos(channel.clone(), params)
+            });
+            let follows = scope.spawn(|| self.channel_follows(channel.clone(), FollowsParams::new().with_limit(1)));
+            let channel_object = self.channel(channel.clone());
+            (channel_object, channel_stream.join(), videos.join(), follows.join())
+        });
+        Ok(overview::ChannelOverview::new(
+                try!(channel_object),
+                try!(channel_stream).stream().clone(),
+                try!(videos).videos().clone(),
+                try!(follows).total()))
+    }
 
-    #[test]
-    fn test_top_games_with_default_params() {
-        let client = create_test_twitch_client();
-        let top_games = client.top_games(TopGamesParams::default()).unwrap();
-        assert!(top_games.total() > 0, "top_games.total() = {} > 0", top_games.total());
-        assert_eq!(top_games.top().len(), 10);
+    /// Get channel's list of editors.
+    ///
+    /// Returns a list of user objects who are editors of the channel.
+    /// Requires an OAuth token of the channel owner with the `channel_read` scope.
+    pub fn channel_editors<C: Into<IdOrName>>(&self, channel: C) -> Result<model::channel::Editors> {
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/channels/{}/editors", channel);
+        let response = try!(self.http_client.get_scoped_content(&url, "channel_read"));
+        let editors: model::channel::Editors = try!(model::from_json(&response, self.strict_mode));
+        Ok(editors)
     }
 
-    #[test]
-    fn test_top_games_with_custom_params() {
-        let client = create_test_twitch_client();
-        let params = TopGamesParams::new()
-                .with_offset(0)
-                .with_limit(2);
-        let top_games = client.top_games(params).unwrap();
-        assert!(top_games.total() > 0, "top_games.total() = {} > 0", top_games.total());
-        assert_eq!(top_games.top().len(), 2);
+    /// Update channel's status, game, delay or channel feed setting.
+    ///
+    /// Returns the updated channel object.
+    /// Requires an OAuth token with the `channel_editor` scope.
+    pub fn update_channel<C: Into<IdOrName>>(&self, channel: C, params: UpdateChannelParams)
+            -> Result<model::channel::Channel> {
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/channels/{}", channel);
+        let response = try!(self.http_client.put_content_with_body(&url, params, "channel_editor"));
+        let channel: model::channel::Channel = try!(model::from_json(&response, self.strict_mode));
+        Ok(channel)
     }
 
-    #[test]
-    fn test_ingests() {
-        let client = create_test_twitch_client();
-        let ingests = client.ingests().unwrap();
-        assert!(ingests.ingests().len() > 0, "ingests.ingests().len() = {} > 0", ingests.ingests().len());
+    /// Reset channel's stream key.
+    ///
+    /// Returns the channel object including the new stream key.
+    /// Requires an OAuth token with the `channel_stream` scope.
+    pub fn reset_stream_key<C: Into<IdOrName>>(&self, channel: C) -> Result<model::channel::Channel> {
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/channels/{}/stream_key", channel);
+        let response = try!(self.http_client.delete_content(&url, "channel_stream"));
+        let channel: model::channel::Channel = try!(model::from_json(&response, self.strict_mode));
+        Ok(channel)
     }
 
-    #[test]
-    fn test_basic_info() {
-        let client = create_test_twitch_client();
-        let basic_info = client.basic_info().unwrap();
-        let token = basic_info.token();
-        assert!(!token.valid(), "expecting invalid token for unauthenticated access");
-        assert!(token.user_name().is_none(), "expecting no user name for unauthenticated access");
-        assert!(token.authorization().is_none(), "expecting no auth info for unauthenticated access");
+    /// Start commercial on channel.
+    ///
+    /// The length of the commercial needs to be one of the `COMMERCIAL_LENGTHS` in seconds.
+    /// Requires an OAuth token with the `channel_commercial` scope.
+    pub fn run_commercial<C: Into<IdOrName>>(&self, channel: C, length: u16) -> Result<()> {
+        if !COMMERCIAL_LENGTHS.contains(&length) {
+            return Err(Error::InvalidCommercialLength(length));
+        }
+
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/channels/{}/commercial", channel);
+        let params = param::CommercialParams::new()
+                .with_length(length);
+        match self.http_client.post_content_with_params(&url, params, "channel_commercial") {
+            Ok(_) => Ok(()),
+            Err(Error::UnexpectedStatus { status: 422, body, api_error, .. }) => {
+                let message = api_error.map(|api_error| api_error.message().clone()).unwrap_or(body);
+                Err(Error::CommercialRejected(message))
+            },
+            Err(err) => Err(err),
+        }
     }
 
-    #[test]
-    fn test_stream() {
-        let client = create_test_twitch_client();
-        let channel_stream = client.stream("test_channel").unwrap();
-        assert!(channel_stream.stream().is_none(), "expecting test channel stream to be offline");
+    /// Get channel's list of following users.
+    ///
+    /// Returns a list of follow objects.
+    pub fn channel_follows<C: Into<IdOrName>>(&self, channel: C, params: FollowsParams)
+            -> Result<model::follow::Follows> {
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/channels/{}/follows", channel);
+        let response = try!(self.http_client.get_content_reader_with_params(&url, params));
+        let follows: model::follow::Follows = try!(model::from_json_reader(response, self.strict_mode));
+        Ok(follows)
+    }
+
+    /// Get users by their login names.
+    ///
+    /// Returns the users with the login names, without the names that do not belong to a user.
+    /// Twitch allows up to 100 login names per request.
+    /// Requires `ApiVersion::V5`.
+    pub fn users_by_login(&self, logins: &[&str]) -> Result<model::user::Users> {
+        let params = param::UsersByLoginParams::new(logins);
+        let response = try!(self.http_client.get_content_with_params("/users", params));
+        let users: model::user::Users = try!(model::from_json(&response, self.strict_mode));
+        Ok(users)
+    }
+
+    /// Get a user's list of followed channels.
+    ///
+    /// Returns a list of follow objects.
+    pub fn user_follows<U: Into<IdOrName>>(&self, user: U, params: FollowsParams) -> Result<model::follow::Follows> {
+        let user = try!(self.resolve_id_or_name(user.into()));
+        let url = format!("/users/{}/follows/channels", user);
+        let response = try!(self.http_client.get_content_reader_with_params(&url, params));
+        let follows: model::follow::Follows = try!(model::from_json_reader(response, self.strict_mode));
+        Ok(follows)
+    }
+
+    /// Get the list of channels that the authenticated user is following.
+    ///
+    /// Returns a list of follow objects.
+    /// Requires an OAuth token with the `user_read` scope to look up the authenticated user.
+    pub fn followed_channels(&self, params: FollowsParams) -> Result<model::follow::Follows> {
+        let user = try!(self.authenticated_user());
+        self.user_follows(user, params)
+    }
+
+    /// Get status of follow relationship between user and target channel.
+    ///
+    /// Returns a follow object if the user is following the channel and `None` otherwise.
+    pub fn user_follows_channel<U: Into<IdOrName>, C: Into<IdOrName>>(&self, user: U, channel: C)
+            -> Result<Option<model::follow::Follow>> {
+        let user = try!(self.resolve_id_or_name(user.into()));
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/users/{}/follows/channels/{}", user, channel);
+        match try!(self.http_client.get_optional_content(&url, None)) {
+            Some(response) => {
+                let follow: model::follow::Follow = try!(model::from_json(&response, self.strict_mode));
+                Ok(Some(follow))
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Get subscription status of a user to a channel.
+    ///
+    /// Returns a subscription object if the user is subscribed to the channel and `None` otherwise.
+    /// Requires an OAuth token of the user with the `user_subscriptions` scope.
+    pub fn user_subscribed_to_channel<U: Into<IdOrName>, C: Into<IdOrName>>(&self, user: U, channel: C)
+            -> Result<Option<model::subscription::Subscription>> {
+        let user = try!(self.resolve_id_or_name(user.into()));
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/users/{}/subscriptions/{}", user, channel);
+        match try!(self.http_client.get_optional_content(&url, Some("user_subscriptions"))) {
+            Some(response) => {
+                let subscription: model::subscription::Subscription =
+                        try!(model::from_json(&response, self.strict_mode));
+                Ok(Some(subscription))
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Follow a channel.
+    ///
+    /// Adds the channel to the user's followed channels
+    /// and optionally enables notifications when the channel goes live.
+    /// Returns the created follow object.
+    /// Requires an OAuth token of the user with the `user_follows_edit` scope.
+    pub fn follow_channel<U: Into<IdOrName>, C: Into<IdOrName>>(&self, user: U, channel: C, notifications: bool)
+            -> Result<model::follow::Follow> {
+        let user = try!(self.resolve_id_or_name(user.into()));
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/users/{}/follows/channels/{}", user, channel);
+        let params = param::FollowChannelParams::new()
+                .with_notifications(notifications);
+        let response = try!(self.http_client.put_content_with_params(&url, params, "user_follows_edit"));
+        let follow: model::follow::Follow = try!(model::from_json(&response, self.strict_mode));
+        Ok(follow)
+    }
+
+    /// Unfollow a channel.
+    ///
+    /// Removes the channel from the user's followed channels.
+    /// Requires an OAuth token of the user with the `user_follows_edit` scope.
+    pub fn unfollow_channel<U: Into<IdOrName>, C: Into<IdOrName>>(&self, user: U, channel: C) -> Result<()> {
+        let user = try!(self.resolve_id_or_name(user.into()));
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/users/{}/follows/channels/{}", user, channel);
+        try!(self.http_client.delete_content(&url, "user_follows_edit"));
+        Ok(())
+    }
+
+    /// Get user's block list.
+    ///
+    /// Returns a list of blocked users.
+    /// Requires an OAuth token of the user with the `user_blocks_read` scope.
+    pub fn blocks<U: Into<IdOrName>>(&self, user: U, params: BlocksParams) -> Result<model::block::Blocks> {
+        let user = try!(self.resolve_id_or_name(user.into()));
+        let url = format!("/users/{}/blocks", user);
+        let response = try!(self.http_client.get_scoped_content_with_params(&url, params, "user_blocks_read"));
+        let blocks: model::block::Blocks = try!(model::from_json(&response, self.strict_mode));
+        Ok(blocks)
+    }
+
+    /// Add target to user's block list.
+    ///
+    /// Returns the created block object.
+    /// Requires an OAuth token of the user with the `user_blocks_edit` scope.
+    pub fn block_user<U: Into<IdOrName>, T: Into<IdOrName>>(&self, user: U, target: T) -> Result<model::block::Block> {
+        let user = try!(self.resolve_id_or_name(user.into()));
+        let target = try!(self.resolve_id_or_name(target.into()));
+        let url = format!("/users/{}/blocks/{}", user, target);
+        let response = try!(self.http_client.put_content(&url, "user_blocks_edit"));
+        let block: model::block::Block = try!(model::from_json(&response, self.strict_mode));
+        Ok(block)
+    }
+
+    /// Delete target from user's block list.
+    ///
+    /// Requires an OAuth token of the user with the `user_blocks_edit` scope.
+    pub fn unblock_user<U: Into<IdOrName>, T: Into<IdOrName>>(&self, user: U, target: T) -> Result<()> {
+        let user = try!(self.resolve_id_or_name(user.into()));
+        let target = try!(self.resolve_id_or_name(target.into()));
+        let url = format!("/users/{}/blocks/{}", user, target);
+        try!(self.http_client.delete_content(&url, "user_blocks_edit"));
+        Ok(())
+    }
+
+    /// Get video object.
+    ///
+    /// Returns a video object.
+    pub fn video(&self, id: &str) -> Result<model::video::Video> {
+        let url = format!("/videos/{}", id);
+        let response = try!(self.http_client.get_content(&url));
+        let video: model::video::Video = try!(model::from_json(&response, self.strict_mode));
+        Ok(video)
+    }
+
+    /// Get top videos by number of views.
+    ///
+    /// Returns a list of video objects sorted by number of views, most popular first.
+    pub fn top_videos(&self, params: TopVideosParams) -> Result<model::video::Videos> {
+        let response = try!(self.http_client.get_content_reader_with_params("/videos/top", params));
+        let videos: model::video::Videos = try!(model::from_json_reader(response, self.strict_mode));
+        Ok(videos)
+    }
+
+    /// Get videos of a channel.
+    ///
+    /// Returns a list of video objects sorted by publication date, most recent first.
+    pub fn channel_videos<C: Into<IdOrName>>(&self, channel: C, params: ChannelVideosParams)
+            -> Result<model::video::ChannelVideos> {
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/channels/{}/videos", channel);
+        let response = try!(self.http_client.get_content_reader_with_params(&url, params));
+        let videos: model::video::ChannelVideos = try!(model::from_json_reader(response, self.strict_mode));
+        Ok(videos)
+    }
+
+    /// Get videos of the channels that the authenticated user is following.
+    ///
+    /// Returns a list of video objects sorted by publication date, most recent first.
+    /// Requires an OAuth token with the `user_read` scope.
+    pub fn followed_videos(&self, params: FollowedVideosParams) -> Result<model::video::Videos> {
+        let response = try!(self.http_client.get_scoped_content_with_params("/videos/followed", params, "user_read"));
+        let videos: model::video::Videos = try!(model::from_json(&response, self.strict_mode));
+        Ok(videos)
+    }
+
+    /// Get top clips.
+    ///
+    /// Returns the most viewed (or trending) clips, optionally of channels, games or languages.
+    /// Requires `ApiVersion::V5`.
+    pub fn top_clips(&self, params: ClipsParams) -> Result<model::clip::Clips> {
+        let response = try!(self.http_client.get_content_with_params("/clips/top", params));
+        let clips: model::clip::Clips = try!(model::from_json(&response, self.strict_mode));
+        Ok(clips)
+    }
+
+    /// Iterate over the top clips.
+    ///
+    /// Lazily fetches the pages starting at the cursor of the params with the limit of the params as page size.
+    /// Requires `ApiVersion::V5`.
+    pub fn top_clips_iter<'a>(&'a self, params: ClipsParams) -> PageIterator<'a, model::clip::Clip> {
+        PageIterator::with_cursor(None, move |cursor| {
+            let page_params = match cursor {
+                Some(cursor) => params.clone().with_cursor(cursor),
+                None => params.clone(),
+            };
+            let clips = try!(self.top_clips(page_params));
+            Ok((clips.items().to_vec(), clips.next_cursor()))
+        })
+    }
+
+    /// Get clip object.
+    ///
+    /// Returns the clip with the slug.
+    /// Requires `ApiVersion::V5`.
+    pub fn clip(&self, slug: &str) -> Result<model::clip::Clip> {
+        let url = format!("/clips/{}", slug);
+        let response = try!(self.http_client.get_content(&url));
+        let clip: model::clip::Clip = try!(model::from_json(&response, self.strict_mode));
+        Ok(clip)
+    }
+
+    /// Get clips of followed games.
+    ///
+    /// Returns the top clips of the games that the authenticated user is following.
+    /// Requires `ApiVersion::V5` and an OAuth token with the `user_read` scope.
+    pub fn followed_clips(&self, params: ClipsParams) -> Result<model::clip::Clips> {
+        let response = try!(self.http_client.get_scoped_content_with_params("/clips/followed", params, "user_read"));
+        let clips: model::clip::Clips = try!(model::from_json(&response, self.strict_mode));
+        Ok(clips)
+    }
+
+    /// Get community by name.
+    ///
+    /// Returns the community with the name, or `None` if no community has the name.
+    /// Requires `ApiVersion::V5`.
+    pub fn community_by_name(&self, name: &str) -> Result<Option<model::community::Community>> {
+        let params: &[(&str, &str)] = &[("name", name)];
+        match self.http_client.get_content_with_params("/communities", params) {
+            Ok(response) => Ok(Some(try!(model::from_json(&response, self.strict_mode)))),
+            Err(Error::NotFound { .. }) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Get community by id.
+    ///
+    /// Returns the community with the id.
+    /// Requires `ApiVersion::V5`.
+    pub fn community(&self, id: &str) -> Result<model::community::Community> {
+        let url = format!("/communities/{}", id);
+        let response = try!(self.http_client.get_content(&url));
+        let community: model::community::Community = try!(model::from_json(&response, self.strict_mode));
+        Ok(community)
+    }
+
+    /// Get top communities.
+    ///
+    /// Returns the communities sorted by their number of viewers, most popular first.
+    /// Requires `ApiVersion::V5`.
+    pub fn top_communities(&self, params: TopCommunitiesParams) -> Result<model::community::TopCommunities> {
+        let response = try!(self.http_client.get_content_with_params("/communities/top", params));
+        let communities: model::community::TopCommunities = try!(model::from_json(&response, self.strict_mode));
+        Ok(communities)
+    }
+
+    /// Iterate over the communities sorted by their number of viewers, most popular first.
+    ///
+    /// Lazily fetches the pages starting at the cursor of the params with the limit of the params as page size.
+    /// Requires `ApiVersion::V5`.
+    pub fn top_communities_iter<'a>(&'a self, params: TopCommunitiesParams)
+            -> PageIterator<'a, model::community::TopCommunity> {
+        PageIterator::with_cursor(None, move |cursor| {
+            let page_params = match cursor {
+                Some(cursor) => params.clone().with_cursor(cursor),
+                None => params.clone(),
+            };
+            let communities = try!(self.top_communities(page_params));
+            Ok((communities.items().to_vec(), communities.next_cursor()))
+        })
+    }
+
+    /// Get collection metadata.
+    ///
+    /// Returns the summary of the collection without its items.
+    /// Requires `ApiVersion::V5`.
+    pub fn collection_metadata(&self, id: &str) -> Result<model::collection::CollectionMetadata> {
+        let url = format!("/collections/{}", id);
+        let response = try!(self.http_client.get_content(&url));
+        let metadata: model::collection::CollectionMetadata = try!(model::from_json(&response, self.strict_mode));
+        Ok(metadata)
+    }
+
+    /// Get collection.
+    ///
+    /// Returns the items of the collection, including unpublished and private videos.
+    /// Requires `ApiVersion::V5`.
+    pub fn collection(&self, id: &str) -> Result<model::collection::Collection> {
+        let url = format!("/collections/{}/items", id);
+        let params: &[(&str, &str)] = &[("include_all_items", "true")];
+        let response = try!(self.http_client.get_content_with_params(&url, params));
+        let collection: model::collection::Collection = try!(model::from_json(&response, self.strict_mode));
+        Ok(collection)
+    }
+
+    /// Get collections by channel.
+    ///
+    /// Returns the collections of the channel, most recently updated first.
+    /// Requires `ApiVersion::V5`.
+    pub fn channel_collections<C: Into<IdOrName>>(&self, channel: C, params: CollectionsParams)
+            -> Result<model::collection::Collections> {
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/channels/{}/collections", channel);
+        let response = try!(self.http_client.get_content_with_params(&url, params));
+        let collections: model::collection::Collections = try!(model::from_json(&response, self.strict_mode));
+        Ok(collections)
+    }
+
+    /// Iterate over the collections of the channel, most recently updated first.
+    ///
+    /// Lazily fetches the pages starting at the cursor of the params with the limit of the params as page size.
+    /// Requires `ApiVersion::V5`.
+    pub fn channel_collections_iter<'a, C: Into<IdOrName>>(&'a self, channel: C, params: CollectionsParams)
+            -> PageIterator<'a, model::collection::CollectionMetadata> {
+        let channel = channel.into();
+        PageIterator::with_cursor(None, move |cursor| {
+            let page_params = match cursor {
+                Some(cursor) => params.clone().with_cursor(cursor),
+                None => params.clone(),
+            };
+            let collections = try!(self.channel_collections(channel.clone(), page_params));
+            Ok((collections.items().to_vec(), collections.next_cursor()))
+        })
+    }
+
+    /// Get posts of a channel feed.
+    ///
+    /// Returns a list of post objects, newest first.
+    pub fn channel_posts<C: Into<IdOrName>>(&self, channel: C, params: PostsParams) -> Result<model::feed::Posts> {
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/feed/{}/posts", channel);
+        let response = try!(self.http_client.get_content_with_params(&url, params));
+        let posts: model::feed::Posts = try!(model::from_json(&response, self.strict_mode));
+        Ok(posts)
+    }
+
+    /// Iterate over the posts of a channel feed, newest first.
+    ///
+    /// Lazily fetches the pages starting at the cursor of the params with the limit of the params as page size.
+    pub fn channel_posts_iter<'a, C: Into<IdOrName>>(&'a self, channel: C, params: PostsParams)
+            -> PageIterator<'a, model::feed::Post> {
+        let channel = channel.into();
+        PageIterator::with_cursor(None, move |cursor| {
+            let page_params = match cursor {
+                Some(cursor) => params.clone().with_cursor(cursor),
+                None => params.clone(),
+            };
+            let posts = try!(self.channel_posts(channel.clone(), page_params));
+            Ok((posts.items().to_vec(), posts.next_cursor()))
+        })
+    }
+
+    /// Get a post of a channel feed.
+    ///
+    /// Returns a post object.
+    pub fn channel_post<C: Into<IdOrName>>(&self, channel: C, id: &str) -> Result<model::feed::Post> {
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/feed/{}/posts/{}", channel, id);
+        let response = try!(self.http_client.get_content(&url));
+        let post: model::feed::Post = try!(model::from_json(&response, self.strict_mode));
+        Ok(post)
+    }
+
+    /// Create a post in a channel feed.
+    ///
+    /// Returns the created post object.
+    /// Requires an OAuth token of the channel owner with the `channel_feed_edit` scope.
+    pub fn create_post<C: Into<IdOrName>>(&self, channel: C, params: CreatePostParams) -> Result<model::feed::Post> {
+        #[derive(Deserialize)]
+        struct CreatedPost {
+            post: model::feed::Post,
+        }
+
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/feed/{}/posts", channel);
+        let response = try!(self.http_client.post_content_with_params_and_body(&url, params.clone(), params,
+                                                                              "channel_feed_edit"));
+        let created_post: CreatedPost = try!(model::from_json(&response, self.strict_mode));
+        Ok(created_post.post)
+    }
+
+    /// Delete a post from a channel feed.
+    ///
+    /// Returns the deleted post object.
+    /// Requires an OAuth token of the channel owner with the `channel_feed_edit` scope.
+    pub fn delete_post<C: Into<IdOrName>>(&self, channel: C, id: &str) -> Result<model::feed::Post> {
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/feed/{}/posts/{}", channel, id);
+        let response = try!(self.http_client.delete_content(&url, "channel_feed_edit"));
+        let post: model::feed::Post = try!(model::from_json(&response, self.strict_mode));
+        Ok(post)
+    }
+
+    /// React to a post of a channel feed.
+    ///
+    /// The emote id is either "endorse" or the id of an emoticon.
+    /// Returns the created reaction object.
+    /// Requires an OAuth token of the user with the `channel_feed_edit` scope.
+    pub fn create_reaction<C: Into<IdOrName>>(&self, channel: C, id: &str, emote_id: &str)
+            -> Result<model::feed::Reaction> {
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/feed/{}/posts/{}/reactions", channel, id);
+        let params = param::ReactionParams::new()
+                .with_emote_id(emote_id);
+        let response = try!(self.http_client.post_content_with_params(&url, params, "channel_feed_edit"));
+        let reaction: model::feed::Reaction = try!(model::from_json(&response, self.strict_mode));
+        Ok(reaction)
+    }
+
+    /// Remove a reaction from a post of a channel feed.
+    ///
+    /// The emote id is either "endorse" or the id of an emoticon.
+    /// Requires an OAuth token of the user with the `channel_feed_edit` scope.
+    pub fn delete_reaction<C: Into<IdOrName>>(&self, channel: C, id: &str, emote_id: &str) -> Result<()> {
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/feed/{}/posts/{}/reactions", channel, id);
+        let params = param::ReactionParams::new()
+                .with_emote_id(emote_id);
+        try!(self.http_client.delete_content_with_params(&url, params, "channel_feed_edit"));
+        Ok(())
+    }
+
+    /// Get list of every emoticon.
+    ///
+    /// Returns a list of all emoticon objects for Twitch.
+    pub fn chat_emoticons(&self) -> Result<model::chat::Emoticons> {
+        let response = try!(self.http_client.get_content("/chat/emoticons"));
+        let emoticons: model::chat::Emoticons = try!(model::from_json(&response, self.strict_mode));
+        Ok(emoticons)
+    }
+
+    /// Get list of emoticons.
+    ///
+    /// Returns a list of emoticons, grouped by emoticon sets if they were requested.
+    pub fn chat_emoticon_images(&self, params: EmoticonImagesParams) -> Result<model::chat::EmoticonImages> {
+        let response = try!(self.http_client.get_content_with_params("/chat/emoticon_images", params));
+        let emoticon_images: model::chat::EmoticonImages = try!(model::from_json(&response, self.strict_mode));
+        Ok(emoticon_images)
+    }
+
+    /// Get chat badges for channel.
+    ///
+    /// Returns a list of chat badges that can be used in the channel's chat.
+    pub fn chat_badges<C: Into<IdOrName>>(&self, channel: C) -> Result<model::chat::Badges> {
+        let channel = try!(self.resolve_id_or_name(channel.into()));
+        let url = format!("/chat/{}/badges", channel);
+        let response = try!(self.http_client.get_content(&url));
+        let badges: model::chat::Badges = try!(model::from_json(&response, self.strict_mode));
+        Ok(badges)
+    }
+
+    /// Get global chat badges.
+    ///
+    /// Returns the badges that can be shown in the chat of every channel (e.g. "staff", "premium" or "bits"),
+    /// with their image urls per version.
+    pub fn chat_global_badges(&self) -> Result<model::chat::BadgeSets> {
+        let response = try!(self.http_client.get_content_from_link(GLOBAL_BADGES_URL));
+        let badge_sets: model::chat::BadgeSets = try!(model::from_json(&response, self.strict_mode));
+        Ok(badge_sets)
+    }
+
+    /// Get chat badges of channel.
+    ///
+    /// Returns the badges that are specific to the channel (e.g. the "subscriber" and "bits" badges),
+    /// with their image urls per version.
+    pub fn chat_channel_badges<C: Into<model::id::ChannelId>>(&self, channel_id: C) -> Result<model::chat::BadgeSets> {
+        let url = format!("{}/{}/display", CHANNEL_BADGES_BASE_URL, channel_id.into());
+        let response = try!(self.http_client.get_content_from_link(&url));
+        let badge_sets: model::chat::BadgeSets = try!(model::from_json(&response, self.strict_mode));
+        Ok(badge_sets)
+    }
+}
+
+
+/// Returns the page size for fetching up to `max_items` items with as few requests as possible.
+#[cfg(feature = "blocking")]
+fn page_limit(max_items: usize) -> u8 {
+    cmp::max(cmp::min(max_items, paging::MAX_LIMIT as usize), 1) as u8
+}
+
+
+
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs::File;
+    use std::io::Read;
+    use serde_json;
+
+    #[test]
+    fn test_twitch_client_should_be_clone_send_and_sync() {
+        fn assert_clone_send_sync<T: Clone + Send + Sync>() {}
+        assert_clone_send_sync::<TwitchClient>();
+    }
+
+    #[test]
+    fn test_builder_should_configure_connection_pool() {
+        let builder = TwitchClientBuilder::new("client_id")
+                .with_max_idle_connections_per_host(20)
+                .with_idle_timeout(Duration::from_secs(90))
+                .with_keep_alive(false);
+        assert_eq!(builder.transport_config.max_idle_connections_per_host, Some(20));
+        assert_eq!(builder.transport_config.idle_timeout, Some(Duration::from_secs(90)));
+        assert!(builder.transport_config.disable_keep_alive);
+        assert!(builder.build().is_ok());
+    }
+
+    #[cfg(feature = "hyper-transport")]
+    #[test]
+    fn test_builder_should_use_hyper_connector() {
+        use std::io;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use hyper;
+        use hyper::net::{HttpStream, NetworkConnector};
+
+        struct RefusingConnector(Arc<AtomicUsize>);
+
+        impl NetworkConnector for RefusingConnector {
+            type Stream = HttpStream;
+
+            fn connect(&self, _host: &str, _port: u16, _scheme: &str) -> hyper::Result<HttpStream> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                Err(hyper::Error::Io(io::Error::new(io::ErrorKind::ConnectionRefused, "refused by the test")))
+            }
+        }
+
+        let connections = Arc::new(AtomicUsize::new(0));
+        let client = TwitchClientBuilder::new("client_id")
+                .with_hyper_connector(RefusingConnector(connections.clone()))
+                .build()
+                .unwrap();
+        match client.top_games(TopGamesParams::default()) {
+            Err(Error::Io(ref error)) => assert_eq!(error.kind(), io::ErrorKind::ConnectionRefused),
+            result => panic!("expecting the io error of the connector, but got: {:?}", result),
+        }
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_top_games_with_default_params() {
+        let client = create_test_twitch_client();
+        let top_games = client.top_games(TopGamesParams::default()).unwrap();
+        assert!(top_games.total() > 0, "top_games.total() = {} > 0", top_games.total());
+        assert_eq!(top_games.top().len(), 10);
+    }
+
+    #[test]
+    fn test_top_games_with_custom_params() {
+        let client = create_test_twitch_client();
+        let params = TopGamesParams::new()
+                .with_offset(0)
+                .with_limit(2);
+        let top_games = client.top_games(params).unwrap();
+        assert!(top_games.total() > 0, "top_games.total() = {} > 0", top_games.total());
+        assert_eq!(top_games.top().len(), 2);
+    }
+
+    #[test]
+    fn test_top_games_iter() {
+        let client = create_test_twitch_client();
+        let params = TopGamesParams::new()
+                .with_limit(2);
+        let top_games: Vec<_> = client.top_games_iter(params).take(5).map(|game_info| game_info.unwrap()).collect();
+        assert_eq!(top_games.len(), 5);
+    }
+
+    #[test]
+    fn test_all_top_games() {
+        let client = create_test_twitch_client();
+        let top_games = client.all_top_games(150).unwrap();
+        assert!(top_games.len() <= 150, "top_games.len() = {} <= 150", top_games.len());
+        assert!(!top_games.is_empty(), "expecting top games");
+    }
+
+    #[test]
+    fn test_page_limit() {
+        assert_eq!(page_limit(150), 100);
+        assert_eq!(page_limit(42), 42);
+        assert_eq!(page_limit(0), 1);
+    }
+
+    #[test]
+    fn test_all_streams() {
+        let client = create_test_twitch_client();
+        let streams = client.all_streams(StreamsParams::new().with_limit(25), 60).unwrap();
+        assert_eq!(streams.len(), 60);
+    }
+
+    #[test]
+    fn test_find_game_exact() {
+        let client = create_test_twitch_client();
+        let game = client.find_game_exact("counter-strike: global offensive").unwrap();
+        assert_eq!(game.map(|game| game.name().clone()), Some("Counter-Strike: Global Offensive".to_owned()));
+    }
+
+    #[test]
+    fn test_streams_for_channels() {
+        let client = create_test_twitch_client();
+        let top_streams = client.streams(StreamsParams::new().with_limit(5)).unwrap();
+        let channels: Vec<&str> = top_streams.streams().iter()
+                .map(|stream| stream.channel().name().as_str())
+                .collect();
+        let streams = client.streams_for_channels(&channels).unwrap();
+        assert!(streams.streams().len() > 0, "streams.streams().len() = {} > 0", streams.streams().len());
+    }
+
+    #[test]
+    fn test_channels() {
+        let client = create_test_twitch_client();
+        let channels = client.channels(&["test_channel", "twitch"]).unwrap();
+        assert_eq!(channels.len(), 2);
+        assert_eq!(channels[1].name(), "twitch");
+    }
+
+    #[test]
+    fn test_next_page() {
+        let client = create_test_twitch_client();
+        let params = TopGamesParams::new()
+                .with_limit(2);
+        let top_games = client.top_games(params).unwrap();
+        let next_top_games = client.next_page(&top_games).unwrap().unwrap();
+        assert_eq!(next_top_games.top().len(), 2);
+        assert!(next_top_games.top()[0].game().id() != top_games.top()[0].game().id());
+    }
+
+    #[test]
+    fn test_ingests() {
+        let client = create_test_twitch_client();
+        let ingests = client.ingests().unwrap();
+        assert!(ingests.ingests().len() > 0, "ingests.ingests().len() = {} > 0", ingests.ingests().len());
+    }
+
+    #[test]
+    fn test_basic_info() {
+        let client = create_test_twitch_client();
+        let basic_info = client.basic_info().unwrap();
+        let token = basic_info.token();
+        assert!(!token.valid(), "expecting invalid token for unauthenticated access");
+        assert!(token.user_name().is_none(), "expecting no user name for unauthenticated access");
+        assert!(token.authorization().is_none(), "expecting no auth info for unauthenticated access");
+    }
+
+    #[test]
+    fn test_stream() {
+        let client = create_test_twitch_client();
+        let channel_stream = client.stream("test_channel").unwrap();
+        assert!(channel_stream.stream().is_none(), "expecting test channel stream to be offline");
     }
 
     #[test]
@@ -274,6 +1761,42 @@ mod tests {
         assert_eq!(streams.streams().len(), 2);
     }
 
+    #[test]
+    fn test_followed_streams_without_oauth_token() {
+        let client = create_test_twitch_client();
+        match client.followed_streams(FollowedStreamsParams::default()) {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "user_read"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_followed_clips_without_oauth_token() {
+        let client = create_test_twitch_client();
+        match client.followed_clips(ClipsParams::default()) {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "user_read"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_followed_videos_without_oauth_token() {
+        let client = create_test_twitch_client();
+        match client.followed_videos(FollowedVideosParams::default()) {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "user_read"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_followed_channels_without_oauth_token() {
+        let client = create_test_twitch_client();
+        match client.followed_channels(FollowsParams::default()) {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "user_read"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
     #[test]
     fn test_featured_streams_with_default_params() {
         let client = create_test_twitch_client();
@@ -307,21 +1830,255 @@ mod tests {
         client.streams_summary(params).unwrap();
     }
 
+    #[test]
+    fn test_get_json() {
+        let client = create_test_twitch_client();
+        let streams_summary = client.get_json("/streams/summary", &[("game", "Overwatch")]).unwrap();
+        assert!(streams_summary["viewers"].is_u64(), "streams_summary[\"viewers\"] should be a number");
+    }
+
     #[test]
     fn test_channel() {
         let client = create_test_twitch_client();
         let channel = client.channel("test_channel").unwrap();
         assert_eq!(channel.name(), "test_channel");
-        assert!(channel.url().find("test_channel").is_some(), "channel.url should contain \"test_channel\"");
+        assert!(channel.url().as_str().find("test_channel").is_some(), "channel.url should contain \"test_channel\"");
         assert!(channel.views() > 0, "channel.views() = {} > 0", channel.views());
         assert!(channel.followers() > 0, "channel.followers() = {} > 0", channel.followers());
+        assert!(channel.stream_key().is_none(), "expecting no stream key for unauthenticated access");
+    }
+
+    #[test]
+    fn test_channel_by_id_with_api_version_v3() {
+        let client = create_test_twitch_client();
+        match client.channel(12345u64) {
+            Err(error::Error::UnsupportedIdOrName(api_version, _)) => assert_eq!(api_version, ApiVersion::V3),
+            result => panic!("expecting unsupported id or name error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_channel_by_name_with_api_version_v5() {
+        let client = TwitchClientBuilder::new(read_test_client_id())
+                .with_api_version(ApiVersion::V5)
+                .build()
+                .unwrap();
+        match client.channel("test_channel") {
+            Err(error::Error::UnsupportedIdOrName(api_version, _)) => assert_eq!(api_version, ApiVersion::V5),
+            result => panic!("expecting unsupported id or name error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_base_url() {
+        let client = TwitchClientBuilder::new(read_test_client_id())
+                .with_base_url("http://127.0.0.1:1/kraken/")
+                .build()
+                .unwrap();
+        if let Ok(top_games) = client.top_games(TopGamesParams::default()) {
+            panic!("expecting error for unreachable base url, but got: {:?}", top_games);
+        }
+    }
+
+    #[test]
+    fn test_invalid_base_url() {
+        for base_url in &["localhost:8080/kraken", "not a url"] {
+            match TwitchClientBuilder::new("client").with_base_url(*base_url).build() {
+                Err(error::Error::InvalidUrl(url)) => assert_eq!(url, *base_url),
+                Err(error) => panic!("expecting invalid url error, but got: {:?}", error),
+                Ok(_) => panic!("expecting invalid url error for base url: {}", base_url),
+            }
+        }
+    }
+
+    #[test]
+    fn test_channel_editors_without_oauth_token() {
+        let client = create_test_twitch_client();
+        match client.channel_editors("test_channel") {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "channel_read"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_update_channel_without_oauth_token() {
+        let client = create_test_twitch_client();
+        let params = UpdateChannelParams::new()
+                .with_status("test status");
+        match client.update_channel("test_channel", params) {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "channel_editor"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_reset_stream_key_without_oauth_token() {
+        let client = create_test_twitch_client();
+        match client.reset_stream_key("test_channel") {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "channel_stream"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_run_commercial_with_invalid_length() {
+        let client = create_test_twitch_client();
+        match client.run_commercial("test_channel", 45) {
+            Err(error::Error::InvalidCommercialLength(length)) => assert_eq!(length, 45),
+            result => panic!("expecting invalid commercial length error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_channel_follows_with_default_params() {
+        let client = create_test_twitch_client();
+        let follows = client.channel_follows("test_channel", FollowsParams::default()).unwrap();
+        assert!(follows.total() > 0, "follows.total() = {} > 0", follows.total());
+    }
+
+    #[test]
+    fn test_channel_follows_with_custom_params() {
+        let client = create_test_twitch_client();
+        let params = FollowsParams::new()
+                .with_offset(0)
+                .with_limit(2)
+                .with_direction(Direction::Asc);
+        let follows = client.channel_follows("test_channel", params).unwrap();
+        assert_eq!(follows.follows().len(), 2);
+    }
+
+    #[test]
+    fn test_user_follows() {
+        let client = create_test_twitch_client();
+        let params = FollowsParams::new()
+                .with_limit(1);
+        let follows = client.user_follows("test_user1", params).unwrap();
+        for follow in follows.follows() {
+            assert!(follow.channel().is_some(), "expecting followed channel for follows of a user");
+        }
+    }
+
+    #[test]
+    fn test_user_follows_channel_without_follow_relationship() {
+        let client = create_test_twitch_client();
+        let follow = client.user_follows_channel("test_user1", "test_channel").unwrap();
+        assert!(follow.is_none(), "expecting test user to not follow the test channel");
+    }
+
+    #[test]
+    fn test_user_subscribed_to_channel_without_oauth_token() {
+        let client = create_test_twitch_client();
+        match client.user_subscribed_to_channel("test_user1", "test_channel") {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "user_subscriptions"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_follow_channel_without_oauth_token() {
+        let client = create_test_twitch_client();
+        match client.follow_channel("test_user1", "test_channel", false) {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "user_follows_edit"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_unfollow_channel_without_oauth_token() {
+        let client = create_test_twitch_client();
+        match client.unfollow_channel("test_user1", "test_channel") {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "user_follows_edit"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_blocks_without_oauth_token() {
+        let client = create_test_twitch_client();
+        match client.blocks("test_user1", BlocksParams::default()) {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "user_blocks_read"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_block_user_without_oauth_token() {
+        let client = create_test_twitch_client();
+        match client.block_user("test_user1", "test_user_troll") {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "user_blocks_edit"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_top_videos_with_custom_params() {
+        let client = create_test_twitch_client();
+        let params = TopVideosParams::new()
+                .with_limit(2)
+                .with_period(Period::All);
+        let top_videos = client.top_videos(params).unwrap();
+        assert_eq!(top_videos.videos().len(), 2);
+    }
+
+    #[test]
+    fn test_video() {
+        let client = create_test_twitch_client();
+        let top_videos = client.top_videos(TopVideosParams::new().with_limit(1)).unwrap();
+        let top_video = &top_videos.videos()[0];
+        let video = client.video(top_video.id()).unwrap();
+        assert_eq!(video.id(), top_video.id());
+    }
+
+    #[test]
+    fn test_channel_posts_with_custom_params() {
+        let client = create_test_twitch_client();
+        let params = PostsParams::new()
+                .with_limit(1);
+        let posts = client.channel_posts("test_channel", params).unwrap();
+        assert!(posts.posts().len() <= 1, "posts.posts().len() = {} <= 1", posts.posts().len());
+    }
+
+    #[test]
+    fn test_create_post_without_oauth_token() {
+        let client = create_test_twitch_client();
+        match client.create_post("test_channel", CreatePostParams::new("Kappa post")) {
+            Err(error::Error::Unauthorized(scope)) => assert_eq!(scope, "channel_feed_edit"),
+            result => panic!("expecting unauthorized error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_chat_emoticons() {
+        let client = create_test_twitch_client();
+        let emoticons = client.chat_emoticons().unwrap();
+        assert!(emoticons.emoticons().len() > 0, "emoticons.emoticons().len() = {} > 0", emoticons.emoticons().len());
+    }
+
+    #[test]
+    fn test_chat_emoticon_images_with_custom_params() {
+        let client = create_test_twitch_client();
+        let params = EmoticonImagesParams::new()
+                .with_emoticon_set(0);
+        let emoticon_images = client.chat_emoticon_images(params).unwrap();
+        assert!(emoticon_images.emoticon_sets().is_some(), "expecting emoticons grouped by emoticon sets");
+    }
+
+    #[test]
+    fn test_chat_badges() {
+        let client = create_test_twitch_client();
+        let badges = client.chat_badges("test_channel").unwrap();
+        assert!(badges.moderator().is_some(), "expecting a moderator badge");
     }
 
 
 
     fn create_test_twitch_client() -> TwitchClient {
+        TwitchClient::new(read_test_client_id()).unwrap()
+    }
+
+    pub fn read_test_client_id() -> String {
         let auth = read_auth();
-        TwitchClient::new(auth.client_id).unwrap()
+        auth.client_id
     }
 
     fn read_auth() -> Auth {
@@ -0,0 +1,94 @@
+//! Metrics of the requests.
+//!
+//! Implement `ClientMetrics` to record the latency and the status codes of the requests
+//! in a monitoring system (e.g. Prometheus or statsd).
+
+use std::sync::Arc;
+use std::time::Duration;
+
+
+/// Receives the outcome of every request that is sent to the Twitch API, including retried requests.
+///
+/// The callback is invoked on the thread that sent the request and should return quickly.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::time::Duration;
+/// use twitch_client::*;
+/// use twitch_client::metrics::ClientMetrics;
+///
+/// struct ErrorCounter {
+///     errors: AtomicUsize,
+/// }
+///
+/// impl ClientMetrics for ErrorCounter {
+///     fn on_request_complete(&self, _endpoint: &str, status: Option<u16>, _duration: Duration) {
+///         if status.map_or(true, |status| status >= 400) {
+///             self.errors.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+///
+/// let twitch_client = TwitchClientBuilder::new("<YOUR_TWITCH_CLIENT_ID>")
+///         .with_metrics(ErrorCounter { errors: AtomicUsize::new(0) })
+///         .build()
+///         .unwrap();
+/// ```
+pub trait ClientMetrics: Send + Sync {
+    /// Called after a response was received or the request failed.
+    ///
+    /// The `endpoint` is the path of the request url (e.g. "/kraken/streams"),
+    /// which may contain channel or user names and ids.
+    /// The `status` is `None` if no response was received at all (e.g. connection failures).
+    fn on_request_complete(&self, endpoint: &str, status: Option<u16>, duration: Duration);
+}
+
+impl<M: ClientMetrics + ?Sized> ClientMetrics for Arc<M> {
+    fn on_request_complete(&self, endpoint: &str, status: Option<u16>, duration: Duration) {
+        (**self).on_request_complete(endpoint, status, duration)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use error::Result;
+    use transport::{Transport, Request, Response};
+    use TwitchClientBuilder;
+
+    struct RecordingMetrics {
+        requests: Mutex<Vec<(String, Option<u16>)>>,
+    }
+
+    impl ClientMetrics for RecordingMetrics {
+        fn on_request_complete(&self, endpoint: &str, status: Option<u16>, _duration: Duration) {
+            self.requests.lock().unwrap().push((endpoint.to_owned(), status));
+        }
+    }
+
+    struct IngestsTransport;
+
+    impl Transport for IngestsTransport {
+        fn send(&self, _request: Request) -> Result<Response> {
+            Ok(Response::new(200, Vec::new(), r#"{"_links":{},"ingests":[]}"#.to_owned()))
+        }
+    }
+
+    #[test]
+    fn test_should_record_requests() {
+        let metrics = Arc::new(RecordingMetrics { requests: Mutex::new(Vec::new()) });
+        let client = TwitchClientBuilder::new("client_id")
+                .with_transport(IngestsTransport)
+                .with_metrics(metrics.clone())
+                .build()
+                .unwrap();
+
+        client.ingests().unwrap();
+
+        assert_eq!(*metrics.requests.lock().unwrap(), vec![("/kraken/ingests".to_owned(), Some(200))]);
+    }
+}
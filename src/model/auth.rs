@@ -0,0 +1,60 @@
+//! Twitch OAuth tokens.
+//!
+//! Access tokens authorize requests on behalf of a user for the granted scopes.
+
+pub use model::scope::Scope;
+use model::scope::deserialize_known_scopes;
+
+
+/// Access token obtained by exchanging an authorization code.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "access_token": "0123456789abcdefghijABCDEFGHIJ",
+///   "refresh_token": "eyJfaWQmNzMtNGCJ9%6VFV5LNrZFUj8oU231/3Aj",
+///   "expires_in": 3600,
+///   "scope": [
+///     "user_read",
+///     "channel_read"
+///   ],
+///   "token_type": "bearer"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct AccessToken {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_in: Option<u64>,
+    #[serde(default, deserialize_with="deserialize_known_scopes")]
+    scope: Vec<Scope>,
+    token_type: Option<String>,
+}
+
+
+impl AccessToken {
+    /// Example value: "0123456789abcdefghijABCDEFGHIJ"
+    pub fn access_token(&self) -> &String {
+        &self.access_token
+    }
+    /// Example value: "eyJfaWQmNzMtNGCJ9%6VFV5LNrZFUj8oU231/3Aj"
+    pub fn refresh_token(&self) -> &Option<String> {
+        &self.refresh_token
+    }
+    /// Example value: 3600
+    ///
+    /// Number of seconds until the access token expires.
+    pub fn expires_in(&self) -> Option<u64> {
+        self.expires_in
+    }
+    /// Example value: [`Scope::UserRead`, `Scope::ChannelRead`]
+    pub fn scope(&self) -> &Vec<Scope> {
+        &self.scope
+    }
+    /// Example value: "bearer"
+    pub fn token_type(&self) -> &Option<String> {
+        &self.token_type
+    }
+}
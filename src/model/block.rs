@@ -0,0 +1,90 @@
+//! Twitch blocks.
+//!
+//! Users can block (ignore) other users to hide their chat messages and whispers.
+
+pub use model::DateTime;
+use model::deserialize_date_time;
+pub use model::user::User;
+pub use model::{Links, TwitchLinks};
+use model::deserialize_links;
+
+
+/// List of users that are blocked by a user.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_links": {
+///     "next": "https://api.twitch.tv/kraken/users/test_user1/blocks?limit=25&offset=25",
+///     "self": "https://api.twitch.tv/kraken/users/test_user1/blocks?limit=25&offset=0"
+///   },
+///   "blocks": [
+///     {
+///       // See `Block` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Blocks {
+    blocks: Vec<Block>,
+    #[serde(rename="_links", default, deserialize_with="deserialize_links")]
+    links: Links,
+}
+
+/// Blocked user.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_links": {
+///     "self": "https://api.twitch.tv/kraken/users/test_user1/blocks/test_user_troll"
+///   },
+///   "updated_at": "2013-02-07T01:04:43Z",
+///   "user": {
+///     // See `User` type
+///   },
+///   "_id": 287813
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Block {
+    #[serde(rename="_id")]
+    id: u64,
+    #[serde(deserialize_with="deserialize_date_time")]
+    updated_at: DateTime,
+    user: User,
+}
+
+
+impl Blocks {
+    /// Example value: See `Block` type.
+    pub fn blocks(&self) -> &Vec<Block> {
+        &self.blocks
+    }
+}
+
+impl TwitchLinks for Blocks {
+    fn links(&self) -> &Links {
+        &self.links
+    }
+}
+
+impl Block {
+    /// Example value: 287813
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+    /// Example value: "2013-02-07T01:04:43Z"
+    pub fn updated_at(&self) -> &DateTime {
+        &self.updated_at
+    }
+    /// Example value: See `User` type.
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+}
@@ -0,0 +1,288 @@
+//! Streams that borrow their strings from the response body.
+//!
+//! Deserializing the owned `stream::Streams` allocates a `String` for every text field of every stream,
+//! which adds up for applications that poll thousands of streams.
+//! The models of this module borrow the text fields from the JSON instead (`Cow::Borrowed`)
+//! and only allocate for strings that contain escape sequences (`Cow::Owned`).
+//! They contain the fields that are usually needed when polling streams and ignore the others,
+//! so they are always deserialized leniently (see `StrictMode`).
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use twitch_client::*;
+//!
+//! let twitch_client = TwitchClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap();
+//! let mut buffer = String::new();
+//! let streams = twitch_client.streams_borrowed(StreamsParams::new().with_limit(100), &mut buffer).unwrap();
+//! for stream in streams.streams() {
+//!     println!("{} is playing {:?} for {} viewers", stream.channel().name(), stream.game(), stream.viewers());
+//! }
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+
+use serde::{Deserialize, Deserializer};
+use serde::de::{Error as DeError, Visitor};
+
+use model::de::{number_or_string, optional_number_or_string};
+pub use model::id::{ChannelId, StreamId};
+
+
+/// Borrowed variant of `stream::Streams`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Streams<'a> {
+    #[serde(rename="_total")]
+    total: u32,
+    #[serde(borrow)]
+    streams: Vec<Stream<'a>>,
+}
+
+/// Borrowed variant of `stream::Stream`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Stream<'a> {
+    #[serde(rename="_id")]
+    id: StreamId,
+    #[serde(borrow, default, deserialize_with="empty_str_as_none")]
+    game: Option<Cow<'a, str>>,
+    #[serde(deserialize_with="number_or_string")]
+    viewers: u32,
+    #[serde(default, deserialize_with="number_or_string")]
+    average_fps: f64,
+    #[serde(default, deserialize_with="optional_number_or_string")]
+    delay: Option<u32>,
+    #[serde(default, deserialize_with="number_or_string")]
+    video_height: u16,
+    #[serde(default)]
+    is_playlist: bool,
+    #[serde(borrow, default, deserialize_with="empty_str_as_none")]
+    stream_type: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    created_at: Cow<'a, str>,
+    #[serde(borrow)]
+    channel: Channel<'a>,
+}
+
+/// Borrowed variant of `channel::Channel`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Channel<'a> {
+    #[serde(rename="_id")]
+    id: ChannelId,
+    #[serde(borrow)]
+    name: Cow<'a, str>,
+    #[serde(borrow)]
+    display_name: Cow<'a, str>,
+    #[serde(borrow, default, deserialize_with="empty_str_as_none")]
+    game: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with="empty_str_as_none")]
+    status: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    language: Cow<'a, str>,
+    #[serde(borrow, default, deserialize_with="empty_str_as_none")]
+    logo: Option<Cow<'a, str>>,
+    #[serde(default)]
+    partner: bool,
+    #[serde(borrow)]
+    url: Cow<'a, str>,
+    #[serde(default, deserialize_with="number_or_string")]
+    views: u32,
+    #[serde(default, deserialize_with="number_or_string")]
+    followers: u32,
+}
+
+
+impl<'a> Streams<'a> {
+    /// Example value: 12345
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+    /// Example value: See `Stream` type.
+    pub fn streams(&self) -> &Vec<Stream<'a>> {
+        &self.streams
+    }
+}
+
+impl<'a> Stream<'a> {
+    /// Example value: 4989654544
+    pub fn id(&self) -> StreamId {
+        self.id
+    }
+    /// Example value: "StarCraft II: Heart of the Swarm"
+    pub fn game(&self) -> Option<&str> {
+        self.game.as_ref().map(|game| game.as_ref())
+    }
+    /// Example value: 2123
+    pub fn viewers(&self) -> u32 {
+        self.viewers
+    }
+    /// Example value: 29.9880749574
+    pub fn average_fps(&self) -> f64 {
+        self.average_fps
+    }
+    /// Example value: 0
+    pub fn delay(&self) -> Option<u32> {
+        self.delay
+    }
+    /// Example value: 720
+    pub fn video_height(&self) -> u16 {
+        self.video_height
+    }
+    /// Example value: false
+    pub fn is_playlist(&self) -> bool {
+        self.is_playlist
+    }
+    /// Example value: "live"
+    pub fn stream_type(&self) -> Option<&str> {
+        self.stream_type.as_ref().map(|stream_type| stream_type.as_ref())
+    }
+    /// Unparsed date in RFC 3339 format.
+    ///
+    /// Example value: "2015-02-12T04:42:31Z"
+    pub fn created_at(&self) -> &str {
+        &self.created_at
+    }
+    /// Example value: See `Channel` type.
+    pub fn channel(&self) -> &Channel<'a> {
+        &self.channel
+    }
+}
+
+impl<'a> Channel<'a> {
+    /// Example value: 12345
+    pub fn id(&self) -> ChannelId {
+        self.id
+    }
+    /// Example value: "test_channel"
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Example value: "test_channel"
+    pub fn display_name(&self) -> &str {
+        &self.display_name
+    }
+    /// Example value: "Gaming Talk Shows"
+    pub fn game(&self) -> Option<&str> {
+        self.game.as_ref().map(|game| game.as_ref())
+    }
+    /// Example value: "test status"
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_ref().map(|status| status.as_ref())
+    }
+    /// Example value: "en"
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+    /// Example value: "http://static-cdn.jtvnw.net/jtv_user_pictures/test_channel-profile_image-94a42b3a13c31c02-300x300.jpeg"
+    pub fn logo(&self) -> Option<&str> {
+        self.logo.as_ref().map(|logo| logo.as_ref())
+    }
+    /// Example value: true
+    pub fn partner(&self) -> bool {
+        self.partner
+    }
+    /// Example value: "http://www.twitch.tv/test_channel"
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+    /// Example value: 49144894
+    pub fn views(&self) -> u32 {
+        self.views
+    }
+    /// Example value: 215780
+    pub fn followers(&self) -> u32 {
+        self.followers
+    }
+}
+
+
+/// String that is borrowed from the input if it contains no escape sequences.
+struct BorrowedStr<'a>(Cow<'a, str>);
+
+impl<'de: 'a, 'a> Deserialize<'de> for BorrowedStr<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<BorrowedStr<'a>, D::Error> {
+        deserializer.deserialize_str(BorrowedStrVisitor).map(BorrowedStr)
+    }
+}
+
+struct BorrowedStrVisitor;
+
+impl<'de> Visitor<'de> for BorrowedStrVisitor {
+    type Value = Cow<'de, str>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_borrowed_str<E: DeError>(self, value: &'de str) -> Result<Cow<'de, str>, E> {
+        Ok(Cow::Borrowed(value))
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Cow<'de, str>, E> {
+        Ok(Cow::Owned(value.to_owned()))
+    }
+
+    fn visit_string<E: DeError>(self, value: String) -> Result<Cow<'de, str>, E> {
+        Ok(Cow::Owned(value))
+    }
+}
+
+/// Deserializes `null` and blank strings as `None` and borrows the other strings if possible.
+fn empty_str_as_none<'de: 'a, 'a, D: Deserializer<'de>>(deserializer: D)
+        -> Result<Option<Cow<'a, str>>, D::Error> {
+    let value: Option<BorrowedStr<'a>> = try!(Option::deserialize(deserializer));
+    Ok(value.map(|value| value.0).filter(|value| !value.trim().is_empty()))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    use model::stream;
+
+    const STREAMS: &'static str = include_str!("../testing/fixtures/streams.json");
+
+    #[test]
+    fn test_borrowed_streams_should_match_owned_streams() {
+        let owned: stream::Streams = serde_json::from_str(STREAMS).unwrap();
+        let borrowed: Streams = serde_json::from_str(STREAMS).unwrap();
+        assert_eq!(borrowed.total(), owned.total());
+        assert_eq!(borrowed.streams().len(), owned.streams().len());
+
+        let (owned_stream, borrowed_stream) = (&owned.streams()[0], &borrowed.streams()[0]);
+        assert_eq!(borrowed_stream.id(), owned_stream.id());
+        assert_eq!(borrowed_stream.game(), owned_stream.game().as_ref().map(|game| game.as_str()));
+        assert_eq!(borrowed_stream.viewers(), owned_stream.viewers());
+        assert_eq!(borrowed_stream.channel().name(), owned_stream.channel().name());
+        assert_eq!(borrowed_stream.channel().status(), Some("test status"));
+        assert!(borrowed_stream.channel().logo().is_some());
+    }
+
+    #[test]
+    fn test_borrowed_streams_should_only_copy_escaped_strings() {
+        let json = r#"{
+            "_id": 12345,
+            "name": "test_channel",
+            "display_name": "Test \"Channel\"",
+            "game": "",
+            "status": null,
+            "language": "en",
+            "url": "http://www.twitch.tv/test_channel"
+        }"#;
+        let channel: Channel = serde_json::from_str(json).unwrap();
+        match channel.name {
+            Cow::Borrowed(name) => assert_eq!(name, "test_channel"),
+            Cow::Owned(_) => panic!("name should be borrowed"),
+        }
+        match channel.display_name {
+            Cow::Owned(ref display_name) => assert_eq!(display_name, "Test \"Channel\""),
+            Cow::Borrowed(_) => panic!("escaped display_name cannot be borrowed"),
+        }
+        assert_eq!(channel.game(), None);
+        assert_eq!(channel.status(), None);
+    }
+}
@@ -4,9 +4,22 @@
 //! Channels have a stream, can run commercials, store videos, display information and status,
 //! and have a customized page including banners and backgrounds.
 
-pub use model::UrlString;
-pub use model::DateString;
+pub use model::Url;
+pub use model::id::ChannelId;
+pub use model::{Links, TwitchLinks};
+use model::deserialize_links;
+pub use model::DateTime;
+use model::deserialize_date_time;
+use model::de::{empty_string_as_none, null_string_as_none, number_or_string, optional_number_or_string};
+#[cfg(feature = "chrono")]
+use model::duration_between;
+#[cfg(feature = "chrono")]
+use std::time::Duration;
 pub use model::LocaleString;
+pub use model::language::Language;
+pub use model::user::User;
+#[cfg(feature = "model-builders")]
+use model::{url_from_str, unix_epoch};
 
 
 /// Channel information.
@@ -36,6 +49,7 @@ pub use model::LocaleString;
 ///   "url": "http://www.twitch.tv/test_channel",
 ///   "views": 49144894,
 ///   "followers": 215780,
+///   "stream_key": "live_21229404_abcdefg",
 ///   "_links": {
 ///     "self": "https://api.twitch.tv/kraken/channels/test_channel",
 ///     "follows": "https://api.twitch.tv/kraken/channels/test_channel/follows",
@@ -50,36 +64,96 @@ pub use model::LocaleString;
 ///   }
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct Channel {
     #[serde(rename="_id")]
-    id: u64,
+    id: ChannelId,
     name: String,
     display_name: String,
+    #[serde(default, deserialize_with="empty_string_as_none")]
     game: Option<String>,
+    #[serde(default, deserialize_with="empty_string_as_none")]
     status: Option<String>,
     mature: Option<bool>,
+    #[serde(default, deserialize_with="optional_number_or_string")]
     delay: Option<u32>,
     language: LocaleString,
+    #[serde(default, deserialize_with="empty_string_as_none")]
     broadcaster_language: Option<LocaleString>,
-    created_at: DateString,
-    updated_at: DateString,
-    logo: Option<UrlString>,
-    banner: Option<UrlString>,
-    video_banner: Option<UrlString>,
-    background: Option<UrlString>,
-    profile_banner: Option<UrlString>,
-    profile_banner_background_color: Option<UrlString>,
+    #[serde(deserialize_with="deserialize_date_time")]
+    created_at: DateTime,
+    #[serde(deserialize_with="deserialize_date_time")]
+    updated_at: DateTime,
+    #[serde(default, deserialize_with="empty_string_as_none")]
+    logo: Option<Url>,
+    #[serde(default, deserialize_with="empty_string_as_none")]
+    banner: Option<Url>,
+    #[serde(default, deserialize_with="empty_string_as_none")]
+    video_banner: Option<Url>,
+    #[serde(default, deserialize_with="empty_string_as_none")]
+    background: Option<Url>,
+    #[serde(default, deserialize_with="empty_string_as_none")]
+    profile_banner: Option<Url>,
+    #[serde(default, deserialize_with="null_string_as_none")]
+    profile_banner_background_color: Option<Url>,
+    #[serde(default)]
     partner: bool,
-    url: UrlString,
+    url: Url,
+    #[serde(default, deserialize_with="number_or_string")]
     views: u32,
+    #[serde(default, deserialize_with="number_or_string")]
     followers: u32,
+    stream_key: Option<String>,
+    #[serde(rename="_links", default, deserialize_with="deserialize_links")]
+    links: Links,
+}
+
+/// Builder of `Channel` values that were not sent by Twitch (e.g. in tests of downstream crates).
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::model::channel::ChannelBuilder;
+///
+/// let channel = ChannelBuilder::new(12345, "test_channel")
+///         .with_game("Gaming Talk Shows")
+///         .with_status("test status")
+///         .build();
+/// assert_eq!(channel.name(), "test_channel");
+/// ```
+#[cfg(feature = "model-builders")]
+#[derive(Debug, Clone)]
+pub struct ChannelBuilder {
+    channel: Channel,
+}
+
+/// Users that are editors of a channel.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_links": {
+///     "self": "https://api.twitch.tv/kraken/channels/test_channel/editors"
+///   },
+///   "users": [
+///     {
+///       // See `User` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Editors {
+    users: Vec<User>,
 }
 
 
 impl Channel {
     /// Example value: 12345
-    pub fn id(&self) -> u64 {
+    pub fn id(&self) -> ChannelId {
         self.id
     }
     /// Example value: "test_channel"
@@ -115,35 +189,42 @@ impl Channel {
         &self.broadcaster_language
     }
     /// Example value: "2007-05-22T10:39:54Z"
-    pub fn created_at(&self) -> &DateString {
+    pub fn created_at(&self) -> &DateTime {
         &self.created_at
     }
+    /// Returns the age of the channel at `now` (e.g. `Utc::now()`), computed from `created_at`.
+    #[cfg(feature = "chrono")]
+    pub fn age(&self, now: DateTime) -> Duration {
+        duration_between(&self.created_at, &now)
+    }
     /// Example value: "2015-02-12T04:15:49Z"
-    pub fn updated_at(&self) -> &DateString {
+    pub fn updated_at(&self) -> &DateTime {
         &self.updated_at
     }
     /// Example value: "http://static-cdn.jtvnw.net/jtv_user_pictures/test_channel-profile_image-94a42b3a13c31c02-300x300.jpeg"
-    pub fn logo(&self) -> &Option<UrlString> {
+    pub fn logo(&self) -> &Option<Url> {
         &self.logo
     }
     /// Example value: "http://static-cdn.jtvnw.net/jtv_user_pictures/test_channel-channel_header_image-08dd874c17f39837-640x125.png"
-    pub fn banner(&self) -> &Option<UrlString> {
+    pub fn banner(&self) -> &Option<Url> {
         &self.banner
     }
     /// Example value: "http://static-cdn.jtvnw.net/jtv_user_pictures/test_channel-channel_offline_image-b314c834d210dc1a-640x360.png"
-    pub fn video_banner(&self) -> &Option<UrlString> {
+    pub fn video_banner(&self) -> &Option<Url> {
         &self.video_banner
     }
     /// Example value: `None`
-    pub fn background(&self) -> &Option<UrlString> {
+    pub fn background(&self) -> &Option<Url> {
         &self.background
     }
     /// Example value: "http://static-cdn.jtvnw.net/jtv_user_pictures/test_channel-profile_banner-6936c61353e4aeed-480.png"
-    pub fn profile_banner(&self) -> &Option<UrlString> {
+    pub fn profile_banner(&self) -> &Option<Url> {
         &self.profile_banner
     }
-    /// Example value: "null"
-    pub fn profile_banner_background_color(&self) -> &Option<UrlString> {
+    /// Example value: "#6441a5"
+    ///
+    /// `None` if Twitch sent the literal string "null".
+    pub fn profile_banner_background_color(&self) -> &Option<Url> {
         &self.profile_banner_background_color
     }
     /// Example value: true
@@ -151,7 +232,7 @@ impl Channel {
         self.partner
     }
     /// Example value: "http://www.twitch.tv/test_channel"
-    pub fn url(&self) -> &UrlString {
+    pub fn url(&self) -> &Url {
         &self.url
     }
     /// Example value: 49144894
@@ -162,4 +243,148 @@ impl Channel {
     pub fn followers(&self) -> u32 {
         self.followers
     }
+    /// Only set if the channel was retrieved with an OAuth token of the channel owner.
+    ///
+    /// Example value: "live_21229404_abcdefg"
+    pub fn stream_key(&self) -> &Option<String> {
+        &self.stream_key
+    }
+}
+
+impl TwitchLinks for Channel {
+    fn links(&self) -> &Links {
+        &self.links
+    }
+}
+
+impl Editors {
+    /// Example value: See `User` type.
+    pub fn users(&self) -> &Vec<User> {
+        &self.users
+    }
+}
+
+#[cfg(feature = "model-builders")]
+impl ChannelBuilder {
+    /// Constructs a builder of an english channel without game and status.
+    ///
+    /// The display name is the name and the url is "https://www.twitch.tv/{name}".
+    pub fn new<I: Into<ChannelId>>(id: I, name: &str) -> ChannelBuilder {
+        ChannelBuilder {
+            channel: Channel {
+                id: id.into(),
+                name: name.to_owned(),
+                display_name: name.to_owned(),
+                game: None,
+                status: None,
+                mature: None,
+                delay: None,
+                language: Language::English,
+                broadcaster_language: None,
+                created_at: unix_epoch(),
+                updated_at: unix_epoch(),
+                logo: None,
+                banner: None,
+                video_banner: None,
+                background: None,
+                profile_banner: None,
+                profile_banner_background_color: None,
+                partner: false,
+                url: url_from_str(&format!("https://www.twitch.tv/{}", name)),
+                views: 0,
+                followers: 0,
+                stream_key: None,
+                links: Links::new(),
+            },
+        }
+    }
+    /// Sets the display name.
+    pub fn with_display_name(mut self, display_name: &str) -> ChannelBuilder {
+        self.channel.display_name = display_name.to_owned();
+        self
+    }
+    /// Sets the game (name).
+    pub fn with_game(mut self, game: &str) -> ChannelBuilder {
+        self.channel.game = Some(game.to_owned());
+        self
+    }
+    /// Sets the status (title).
+    pub fn with_status(mut self, status: &str) -> ChannelBuilder {
+        self.channel.status = Some(status.to_owned());
+        self
+    }
+    /// Sets whether the channel is for mature audiences.
+    pub fn with_mature(mut self, mature: bool) -> ChannelBuilder {
+        self.channel.mature = Some(mature);
+        self
+    }
+    /// Sets the delay in seconds.
+    pub fn with_delay(mut self, delay: u32) -> ChannelBuilder {
+        self.channel.delay = Some(delay);
+        self
+    }
+    /// Sets the language and the broadcaster language.
+    pub fn with_language(mut self, language: Language) -> ChannelBuilder {
+        self.channel.broadcaster_language = Some(language.clone());
+        self.channel.language = language;
+        self
+    }
+    /// Sets the creation date.
+    pub fn with_created_at(mut self, created_at: DateTime) -> ChannelBuilder {
+        self.channel.created_at = created_at;
+        self
+    }
+    /// Sets the date of the last update.
+    pub fn with_updated_at(mut self, updated_at: DateTime) -> ChannelBuilder {
+        self.channel.updated_at = updated_at;
+        self
+    }
+    /// Sets the logo (url).
+    pub fn with_logo(mut self, logo: &str) -> ChannelBuilder {
+        self.channel.logo = Some(url_from_str(logo));
+        self
+    }
+    /// Sets whether the channel is a partner.
+    pub fn with_partner(mut self, partner: bool) -> ChannelBuilder {
+        self.channel.partner = partner;
+        self
+    }
+    /// Sets the number of views.
+    pub fn with_views(mut self, views: u32) -> ChannelBuilder {
+        self.channel.views = views;
+        self
+    }
+    /// Sets the number of followers.
+    pub fn with_followers(mut self, followers: u32) -> ChannelBuilder {
+        self.channel.followers = followers;
+        self
+    }
+    /// Builds the channel.
+    pub fn build(self) -> Channel {
+        self.channel
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+
+    #[test]
+    fn test_should_deserialize_inconsistent_values() {
+        let channel: Channel = serde_json::from_str(
+            include_str!("../testing/fixtures/channel_inconsistent_nulls.json")).unwrap();
+        assert_eq!(channel.id().value(), 12345);
+        assert_eq!(channel.game(), &None);
+        assert_eq!(channel.status(), &None);
+        assert_eq!(channel.broadcaster_language(), &None);
+        assert_eq!(channel.delay(), Some(0));
+        assert_eq!(channel.logo(), &None);
+        assert_eq!(channel.video_banner(), &None);
+        assert_eq!(channel.profile_banner(), &None);
+        assert_eq!(channel.profile_banner_background_color(), &None);
+        assert_eq!(channel.views(), 49144894);
+        assert_eq!(channel.followers(), 0);
+    }
 }
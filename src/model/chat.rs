@@ -0,0 +1,514 @@
+//! Twitch chat.
+//!
+//! Emoticons and badges that are used in the chat of channels.
+//!
+//! The images are served by the Twitch CDN, whose urls can be built with `emote_url` and `badge_image_url`
+//! from the ids in chat messages (e.g. the `emotes` and `badges` tags of IRC messages).
+
+use std::collections::HashMap;
+
+pub use model::Url;
+
+
+const EMOTE_BASE_URL: &'static str = "https://static-cdn.jtvnw.net/emoticons/v1";
+const BADGE_BASE_URL: &'static str = "https://static-cdn.jtvnw.net/badges/v1";
+
+
+/// List of all emoticons.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_links": {
+///     "self": "https://api.twitch.tv/kraken/chat/emoticons"
+///   },
+///   "emoticons": [
+///     {
+///       // See `Emoticon` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Emoticons {
+    emoticons: Vec<Emoticon>,
+}
+
+/// Emoticon with its regular expression and images.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "regex": "\\:-?\\)",
+///   "images": [
+///     {
+///       // See `EmoticonImage` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Emoticon {
+    regex: String,
+    images: Vec<EmoticonImage>,
+}
+
+/// Image of an emoticon.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "emoticon_set": null,
+///   "height": 18,
+///   "width": 24,
+///   "url": "http://static-cdn.jtvnw.net/jtv_user_pictures/chansub-global-emoticon-ebf60cd72f7aa600-24x18.png"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct EmoticonImage {
+    emoticon_set: Option<u64>,
+    height: u16,
+    width: u16,
+    url: Url,
+}
+
+/// Emoticon codes and ids, either as a plain list or grouped by emoticon sets.
+///
+/// # Example in JSON without emoticon sets
+///
+/// ```json
+/// {
+///   "emoticons": [
+///     {
+///       // See `EmoticonCode` type
+///     }
+///   ]
+/// }
+/// ```
+///
+/// # Example in JSON with emoticon sets
+///
+/// ```json
+/// {
+///   "emoticon_sets": {
+///     "19151": [
+///       {
+///         // See `EmoticonCode` type
+///       }
+///     ]
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct EmoticonImages {
+    emoticons: Option<Vec<EmoticonCode>>,
+    emoticon_sets: Option<HashMap<String, Vec<EmoticonCode>>>,
+}
+
+/// Emoticon code and id.
+///
+/// The emoticon image can be retrieved with the id from
+/// "http://static-cdn.jtvnw.net/emoticons/v1/{id}/1.0" (see `EmoticonCode::url`).
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": 24687,
+///   "code": "BORT",
+///   "emoticon_set": 33
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct EmoticonCode {
+    id: u64,
+    code: String,
+    emoticon_set: Option<u64>,
+}
+
+/// Chat badges of a channel.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "global_mod": {
+///     // See `Badge` type
+///   },
+///   "admin": {
+///     // See `Badge` type
+///   },
+///   "broadcaster": {
+///     // See `Badge` type
+///   },
+///   "mod": {
+///     // See `Badge` type
+///   },
+///   "staff": {
+///     // See `Badge` type
+///   },
+///   "turbo": {
+///     // See `Badge` type
+///   },
+///   "subscriber": null,
+///   "_links": {
+///     "self": "https://api.twitch.tv/kraken/chat/test_channel/badges"
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Badges {
+    global_mod: Option<Badge>,
+    admin: Option<Badge>,
+    broadcaster: Option<Badge>,
+    #[serde(rename="mod")]
+    moderator: Option<Badge>,
+    staff: Option<Badge>,
+    turbo: Option<Badge>,
+    subscriber: Option<Badge>,
+}
+
+/// Chat badge images.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "alpha": "http://chat-badges.s3.amazonaws.com/globalmod-alpha.png",
+///   "image": "http://chat-badges.s3.amazonaws.com/globalmod.png",
+///   "svg": "http://chat-badges.s3.amazonaws.com/globalmod.svg"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Badge {
+    alpha: Option<Url>,
+    image: Url,
+    svg: Option<Url>,
+}
+
+/// Size of chat images on the Twitch CDN.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum ChatImageScale {
+    /// Size in the chat (e.g. 28x28 pixels for emotes and 18x18 pixels for badges).
+    Small,
+    /// Twice the size for high DPI displays.
+    Medium,
+    /// Largest size (three times the size for emotes, four times for badges).
+    Large,
+}
+
+/// Badges that are shown in all channels (e.g. "staff" or "premium") or in one channel (e.g. "subscriber"),
+/// grouped by badge set and version.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "badge_sets": {
+///     "subscriber": {
+///       "versions": {
+///         "0": {
+///           // See `BadgeVersion` type
+///         }
+///       }
+///     }
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct BadgeSets {
+    badge_sets: HashMap<String, BadgeSet>,
+}
+
+/// Versions of a badge (e.g. the subscriber badges for the number of months).
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "versions": {
+///     "0": {
+///       // See `BadgeVersion` type
+///     }
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct BadgeSet {
+    versions: HashMap<String, BadgeVersion>,
+}
+
+/// Images and description of a badge version.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "image_url_1x": "https://static-cdn.jtvnw.net/badges/v1/5d9f2208-5dd8-11e7-8513-2ff4adfae661/1",
+///   "image_url_2x": "https://static-cdn.jtvnw.net/badges/v1/5d9f2208-5dd8-11e7-8513-2ff4adfae661/2",
+///   "image_url_4x": "https://static-cdn.jtvnw.net/badges/v1/5d9f2208-5dd8-11e7-8513-2ff4adfae661/3",
+///   "description": "Subscriber",
+///   "title": "Subscriber",
+///   "click_action": "subscribe_to_channel",
+///   "click_url": ""
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct BadgeVersion {
+    image_url_1x: Url,
+    image_url_2x: Url,
+    image_url_4x: Url,
+    description: String,
+    title: String,
+    #[serde(default)]
+    click_action: Option<String>,
+    #[serde(default)]
+    click_url: Option<String>,
+}
+
+
+/// Returns the CDN url of the emote image (e.g. "https://static-cdn.jtvnw.net/emoticons/v1/25/1.0").
+pub fn emote_url(emote_id: u64, scale: ChatImageScale) -> String {
+    let scale = match scale {
+        ChatImageScale::Small => "1.0",
+        ChatImageScale::Medium => "2.0",
+        ChatImageScale::Large => "3.0",
+    };
+    format!("{}/{}/{}", EMOTE_BASE_URL, emote_id, scale)
+}
+
+/// Returns the CDN url of the badge image with the image id
+/// (e.g. "https://static-cdn.jtvnw.net/badges/v1/5d9f2208-5dd8-11e7-8513-2ff4adfae661/1").
+///
+/// The image id is the last but one path segment of the image urls of a `BadgeVersion`.
+pub fn badge_image_url(image_id: &str, scale: ChatImageScale) -> String {
+    let scale = match scale {
+        ChatImageScale::Small => "1",
+        ChatImageScale::Medium => "2",
+        ChatImageScale::Large => "3",
+    };
+    format!("{}/{}/{}", BADGE_BASE_URL, image_id, scale)
+}
+
+
+impl Emoticons {
+    /// Example value: See `Emoticon` type.
+    pub fn emoticons(&self) -> &Vec<Emoticon> {
+        &self.emoticons
+    }
+}
+
+impl Emoticon {
+    /// Example value: "\\:-?\\)"
+    pub fn regex(&self) -> &String {
+        &self.regex
+    }
+    /// Example value: See `EmoticonImage` type.
+    pub fn images(&self) -> &Vec<EmoticonImage> {
+        &self.images
+    }
+}
+
+impl EmoticonImage {
+    /// Example value: `None`
+    pub fn emoticon_set(&self) -> Option<u64> {
+        self.emoticon_set
+    }
+    /// Example value: 18
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+    /// Example value: 24
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+    /// Example value: "http://static-cdn.jtvnw.net/jtv_user_pictures/chansub-global-emoticon-ebf60cd72f7aa600-24x18.png"
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+}
+
+impl EmoticonImages {
+    /// Only set if no emoticon sets were requested.
+    ///
+    /// Example value: See `EmoticonCode` type.
+    pub fn emoticons(&self) -> &Option<Vec<EmoticonCode>> {
+        &self.emoticons
+    }
+    /// Only set if emoticon sets were requested.
+    ///
+    /// Example value: {"19151": See `EmoticonCode` type}
+    pub fn emoticon_sets(&self) -> &Option<HashMap<String, Vec<EmoticonCode>>> {
+        &self.emoticon_sets
+    }
+}
+
+impl EmoticonCode {
+    /// Example value: 24687
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+    /// Example value: "BORT"
+    pub fn code(&self) -> &String {
+        &self.code
+    }
+    /// Example value: 33
+    pub fn emoticon_set(&self) -> Option<u64> {
+        self.emoticon_set
+    }
+
+    /// Returns the CDN url of the emoticon image (see `emote_url`).
+    pub fn url(&self, scale: ChatImageScale) -> String {
+        emote_url(self.id, scale)
+    }
+}
+
+impl Badges {
+    /// Example value: See `Badge` type.
+    pub fn global_mod(&self) -> &Option<Badge> {
+        &self.global_mod
+    }
+    /// Example value: See `Badge` type.
+    pub fn admin(&self) -> &Option<Badge> {
+        &self.admin
+    }
+    /// Example value: See `Badge` type.
+    pub fn broadcaster(&self) -> &Option<Badge> {
+        &self.broadcaster
+    }
+    /// Example value: See `Badge` type.
+    pub fn moderator(&self) -> &Option<Badge> {
+        &self.moderator
+    }
+    /// Example value: See `Badge` type.
+    pub fn staff(&self) -> &Option<Badge> {
+        &self.staff
+    }
+    /// Example value: See `Badge` type.
+    pub fn turbo(&self) -> &Option<Badge> {
+        &self.turbo
+    }
+    /// Example value: `None`
+    pub fn subscriber(&self) -> &Option<Badge> {
+        &self.subscriber
+    }
+}
+
+impl Badge {
+    /// Example value: "http://chat-badges.s3.amazonaws.com/globalmod-alpha.png"
+    pub fn alpha(&self) -> &Option<Url> {
+        &self.alpha
+    }
+    /// Example value: "http://chat-badges.s3.amazonaws.com/globalmod.png"
+    pub fn image(&self) -> &Url {
+        &self.image
+    }
+    /// Example value: "http://chat-badges.s3.amazonaws.com/globalmod.svg"
+    pub fn svg(&self) -> &Option<Url> {
+        &self.svg
+    }
+}
+
+impl BadgeSets {
+    /// Example value: {"subscriber": See `BadgeSet` type}
+    pub fn badge_sets(&self) -> &HashMap<String, BadgeSet> {
+        &self.badge_sets
+    }
+
+    /// Returns the version of the badge set, as sent in the `badges` tag of chat messages (e.g. "subscriber/12").
+    pub fn version(&self, badge_set: &str, version: &str) -> Option<&BadgeVersion> {
+        self.badge_sets.get(badge_set).and_then(|badge_set| badge_set.versions.get(version))
+    }
+}
+
+impl BadgeSet {
+    /// Example value: {"0": See `BadgeVersion` type}
+    pub fn versions(&self) -> &HashMap<String, BadgeVersion> {
+        &self.versions
+    }
+}
+
+impl BadgeVersion {
+    /// Example value: "https://static-cdn.jtvnw.net/badges/v1/5d9f2208-5dd8-11e7-8513-2ff4adfae661/1"
+    pub fn image_url_1x(&self) -> &Url {
+        &self.image_url_1x
+    }
+    /// Example value: "https://static-cdn.jtvnw.net/badges/v1/5d9f2208-5dd8-11e7-8513-2ff4adfae661/2"
+    pub fn image_url_2x(&self) -> &Url {
+        &self.image_url_2x
+    }
+    /// Example value: "https://static-cdn.jtvnw.net/badges/v1/5d9f2208-5dd8-11e7-8513-2ff4adfae661/3"
+    pub fn image_url_4x(&self) -> &Url {
+        &self.image_url_4x
+    }
+    /// Example value: "Subscriber"
+    pub fn description(&self) -> &String {
+        &self.description
+    }
+    /// Example value: "Subscriber"
+    pub fn title(&self) -> &String {
+        &self.title
+    }
+    /// Example value: "subscribe_to_channel"
+    pub fn click_action(&self) -> &Option<String> {
+        &self.click_action
+    }
+    /// Example value: ""
+    pub fn click_url(&self) -> &Option<String> {
+        &self.click_url
+    }
+
+    /// Returns the image url in the scale.
+    pub fn image_url(&self, scale: ChatImageScale) -> &Url {
+        match scale {
+            ChatImageScale::Small => &self.image_url_1x,
+            ChatImageScale::Medium => &self.image_url_2x,
+            ChatImageScale::Large => &self.image_url_4x,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+
+    #[test]
+    fn test_cdn_urls() {
+        assert_eq!(emote_url(25, ChatImageScale::Small), "https://static-cdn.jtvnw.net/emoticons/v1/25/1.0");
+        assert_eq!(emote_url(25, ChatImageScale::Large), "https://static-cdn.jtvnw.net/emoticons/v1/25/3.0");
+        assert_eq!(badge_image_url("5d9f2208-5dd8-11e7-8513-2ff4adfae661", ChatImageScale::Medium),
+                "https://static-cdn.jtvnw.net/badges/v1/5d9f2208-5dd8-11e7-8513-2ff4adfae661/2");
+    }
+
+    #[test]
+    fn test_badge_sets_version() {
+        let json = r#"{"badge_sets": {"subscriber": {"versions": {"0": {
+            "image_url_1x": "https://static-cdn.jtvnw.net/badges/v1/5d9f2208-5dd8-11e7-8513-2ff4adfae661/1",
+            "image_url_2x": "https://static-cdn.jtvnw.net/badges/v1/5d9f2208-5dd8-11e7-8513-2ff4adfae661/2",
+            "image_url_4x": "https://static-cdn.jtvnw.net/badges/v1/5d9f2208-5dd8-11e7-8513-2ff4adfae661/3",
+            "description": "Subscriber",
+            "title": "Subscriber",
+            "click_action": "subscribe_to_channel",
+            "click_url": ""
+        }}}}}"#;
+        let badge_sets: BadgeSets = serde_json::from_str(json).unwrap();
+        assert_eq!(badge_sets.version("subscriber", "0").map(|version| version.title().as_str()), Some("Subscriber"));
+        assert!(badge_sets.version("subscriber", "12").is_none());
+        assert!(badge_sets.version("staff", "1").is_none());
+    }
+}
@@ -0,0 +1,156 @@
+//! Users in the chat of a channel.
+//!
+//! Chatters are only available through the undocumented TMI API (see `unofficial`).
+
+pub use model::{Links, TwitchLinks};
+use model::deserialize_links;
+
+
+/// Logins of the users in the chat of a channel, grouped by their role.
+///
+/// Twitch caches the list for a few minutes, so it may lag behind the chat.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_links": {},
+///   "chatter_count": 5,
+///   "chatters": {
+///     // See `ChattersByRole` type
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Chatters {
+    chatter_count: u32,
+    chatters: ChattersByRole,
+    #[serde(rename="_links", default, deserialize_with="deserialize_links")]
+    links: Links,
+}
+
+/// Logins of the chatters per role, each user is only listed under the most privileged role.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "broadcaster": ["test_channel"],
+///   "vips": ["test_user1"],
+///   "moderators": ["nightbot"],
+///   "staff": [],
+///   "admins": [],
+///   "global_mods": [],
+///   "viewers": ["test_user2"]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ChattersByRole {
+    #[serde(default)]
+    broadcaster: Vec<String>,
+    #[serde(default)]
+    vips: Vec<String>,
+    #[serde(default)]
+    moderators: Vec<String>,
+    #[serde(default)]
+    staff: Vec<String>,
+    #[serde(default)]
+    admins: Vec<String>,
+    #[serde(default)]
+    global_mods: Vec<String>,
+    #[serde(default)]
+    viewers: Vec<String>,
+}
+
+
+impl Chatters {
+    /// Example value: 5
+    ///
+    /// Total number of chatters of all roles.
+    pub fn chatter_count(&self) -> u32 {
+        self.chatter_count
+    }
+    /// Example value: See `ChattersByRole` type.
+    pub fn chatters(&self) -> &ChattersByRole {
+        &self.chatters
+    }
+}
+
+impl TwitchLinks for Chatters {
+    fn links(&self) -> &Links {
+        &self.links
+    }
+}
+
+impl ChattersByRole {
+    /// Example value: ["test_channel"]
+    pub fn broadcaster(&self) -> &Vec<String> {
+        &self.broadcaster
+    }
+    /// Example value: ["test_user1"]
+    pub fn vips(&self) -> &Vec<String> {
+        &self.vips
+    }
+    /// Example value: ["nightbot"]
+    pub fn moderators(&self) -> &Vec<String> {
+        &self.moderators
+    }
+    /// Example value: []
+    pub fn staff(&self) -> &Vec<String> {
+        &self.staff
+    }
+    /// Example value: []
+    pub fn admins(&self) -> &Vec<String> {
+        &self.admins
+    }
+    /// Example value: []
+    pub fn global_mods(&self) -> &Vec<String> {
+        &self.global_mods
+    }
+    /// Example value: ["test_user2"]
+    pub fn viewers(&self) -> &Vec<String> {
+        &self.viewers
+    }
+
+    /// Returns the logins of all chatters, starting with the most privileged roles.
+    pub fn all(&self) -> Vec<&String> {
+        self.broadcaster.iter()
+                .chain(self.vips.iter())
+                .chain(self.moderators.iter())
+                .chain(self.staff.iter())
+                .chain(self.admins.iter())
+                .chain(self.global_mods.iter())
+                .chain(self.viewers.iter())
+                .collect()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+
+    #[test]
+    fn test_should_deserialize_chatters() {
+        let json = r#"{
+            "_links": {},
+            "chatter_count": 3,
+            "chatters": {
+                "broadcaster": ["test_channel"],
+                "vips": [],
+                "moderators": ["nightbot"],
+                "staff": [],
+                "admins": [],
+                "global_mods": [],
+                "viewers": ["test_user2"]
+            }
+        }"#;
+        let chatters: Chatters = serde_json::from_str(json).unwrap();
+        assert_eq!(chatters.chatter_count(), 3);
+        assert_eq!(chatters.chatters().moderators(), &vec!["nightbot".to_owned()]);
+        assert_eq!(chatters.chatters().all(), vec!["test_channel", "nightbot", "test_user2"]);
+    }
+}
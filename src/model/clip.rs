@@ -0,0 +1,291 @@
+//! Twitch clips.
+//!
+//! Clips are short excerpts of a live stream or video that are created by viewers.
+//! Only available with `ApiVersion::V5`.
+
+pub use model::DateTime;
+use model::deserialize_date_time;
+pub use model::Url;
+pub use model::language::Language;
+
+
+/// List of clips with a cursor for the next page.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "clips": [
+///     {
+///       // See `Clip` type
+///     }
+///   ],
+///   "_cursor": "MTA="
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Clips {
+    clips: Vec<Clip>,
+    #[serde(rename="_cursor")]
+    cursor: Option<String>,
+}
+
+/// Clip information.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "slug": "AmazonianEncouragingLyrebirdAllenHuhu",
+///   "tracking_id": "102382269",
+///   "url": "https://clips.twitch.tv/AmazonianEncouragingLyrebirdAllenHuhu?tt_medium=clips_api&tt_content=url",
+///   "embed_url": "https://clips.twitch.tv/embed?clip=AmazonianEncouragingLyrebirdAllenHuhu&tt_medium=clips_api&tt_content=embed",
+///   "embed_html": "<iframe src='https://clips.twitch.tv/embed?clip=AmazonianEncouragingLyrebirdAllenHuhu&tt_medium=clips_api&tt_content=embed' width='640' height='360' frameborder='0' scrolling='no' allowfullscreen='true'></iframe>",
+///   "broadcaster": {
+///     // See `ClipUser` type
+///   },
+///   "curator": {
+///     // See `ClipUser` type
+///   },
+///   "vod": {
+///     // See `ClipVod` type
+///   },
+///   "game": "Fallout 4",
+///   "language": "en",
+///   "title": "babby's first wasteland",
+///   "views": 1,
+///   "duration": 27.766,
+///   "created_at": "2017-11-30T22:34:17Z",
+///   "thumbnails": {
+///     // See `ClipThumbnails` type
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Clip {
+    slug: String,
+    tracking_id: String,
+    url: Url,
+    embed_url: Url,
+    embed_html: String,
+    broadcaster: ClipUser,
+    curator: ClipUser,
+    vod: Option<ClipVod>,
+    game: String,
+    language: Language,
+    title: String,
+    views: u32,
+    duration: f64,
+    #[serde(deserialize_with="deserialize_date_time")]
+    created_at: DateTime,
+    thumbnails: ClipThumbnails,
+}
+
+/// Broadcaster or curator of a clip.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": "7236692",
+///   "name": "dansgaming",
+///   "display_name": "DansGaming",
+///   "channel_url": "https://www.twitch.tv/dansgaming",
+///   "logo": "https://static-cdn.jtvnw.net/jtv_user_pictures/dansgaming-profile_image-76e4a4ab9388bc9c-150x150.png"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ClipUser {
+    id: String,
+    name: String,
+    display_name: String,
+    channel_url: Url,
+    logo: Option<Url>,
+}
+
+/// Video that a clip was created from.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": "205586603",
+///   "url": "https://www.twitch.tv/videos/205586603?t=3h22m46s",
+///   "offset": 12166,
+///   "preview_image_url": "https://vod-secure.twitch.tv/_404/404_processing_320x240.png"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ClipVod {
+    id: String,
+    url: Url,
+    #[serde(default)]
+    offset: u32,
+    preview_image_url: Option<Url>,
+}
+
+/// Thumbnails of a clip in different sizes.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "medium": "https://clips-media-assets.twitch.tv/157589949-preview-480x272.jpg",
+///   "small": "https://clips-media-assets.twitch.tv/157589949-preview-260x147.jpg",
+///   "tiny": "https://clips-media-assets.twitch.tv/157589949-preview-86x45.jpg"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ClipThumbnails {
+    medium: Url,
+    small: Url,
+    tiny: Url,
+}
+
+
+impl Clips {
+    /// Example value: See `Clip` type.
+    pub fn clips(&self) -> &Vec<Clip> {
+        &self.clips
+    }
+    /// Cursor for the next page, used with `ClipsParams::with_cursor`.
+    /// Empty on the last page.
+    ///
+    /// Example value: "MTA="
+    pub fn cursor(&self) -> &Option<String> {
+        &self.cursor
+    }
+}
+
+impl Clip {
+    /// Example value: "AmazonianEncouragingLyrebirdAllenHuhu"
+    pub fn slug(&self) -> &String {
+        &self.slug
+    }
+    /// Example value: "102382269"
+    pub fn tracking_id(&self) -> &String {
+        &self.tracking_id
+    }
+    /// Example value: "https://clips.twitch.tv/AmazonianEncouragingLyrebirdAllenHuhu?tt_medium=clips_api&tt_content=url"
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+    /// Example value: "https://clips.twitch.tv/embed?clip=AmazonianEncouragingLyrebirdAllenHuhu&tt_medium=clips_api&tt_content=embed"
+    pub fn embed_url(&self) -> &Url {
+        &self.embed_url
+    }
+    /// Example value: "<iframe src='https://clips.twitch.tv/embed?clip=AmazonianEncouragingLyrebirdAllenHuhu' ...></iframe>"
+    pub fn embed_html(&self) -> &String {
+        &self.embed_html
+    }
+    /// Example value: See `ClipUser` type.
+    pub fn broadcaster(&self) -> &ClipUser {
+        &self.broadcaster
+    }
+    /// User that created the clip.
+    ///
+    /// Example value: See `ClipUser` type.
+    pub fn curator(&self) -> &ClipUser {
+        &self.curator
+    }
+    /// Not set if the clip was created from a stream without a saved video.
+    ///
+    /// Example value: See `ClipVod` type.
+    pub fn vod(&self) -> &Option<ClipVod> {
+        &self.vod
+    }
+    /// Example value: "Fallout 4"
+    pub fn game(&self) -> &String {
+        &self.game
+    }
+    /// Example value: "en"
+    pub fn language(&self) -> &Language {
+        &self.language
+    }
+    /// Example value: "babby's first wasteland"
+    pub fn title(&self) -> &String {
+        &self.title
+    }
+    /// Example value: 1
+    pub fn views(&self) -> u32 {
+        self.views
+    }
+    /// Duration in seconds.
+    ///
+    /// Example value: 27.766
+    pub fn duration(&self) -> f64 {
+        self.duration
+    }
+    /// Example value: "2017-11-30T22:34:17Z"
+    pub fn created_at(&self) -> &DateTime {
+        &self.created_at
+    }
+    /// Example value: See `ClipThumbnails` type.
+    pub fn thumbnails(&self) -> &ClipThumbnails {
+        &self.thumbnails
+    }
+}
+
+impl ClipUser {
+    /// Example value: "7236692"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "dansgaming"
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+    /// Example value: "DansGaming"
+    pub fn display_name(&self) -> &String {
+        &self.display_name
+    }
+    /// Example value: "https://www.twitch.tv/dansgaming"
+    pub fn channel_url(&self) -> &Url {
+        &self.channel_url
+    }
+    /// Example value: "https://static-cdn.jtvnw.net/jtv_user_pictures/dansgaming-profile_image-76e4a4ab9388bc9c-150x150.png"
+    pub fn logo(&self) -> &Option<Url> {
+        &self.logo
+    }
+}
+
+impl ClipVod {
+    /// Example value: "205586603"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "https://www.twitch.tv/videos/205586603?t=3h22m46s"
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+    /// Position of the clip in the video in seconds.
+    ///
+    /// Example value: 12166
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+    /// Example value: "https://vod-secure.twitch.tv/_404/404_processing_320x240.png"
+    pub fn preview_image_url(&self) -> &Option<Url> {
+        &self.preview_image_url
+    }
+}
+
+impl ClipThumbnails {
+    /// Example value: "https://clips-media-assets.twitch.tv/157589949-preview-480x272.jpg"
+    pub fn medium(&self) -> &Url {
+        &self.medium
+    }
+    /// Example value: "https://clips-media-assets.twitch.tv/157589949-preview-260x147.jpg"
+    pub fn small(&self) -> &Url {
+        &self.small
+    }
+    /// Example value: "https://clips-media-assets.twitch.tv/157589949-preview-86x45.jpg"
+    pub fn tiny(&self) -> &Url {
+        &self.tiny
+    }
+}
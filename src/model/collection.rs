@@ -0,0 +1,359 @@
+//! Twitch collections.
+//!
+//! Collections are ordered lists of videos that are curated by a channel (e.g. a series of streams).
+//! Only available with `ApiVersion::V5`.
+
+pub use model::DateTime;
+use model::deserialize_date_time;
+pub use model::Url;
+
+
+/// Collections of a channel with a cursor for the next page.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_cursor": "MTA=",
+///   "collections": [
+///     {
+///       // See `CollectionMetadata` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Collections {
+    #[serde(rename="_cursor")]
+    cursor: Option<String>,
+    collections: Vec<CollectionMetadata>,
+}
+
+/// Summary of a collection without its items.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_id": "myIbIFkZphQSbQ",
+///   "owner": {
+///     // See `CollectionOwner` type
+///   },
+///   "title": "Hearthstone Highlights",
+///   "description": "The best plays of the season.",
+///   "description_html": "<p>The best plays of the season.</p>",
+///   "thumbnails": {
+///     // See `CollectionThumbnails` type
+///   },
+///   "items_count": 3,
+///   "total_duration": 3600,
+///   "views": 42,
+///   "created_at": "2017-03-06T18:40:51.855Z",
+///   "updated_at": "2017-03-06T18:40:51.855Z"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct CollectionMetadata {
+    #[serde(rename="_id")]
+    id: String,
+    owner: CollectionOwner,
+    title: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    description_html: String,
+    thumbnails: CollectionThumbnails,
+    items_count: u32,
+    total_duration: u32,
+    #[serde(default)]
+    views: u32,
+    #[serde(deserialize_with="deserialize_date_time")]
+    created_at: DateTime,
+    #[serde(deserialize_with="deserialize_date_time")]
+    updated_at: DateTime,
+}
+
+/// Collection with its items.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_id": "myIbIFkZphQSbQ",
+///   "items": [
+///     {
+///       // See `CollectionItem` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Collection {
+    #[serde(rename="_id")]
+    id: String,
+    items: Vec<CollectionItem>,
+}
+
+/// Video in a collection.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_id": "eyJ0eXBlIjoidmlkZW8iLCJpZCI6IjEyMjEzODg0OSJ9",
+///   "description_html": "Great game!<br>",
+///   "duration": 784,
+///   "game": "Hearthstone",
+///   "item_id": "122138849",
+///   "item_type": "video",
+///   "owner": {
+///     // See `CollectionOwner` type
+///   },
+///   "published_at": "2017-02-14T22:27:54Z",
+///   "thumbnails": {
+///     // See `CollectionThumbnails` type
+///   },
+///   "title": "Top 10 plays",
+///   "views": 153
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct CollectionItem {
+    #[serde(rename="_id")]
+    id: String,
+    #[serde(default)]
+    description_html: String,
+    duration: u32,
+    game: Option<String>,
+    item_id: String,
+    item_type: String,
+    owner: CollectionOwner,
+    #[serde(deserialize_with="deserialize_date_time")]
+    published_at: DateTime,
+    thumbnails: CollectionThumbnails,
+    title: String,
+    #[serde(default)]
+    views: u32,
+}
+
+/// Owner of a collection or collection item.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_id": "12826",
+///   "name": "twitch",
+///   "display_name": "Twitch",
+///   "logo": "https://static-cdn.jtvnw.net/jtv_user_pictures/twitch-profile_image-bd6df6672afc7497-300x300.png",
+///   "type": "user"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct CollectionOwner {
+    #[serde(rename="_id")]
+    id: String,
+    name: String,
+    display_name: String,
+    logo: Option<Url>,
+    #[serde(rename="type")]
+    owner_type: String,
+}
+
+/// Thumbnails of a collection or collection item in different sizes.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "large": "https://static-cdn.jtvnw.net/s3_vods/twitch/122138849/thumb/thumb0-640x360.jpg",
+///   "medium": "https://static-cdn.jtvnw.net/s3_vods/twitch/122138849/thumb/thumb0-320x180.jpg",
+///   "small": "https://static-cdn.jtvnw.net/s3_vods/twitch/122138849/thumb/thumb0-80x45.jpg",
+///   "template": "https://static-cdn.jtvnw.net/s3_vods/twitch/122138849/thumb/thumb0-{width}x{height}.jpg"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct CollectionThumbnails {
+    large: Url,
+    medium: Url,
+    small: Url,
+    template: String,
+}
+
+
+impl Collections {
+    /// Cursor for the next page, used with `CollectionsParams::with_cursor`.
+    /// Empty on the last page.
+    ///
+    /// Example value: "MTA="
+    pub fn cursor(&self) -> &Option<String> {
+        &self.cursor
+    }
+    /// Example value: See `CollectionMetadata` type.
+    pub fn collections(&self) -> &Vec<CollectionMetadata> {
+        &self.collections
+    }
+}
+
+impl CollectionMetadata {
+    /// Example value: "myIbIFkZphQSbQ"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: See `CollectionOwner` type.
+    pub fn owner(&self) -> &CollectionOwner {
+        &self.owner
+    }
+    /// Example value: "Hearthstone Highlights"
+    pub fn title(&self) -> &String {
+        &self.title
+    }
+    /// Example value: "The best plays of the season."
+    pub fn description(&self) -> &String {
+        &self.description
+    }
+    /// Example value: "<p>The best plays of the season.</p>"
+    pub fn description_html(&self) -> &String {
+        &self.description_html
+    }
+    /// Example value: See `CollectionThumbnails` type.
+    pub fn thumbnails(&self) -> &CollectionThumbnails {
+        &self.thumbnails
+    }
+    /// Example value: 3
+    pub fn items_count(&self) -> u32 {
+        self.items_count
+    }
+    /// Duration of all items in seconds.
+    ///
+    /// Example value: 3600
+    pub fn total_duration(&self) -> u32 {
+        self.total_duration
+    }
+    /// Example value: 42
+    pub fn views(&self) -> u32 {
+        self.views
+    }
+    /// Example value: "2017-03-06T18:40:51.855Z"
+    pub fn created_at(&self) -> &DateTime {
+        &self.created_at
+    }
+    /// Example value: "2017-03-06T18:40:51.855Z"
+    pub fn updated_at(&self) -> &DateTime {
+        &self.updated_at
+    }
+}
+
+impl Collection {
+    /// Example value: "myIbIFkZphQSbQ"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Items in the order of the collection.
+    ///
+    /// Example value: See `CollectionItem` type.
+    pub fn items(&self) -> &Vec<CollectionItem> {
+        &self.items
+    }
+}
+
+impl CollectionItem {
+    /// Example value: "eyJ0eXBlIjoidmlkZW8iLCJpZCI6IjEyMjEzODg0OSJ9"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "Great game!<br>"
+    pub fn description_html(&self) -> &String {
+        &self.description_html
+    }
+    /// Duration in seconds.
+    ///
+    /// Example value: 784
+    pub fn duration(&self) -> u32 {
+        self.duration
+    }
+    /// Example value: "Hearthstone"
+    pub fn game(&self) -> &Option<String> {
+        &self.game
+    }
+    /// Id of the video.
+    ///
+    /// Example value: "122138849"
+    pub fn item_id(&self) -> &String {
+        &self.item_id
+    }
+    /// Example value: "video"
+    pub fn item_type(&self) -> &String {
+        &self.item_type
+    }
+    /// Example value: See `CollectionOwner` type.
+    pub fn owner(&self) -> &CollectionOwner {
+        &self.owner
+    }
+    /// Example value: "2017-02-14T22:27:54Z"
+    pub fn published_at(&self) -> &DateTime {
+        &self.published_at
+    }
+    /// Example value: See `CollectionThumbnails` type.
+    pub fn thumbnails(&self) -> &CollectionThumbnails {
+        &self.thumbnails
+    }
+    /// Example value: "Top 10 plays"
+    pub fn title(&self) -> &String {
+        &self.title
+    }
+    /// Example value: 153
+    pub fn views(&self) -> u32 {
+        self.views
+    }
+}
+
+impl CollectionOwner {
+    /// Example value: "12826"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "twitch"
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+    /// Example value: "Twitch"
+    pub fn display_name(&self) -> &String {
+        &self.display_name
+    }
+    /// Example value: "https://static-cdn.jtvnw.net/jtv_user_pictures/twitch-profile_image-bd6df6672afc7497-300x300.png"
+    pub fn logo(&self) -> &Option<Url> {
+        &self.logo
+    }
+    /// Example value: "user"
+    pub fn owner_type(&self) -> &String {
+        &self.owner_type
+    }
+}
+
+impl CollectionThumbnails {
+    /// Example value: "https://static-cdn.jtvnw.net/s3_vods/twitch/122138849/thumb/thumb0-640x360.jpg"
+    pub fn large(&self) -> &Url {
+        &self.large
+    }
+    /// Example value: "https://static-cdn.jtvnw.net/s3_vods/twitch/122138849/thumb/thumb0-320x180.jpg"
+    pub fn medium(&self) -> &Url {
+        &self.medium
+    }
+    /// Example value: "https://static-cdn.jtvnw.net/s3_vods/twitch/122138849/thumb/thumb0-80x45.jpg"
+    pub fn small(&self) -> &Url {
+        &self.small
+    }
+    /// Url with `{width}` and `{height}` placeholders for custom sizes.
+    ///
+    /// Example value: "https://static-cdn.jtvnw.net/s3_vods/twitch/122138849/thumb/thumb0-{width}x{height}.jpg"
+    pub fn template(&self) -> &String {
+        &self.template
+    }
+}
@@ -0,0 +1,188 @@
+//! Twitch communities.
+//!
+//! Communities group channels around a common topic (e.g. a speedrunning category).
+//! Only available with `ApiVersion::V5`.
+
+pub use model::Url;
+
+
+/// Community information.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_id": "e9f17055-810f-4736-ba40-fba4ac541caa",
+///   "avatar_image_url": "https://static-cdn.jtvnw.net/community-images/e9f17055-810f-4736-ba40-fba4ac541caa/1f7d4c24-b10f-4d28-9b9c-1a8a8ca6d1d8-185x258.png",
+///   "cover_image_url": "https://static-cdn.jtvnw.net/community-images/e9f17055-810f-4736-ba40-fba4ac541caa/1f7d4c24-b10f-4d28-9b9c-1a8a8ca6d1d8-1200x180.png",
+///   "description": "Our community is for developers who stream.",
+///   "description_html": "<p>Our community is for developers who stream.</p>",
+///   "language": "EN",
+///   "name": "DallasTesterCommunity",
+///   "owner_id": "44322889",
+///   "rules": "Be nice.",
+///   "rules_html": "<p>Be nice.</p>",
+///   "summary": "Developers who stream."
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Community {
+    #[serde(rename="_id")]
+    id: String,
+    avatar_image_url: Option<Url>,
+    cover_image_url: Option<Url>,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    description_html: String,
+    language: String,
+    name: String,
+    owner_id: String,
+    #[serde(default)]
+    rules: String,
+    #[serde(default)]
+    rules_html: String,
+    #[serde(default)]
+    summary: String,
+}
+
+/// Top communities by number of viewers.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_cursor": "MTA=",
+///   "_total": 100,
+///   "communities": [
+///     {
+///       // See `TopCommunity` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct TopCommunities {
+    #[serde(rename="_cursor")]
+    cursor: Option<String>,
+    #[serde(rename="_total")]
+    total: u32,
+    communities: Vec<TopCommunity>,
+}
+
+/// Community with its current number of live channels and viewers.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_id": "fd0eab99-832a-4d7e-8cc0-04d73deb2e54",
+///   "avatar_image_url": "https://static-cdn.jtvnw.net/community-images/fd0eab99-832a-4d7e-8cc0-04d73deb2e54/4d2eb6e1-1a3b-4d54-a1b6-8f38a1b9a8c5-185x258.png",
+///   "channels": 126,
+///   "name": "Speedrunning",
+///   "viewers": 4938
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct TopCommunity {
+    #[serde(rename="_id")]
+    id: String,
+    avatar_image_url: Option<Url>,
+    channels: u32,
+    name: String,
+    viewers: u32,
+}
+
+
+impl Community {
+    /// Example value: "e9f17055-810f-4736-ba40-fba4ac541caa"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "https://static-cdn.jtvnw.net/community-images/e9f17055-810f-4736-ba40-fba4ac541caa/1f7d4c24-b10f-4d28-9b9c-1a8a8ca6d1d8-185x258.png"
+    pub fn avatar_image_url(&self) -> &Option<Url> {
+        &self.avatar_image_url
+    }
+    /// Example value: "https://static-cdn.jtvnw.net/community-images/e9f17055-810f-4736-ba40-fba4ac541caa/1f7d4c24-b10f-4d28-9b9c-1a8a8ca6d1d8-1200x180.png"
+    pub fn cover_image_url(&self) -> &Option<Url> {
+        &self.cover_image_url
+    }
+    /// Example value: "Our community is for developers who stream."
+    pub fn description(&self) -> &String {
+        &self.description
+    }
+    /// Example value: "<p>Our community is for developers who stream.</p>"
+    pub fn description_html(&self) -> &String {
+        &self.description_html
+    }
+    /// Example value: "EN"
+    pub fn language(&self) -> &String {
+        &self.language
+    }
+    /// Example value: "DallasTesterCommunity"
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+    /// Example value: "44322889"
+    pub fn owner_id(&self) -> &String {
+        &self.owner_id
+    }
+    /// Example value: "Be nice."
+    pub fn rules(&self) -> &String {
+        &self.rules
+    }
+    /// Example value: "<p>Be nice.</p>"
+    pub fn rules_html(&self) -> &String {
+        &self.rules_html
+    }
+    /// Example value: "Developers who stream."
+    pub fn summary(&self) -> &String {
+        &self.summary
+    }
+}
+
+impl TopCommunities {
+    /// Cursor for the next page, used with `TopCommunitiesParams::with_cursor`.
+    /// Empty on the last page.
+    ///
+    /// Example value: "MTA="
+    pub fn cursor(&self) -> &Option<String> {
+        &self.cursor
+    }
+    /// Example value: 100
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+    /// Example value: See `TopCommunity` type.
+    pub fn communities(&self) -> &Vec<TopCommunity> {
+        &self.communities
+    }
+}
+
+impl TopCommunity {
+    /// Example value: "fd0eab99-832a-4d7e-8cc0-04d73deb2e54"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "https://static-cdn.jtvnw.net/community-images/fd0eab99-832a-4d7e-8cc0-04d73deb2e54/4d2eb6e1-1a3b-4d54-a1b6-8f38a1b9a8c5-185x258.png"
+    pub fn avatar_image_url(&self) -> &Option<Url> {
+        &self.avatar_image_url
+    }
+    /// Number of live channels in the community.
+    ///
+    /// Example value: 126
+    pub fn channels(&self) -> u32 {
+        self.channels
+    }
+    /// Example value: "Speedrunning"
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+    /// Example value: 4938
+    pub fn viewers(&self) -> u32 {
+        self.viewers
+    }
+}
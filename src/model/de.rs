@@ -0,0 +1,105 @@
+//! Lenient deserialization of the inconsistent values sent by Twitch.
+//!
+//! Depending on the endpoint and API version Twitch sends missing values as `null`,
+//! as empty strings or even as the literal string `"null"`, and numbers are sometimes sent as strings.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer};
+use serde::de::{DeserializeOwned, Error as DeError, IntoDeserializer};
+use serde_json::{self, Value};
+
+
+/// Deserializes empty (or blank) strings as `None`.
+pub(crate) fn empty_string_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+        where D: Deserializer<'de>, T: Deserialize<'de> {
+    let value: Option<String> = try!(Option::deserialize(deserializer));
+    match value {
+        Some(ref value) if value.trim().is_empty() => Ok(None),
+        Some(value) => T::deserialize(value.into_deserializer()).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Deserializes empty strings and the literal string `"null"` as `None`.
+pub(crate) fn null_string_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+        where D: Deserializer<'de>, T: Deserialize<'de> {
+    let value: Option<String> = try!(Option::deserialize(deserializer));
+    match value {
+        Some(ref value) if value.trim().is_empty() || value.trim().eq_ignore_ascii_case("null") => Ok(None),
+        Some(value) => T::deserialize(value.into_deserializer()).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Deserializes numbers that are sent either as number or as string (e.g.: `42` or `"42"`).
+///
+/// `null` and empty strings are deserialized as the default value (zero).
+pub(crate) fn number_or_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+        where D: Deserializer<'de>, T: FromStr + DeserializeOwned + Default, T::Err: Display {
+    optional_number_or_string(deserializer).map(Option::unwrap_or_default)
+}
+
+/// Deserializes optional numbers that are sent either as number or as string (e.g.: `42` or `"42"`).
+///
+/// `null` and empty strings are deserialized as `None`.
+pub(crate) fn optional_number_or_string<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+        where D: Deserializer<'de>, T: FromStr + DeserializeOwned, T::Err: Display {
+    match try!(Value::deserialize(deserializer)) {
+        Value::Null => Ok(None),
+        Value::String(ref value) if value.trim().is_empty() => Ok(None),
+        Value::String(value) => value.trim().parse().map(Some).map_err(D::Error::custom),
+        value @ Value::Number(_) => serde_json::from_value(value).map(Some).map_err(D::Error::custom),
+        value => Err(D::Error::custom(format!("expected number or string, but got: {}", value))),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+
+    #[derive(Deserialize, Debug)]
+    struct Lenient {
+        #[serde(default, deserialize_with="empty_string_as_none")]
+        text: Option<String>,
+        #[serde(default, deserialize_with="null_string_as_none")]
+        color: Option<String>,
+        #[serde(default, deserialize_with="number_or_string")]
+        count: u32,
+        #[serde(default, deserialize_with="optional_number_or_string")]
+        delay: Option<u32>,
+    }
+
+    #[test]
+    fn test_should_deserialize_missing_values_as_none() {
+        let lenient: Lenient = serde_json::from_str(
+            r#"{"text": "", "color": "null", "count": null, "delay": ""}"#).unwrap();
+        assert_eq!(lenient.text, None);
+        assert_eq!(lenient.color, None);
+        assert_eq!(lenient.count, 0);
+        assert_eq!(lenient.delay, None);
+
+        let lenient: Lenient = serde_json::from_str("{}").unwrap();
+        assert_eq!(lenient.text, None);
+        assert_eq!(lenient.delay, None);
+    }
+
+    #[test]
+    fn test_should_deserialize_present_values() {
+        let lenient: Lenient = serde_json::from_str(
+            r#"{"text": "test", "color": "#6441a5", "count": "42", "delay": 30}"#).unwrap();
+        assert_eq!(lenient.text, Some("test".to_owned()));
+        assert_eq!(lenient.color, Some("#6441a5".to_owned()));
+        assert_eq!(lenient.count, 42);
+        assert_eq!(lenient.delay, Some(30));
+    }
+
+    #[test]
+    fn test_should_fail_on_invalid_numbers() {
+        assert!(serde_json::from_str::<Lenient>(r#"{"count": "many"}"#).is_err());
+        assert!(serde_json::from_str::<Lenient>(r#"{"delay": true}"#).is_err());
+    }
+}
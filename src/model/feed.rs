@@ -0,0 +1,188 @@
+//! Twitch channel feed.
+//!
+//! The channel feed contains posts that are created by the channel owner
+//! and can be reacted to by other users.
+
+use std::collections::HashMap;
+
+pub use model::DateTime;
+use model::deserialize_date_time;
+pub use model::user::User;
+
+
+/// Posts of a channel feed sorted by creation date, newest first.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_total": 4,
+///   "_cursor": "1454026686000000000",
+///   "_topic": "feeds.channel.test_channel",
+///   "posts": [
+///     {
+///       // See `Post` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Posts {
+    #[serde(rename="_total")]
+    total: u32,
+    #[serde(rename="_cursor")]
+    cursor: Option<String>,
+    posts: Vec<Post>,
+}
+
+/// Post of a channel feed.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": "20",
+///   "created_at": "2016-01-08T22:12:14.478486Z",
+///   "deleted": false,
+///   "emotes": [],
+///   "reactions": {
+///     "endorse": {
+///       // See `Reactions` type
+///     }
+///   },
+///   "body": "Kappa post",
+///   "user": {
+///     // See `User` type
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Post {
+    id: String,
+    #[serde(deserialize_with="deserialize_date_time")]
+    created_at: DateTime,
+    deleted: bool,
+    body: String,
+    reactions: HashMap<String, Reactions>,
+    user: User,
+}
+
+/// Summary of the reactions with the same emote to a post.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "count": 2,
+///   "user_ids": [6900, 7000]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Reactions {
+    count: u32,
+    user_ids: Vec<u64>,
+}
+
+/// Reaction of a user to a post.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": "24989127",
+///   "created_at": "2016-11-29T15:51:12Z",
+///   "emote_id": "25",
+///   "user": {
+///     // See `User` type
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Reaction {
+    id: String,
+    #[serde(deserialize_with="deserialize_date_time")]
+    created_at: DateTime,
+    emote_id: String,
+    user: User,
+}
+
+
+impl Posts {
+    /// Example value: 4
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+    /// Cursor for the next page of posts.
+    ///
+    /// Example value: "1454026686000000000"
+    pub fn cursor(&self) -> &Option<String> {
+        &self.cursor
+    }
+    /// Example value: See `Post` type.
+    pub fn posts(&self) -> &Vec<Post> {
+        &self.posts
+    }
+}
+
+impl Post {
+    /// Example value: "20"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "2016-01-08T22:12:14.478486Z"
+    pub fn created_at(&self) -> &DateTime {
+        &self.created_at
+    }
+    /// Example value: false
+    pub fn deleted(&self) -> bool {
+        self.deleted
+    }
+    /// Example value: "Kappa post"
+    pub fn body(&self) -> &String {
+        &self.body
+    }
+    /// Reactions to the post grouped by emote id ("endorse" or an emoticon id).
+    ///
+    /// Example value: {"endorse": See `Reactions` type}
+    pub fn reactions(&self) -> &HashMap<String, Reactions> {
+        &self.reactions
+    }
+    /// Example value: See `User` type.
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+}
+
+impl Reactions {
+    /// Example value: 2
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+    /// Example value: [6900, 7000]
+    pub fn user_ids(&self) -> &Vec<u64> {
+        &self.user_ids
+    }
+}
+
+impl Reaction {
+    /// Example value: "24989127"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "2016-11-29T15:51:12Z"
+    pub fn created_at(&self) -> &DateTime {
+        &self.created_at
+    }
+    /// Example value: "25"
+    pub fn emote_id(&self) -> &String {
+        &self.emote_id
+    }
+    /// Example value: See `User` type.
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+}
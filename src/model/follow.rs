@@ -0,0 +1,124 @@
+//! Twitch follows.
+//!
+//! Users can follow channels to get notified when they go live.
+
+pub use model::DateTime;
+use model::deserialize_date_time;
+pub use model::user::User;
+pub use model::channel::Channel;
+pub use model::{Links, TwitchLinks};
+use model::deserialize_links;
+
+
+/// Users that follow a channel or channels that are followed by a user sorted by the follow date.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_total": 1234,
+///   "_links": {
+///     "next": "https://api.twitch.tv/kraken/channels/test_user1/follows?direction=DESC&limit=25&offset=25",
+///     "self": "https://api.twitch.tv/kraken/channels/test_user1/follows?direction=DESC&limit=25&offset=0"
+///   },
+///   "follows": [
+///     {
+///       // See `Follow` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Follows {
+    #[serde(rename="_total")]
+    total: u32,
+    follows: Vec<Follow>,
+    #[serde(rename="_links", default, deserialize_with="deserialize_links")]
+    links: Links,
+}
+
+/// Follow relationship between a user and a channel.
+///
+/// Follows of a channel contain the following `user`,
+/// whereas follows of a user contain the followed `channel`.
+///
+/// # Example in JSON for follows of a channel
+///
+/// ```json
+/// {
+///   "created_at": "2013-06-02T09:38:45Z",
+///   "_links": {
+///     "self": "https://api.twitch.tv/kraken/users/test_user2/follows/channels/test_user1"
+///   },
+///   "notifications": true,
+///   "user": {
+///     // See `User` type
+///   }
+/// }
+/// ```
+///
+/// # Example in JSON for follows of a user
+///
+/// ```json
+/// {
+///   "created_at": "2013-06-02T09:38:45Z",
+///   "_links": {
+///     "self": "https://api.twitch.tv/kraken/users/test_user1/follows/channels/test_channel"
+///   },
+///   "notifications": true,
+///   "channel": {
+///     // See `Channel` type
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Follow {
+    #[serde(deserialize_with="deserialize_date_time")]
+    created_at: DateTime,
+    notifications: bool,
+    user: Option<User>,
+    channel: Option<Channel>,
+}
+
+
+impl Follows {
+    /// Example value: 1234
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+    /// Example value: See `Follow` type.
+    pub fn follows(&self) -> &Vec<Follow> {
+        &self.follows
+    }
+}
+
+impl TwitchLinks for Follows {
+    fn links(&self) -> &Links {
+        &self.links
+    }
+}
+
+impl Follow {
+    /// Example value: "2013-06-02T09:38:45Z"
+    pub fn created_at(&self) -> &DateTime {
+        &self.created_at
+    }
+    /// Example value: true
+    pub fn notifications(&self) -> bool {
+        self.notifications
+    }
+    /// Only set for follows of a channel.
+    ///
+    /// Example value: See `User` type.
+    pub fn user(&self) -> &Option<User> {
+        &self.user
+    }
+    /// Only set for follows of a user.
+    ///
+    /// Example value: See `Channel` type.
+    pub fn channel(&self) -> &Option<Channel> {
+        &self.channel
+    }
+}
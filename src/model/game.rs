@@ -3,7 +3,14 @@
 //! Games are categories (e.g. League of Legends, Diablo 3) used by streams and channels.
 //! Games can be searched for by query.
 
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Deserializer};
+
 pub use model::image::ImageLinks;
+pub use model::id::GameId;
+pub use model::{Links, TwitchLinks};
+use model::deserialize_links;
 
 
 /// Games sorted by number of current viewers on Twitch, most popular first.
@@ -24,15 +31,20 @@ pub use model::image::ImageLinks;
 ///   ]
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct TopGames {
     #[serde(rename="_total")]
     total: u32,
     top: Vec<GameInfo>,
+    #[serde(rename="_links", default, deserialize_with="deserialize_links")]
+    links: Links,
 }
 
 /// Current twitch stats about the game.
 ///
+/// Game infos are ordered by their number of viewers (then by channels and game id).
+///
 /// # Example in JSON
 ///
 /// ```json
@@ -44,7 +56,8 @@ pub struct TopGames {
 ///   "channels": 305
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct GameInfo {
     viewers: u32,
     channels: u32,
@@ -75,16 +88,45 @@ pub struct GameInfo {
 ///   "giantbomb_id": 36113
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct Game {
     #[serde(rename="_id")]
-    id: u64,
+    id: GameId,
+    #[serde(default)]
     giantbomb_id: u64,
     name: String,
     #[serde(rename="box")]
     box_image_links: ImageLinks,
     #[serde(rename="logo")]
     logo_image_links: ImageLinks,
+    #[serde(default)]
+    popularity: Option<u32>,
+}
+
+/// Games that were found by a search, most popular first.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_links": {
+///     "self": "https://api.twitch.tv/kraken/search/games?q=star&type=suggest"
+///   },
+///   "games": [
+///     {
+///       // See `Game` type (including "popularity")
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct GameSearchResults {
+    #[serde(default, deserialize_with="deserialize_null_as_empty")]
+    games: Vec<Game>,
+    #[serde(rename="_links", default, deserialize_with="deserialize_links")]
+    links: Links,
 }
 
 
@@ -99,6 +141,12 @@ impl TopGames {
     }
 }
 
+impl TwitchLinks for TopGames {
+    fn links(&self) -> &Links {
+        &self.links
+    }
+}
+
 impl GameInfo {
     /// Example value: 23873
     pub fn viewers(&self) -> u32 {
@@ -114,9 +162,20 @@ impl GameInfo {
     }
 }
 
+impl PartialOrd for GameInfo {
+    fn partial_cmp(&self, other: &GameInfo) -> Option<Ordering> {
+        let ordering = (self.viewers, self.channels, self.game.id)
+                .cmp(&(other.viewers, other.channels, other.game.id));
+        if ordering == Ordering::Equal && self != other {
+            return None;
+        }
+        Some(ordering)
+    }
+}
+
 impl Game {
     /// Example value: 32399
-    pub fn id(&self) -> u64 {
+    pub fn id(&self) -> GameId {
         self.id
     }
     /// Example value: 36113
@@ -135,4 +194,91 @@ impl Game {
     pub fn logo_image_links(&self) -> &ImageLinks {
         &self.logo_image_links
     }
+    /// Example value: 1051
+    ///
+    /// Popularity of the game, only sent in search results (see `GameSearchResults`).
+    pub fn popularity(&self) -> Option<u32> {
+        self.popularity
+    }
+}
+
+impl GameSearchResults {
+    /// Example value: See `Game` type.
+    pub fn games(&self) -> &Vec<Game> {
+        &self.games
+    }
+
+    /// Returns the game whose name equals the name, ignoring the case.
+    pub fn find_exact(&self, name: &str) -> Option<&Game> {
+        let name = name.trim().to_lowercase();
+        self.games.iter().find(|game| game.name.to_lowercase() == name)
+    }
+}
+
+impl TwitchLinks for GameSearchResults {
+    fn links(&self) -> &Links {
+        &self.links
+    }
+}
+
+/// Twitch sends `null` instead of an empty list if no game matches the query.
+fn deserialize_null_as_empty<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Game>, D::Error> {
+    let games: Option<Vec<Game>> = try!(Deserialize::deserialize(deserializer));
+    Ok(games.unwrap_or_default())
+}
+
+/// Builder of `Game` values that were not sent by Twitch (e.g. in tests of downstream crates).
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::model::game::GameBuilder;
+///
+/// let game = GameBuilder::new(32399, "Counter-Strike: Global Offensive")
+///         .with_giantbomb_id(36113)
+///         .build();
+/// assert_eq!(game.name(), "Counter-Strike: Global Offensive");
+/// ```
+#[cfg(feature = "model-builders")]
+#[derive(Debug, Clone)]
+pub struct GameBuilder {
+    game: Game,
+}
+
+#[cfg(feature = "model-builders")]
+impl GameBuilder {
+    /// Constructs a builder of a game with box art and logo links of the Twitch CDN.
+    pub fn new<I: Into<GameId>>(id: I, name: &str) -> GameBuilder {
+        let box_template = format!("https://static-cdn.jtvnw.net/ttv-boxart/{}-{{width}}x{{height}}.jpg", name);
+        let logo_template = format!("https://static-cdn.jtvnw.net/ttv-logoart/{}-{{width}}x{{height}}.jpg", name);
+        GameBuilder {
+            game: Game {
+                id: id.into(),
+                giantbomb_id: 0,
+                name: name.to_owned(),
+                box_image_links: ImageLinks::from_template(&box_template, (52, 72), (136, 190), (272, 380)),
+                logo_image_links: ImageLinks::from_template(&logo_template, (60, 36), (120, 72), (240, 144)),
+                popularity: None,
+            },
+        }
+    }
+    /// Sets the id of the game on [Giant Bomb](https://www.giantbomb.com).
+    pub fn with_giantbomb_id(mut self, giantbomb_id: u64) -> GameBuilder {
+        self.game.giantbomb_id = giantbomb_id;
+        self
+    }
+    /// Sets the box art (url template with `{width}` and `{height}` placeholders).
+    pub fn with_box_template(mut self, template: &str) -> GameBuilder {
+        self.game.box_image_links = ImageLinks::from_template(template, (52, 72), (136, 190), (272, 380));
+        self
+    }
+    /// Sets the logo (url template with `{width}` and `{height}` placeholders).
+    pub fn with_logo_template(mut self, template: &str) -> GameBuilder {
+        self.game.logo_image_links = ImageLinks::from_template(template, (60, 36), (120, 72), (240, 144));
+        self
+    }
+    /// Builds the game.
+    pub fn build(self) -> Game {
+        self.game
+    }
 }
@@ -0,0 +1,122 @@
+//! Twitch Helix bits.
+//!
+//! Bits are a virtual currency that users can use to cheer in chat to support broadcasters.
+
+pub use model::DateTime;
+use model::deserialize_date_time;
+
+
+/// Bits leaderboard of a broadcaster.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "data": [
+///     {
+///       // See `LeaderboardEntry` type
+///     }
+///   ],
+///   "date_range": {
+///     // See `DateRange` type
+///   },
+///   "total": 2
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct BitsLeaderboard {
+    data: Vec<LeaderboardEntry>,
+    date_range: Option<DateRange>,
+    total: u32,
+}
+
+/// Entry of a bits leaderboard.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "user_id": "158010205",
+///   "user_name": "TundraCowboy",
+///   "rank": 1,
+///   "score": 12543
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct LeaderboardEntry {
+    user_id: String,
+    user_name: String,
+    rank: u32,
+    score: u64,
+}
+
+/// Date range of a bits leaderboard.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "started_at": "2018-02-05T08:00:00Z",
+///   "ended_at": "2018-02-12T08:00:00Z"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct DateRange {
+    #[serde(deserialize_with="deserialize_date_time")]
+    started_at: DateTime,
+    #[serde(deserialize_with="deserialize_date_time")]
+    ended_at: DateTime,
+}
+
+
+impl BitsLeaderboard {
+    /// Example value: See `LeaderboardEntry` type.
+    pub fn data(&self) -> &Vec<LeaderboardEntry> {
+        &self.data
+    }
+    /// Not set for the leaderboard of all time.
+    ///
+    /// Example value: See `DateRange` type.
+    pub fn date_range(&self) -> &Option<DateRange> {
+        &self.date_range
+    }
+    /// Example value: 2
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+}
+
+impl LeaderboardEntry {
+    /// Example value: "158010205"
+    pub fn user_id(&self) -> &String {
+        &self.user_id
+    }
+    /// Example value: "TundraCowboy"
+    pub fn user_name(&self) -> &String {
+        &self.user_name
+    }
+    /// Example value: 1
+    pub fn rank(&self) -> u32 {
+        self.rank
+    }
+    /// Number of bits the user has cheered in the date range.
+    ///
+    /// Example value: 12543
+    pub fn score(&self) -> u64 {
+        self.score
+    }
+}
+
+impl DateRange {
+    /// Example value: "2018-02-05T08:00:00Z"
+    pub fn started_at(&self) -> &DateTime {
+        &self.started_at
+    }
+    /// Example value: "2018-02-12T08:00:00Z"
+    pub fn ended_at(&self) -> &DateTime {
+        &self.ended_at
+    }
+}
@@ -0,0 +1,138 @@
+//! Twitch Helix clips.
+//!
+//! Clips are short, shareable videos that are created from a broadcast or video.
+
+pub use model::DateTime;
+use model::deserialize_date_time;
+pub use model::Url;
+
+
+/// Clip information.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": "AwkwardHelplessSalamanderSwiftRage",
+///   "url": "https://clips.twitch.tv/AwkwardHelplessSalamanderSwiftRage",
+///   "embed_url": "https://clips.twitch.tv/embed?clip=AwkwardHelplessSalamanderSwiftRage",
+///   "broadcaster_id": "67955580",
+///   "broadcaster_name": "ChewieMelodies",
+///   "creator_id": "53834192",
+///   "creator_name": "BlackNova03",
+///   "video_id": "205586603",
+///   "game_id": "488191",
+///   "language": "en",
+///   "title": "babymetal",
+///   "view_count": 10,
+///   "created_at": "2017-11-30T22:34:18Z",
+///   "thumbnail_url": "https://clips-media-assets.twitch.tv/157589949-preview-480x272.jpg"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Clip {
+    id: String,
+    url: Url,
+    embed_url: Url,
+    broadcaster_id: String,
+    broadcaster_name: String,
+    creator_id: String,
+    creator_name: String,
+    video_id: String,
+    game_id: String,
+    language: String,
+    title: String,
+    view_count: u32,
+    #[serde(deserialize_with="deserialize_date_time")]
+    created_at: DateTime,
+    thumbnail_url: Url,
+}
+
+/// Clip that was just created and is still being processed.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": "FiveWordsForClipSlug",
+///   "edit_url": "https://clips.twitch.tv/FiveWordsForClipSlug/edit"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct CreatedClip {
+    id: String,
+    edit_url: Url,
+}
+
+
+impl Clip {
+    /// Example value: "AwkwardHelplessSalamanderSwiftRage"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "https://clips.twitch.tv/AwkwardHelplessSalamanderSwiftRage"
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+    /// Example value: "https://clips.twitch.tv/embed?clip=AwkwardHelplessSalamanderSwiftRage"
+    pub fn embed_url(&self) -> &Url {
+        &self.embed_url
+    }
+    /// Example value: "67955580"
+    pub fn broadcaster_id(&self) -> &String {
+        &self.broadcaster_id
+    }
+    /// Example value: "ChewieMelodies"
+    pub fn broadcaster_name(&self) -> &String {
+        &self.broadcaster_name
+    }
+    /// Example value: "53834192"
+    pub fn creator_id(&self) -> &String {
+        &self.creator_id
+    }
+    /// Example value: "BlackNova03"
+    pub fn creator_name(&self) -> &String {
+        &self.creator_name
+    }
+    /// Example value: "205586603"
+    pub fn video_id(&self) -> &String {
+        &self.video_id
+    }
+    /// Example value: "488191"
+    pub fn game_id(&self) -> &String {
+        &self.game_id
+    }
+    /// Example value: "en"
+    pub fn language(&self) -> &String {
+        &self.language
+    }
+    /// Example value: "babymetal"
+    pub fn title(&self) -> &String {
+        &self.title
+    }
+    /// Example value: 10
+    pub fn view_count(&self) -> u32 {
+        self.view_count
+    }
+    /// Example value: "2017-11-30T22:34:18Z"
+    pub fn created_at(&self) -> &DateTime {
+        &self.created_at
+    }
+    /// Example value: "https://clips-media-assets.twitch.tv/157589949-preview-480x272.jpg"
+    pub fn thumbnail_url(&self) -> &Url {
+        &self.thumbnail_url
+    }
+}
+
+impl CreatedClip {
+    /// Example value: "FiveWordsForClipSlug"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "https://clips.twitch.tv/FiveWordsForClipSlug/edit"
+    pub fn edit_url(&self) -> &Url {
+        &self.edit_url
+    }
+}
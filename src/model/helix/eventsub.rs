@@ -0,0 +1,298 @@
+//! Twitch Helix EventSub subscriptions and events.
+//!
+//! EventSub delivers events (e.g. a stream going online) as webhook notifications
+//! to the callback of a subscription (see `helix::eventsub`).
+
+use std::collections::HashMap;
+
+pub use model::DateTime;
+use model::deserialize_date_time;
+
+
+/// EventSub subscription.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+///   "status": "webhook_callback_verification_pending",
+///   "type": "channel.follow",
+///   "version": "1",
+///   "cost": 1,
+///   "condition": {
+///     "broadcaster_user_id": "12826"
+///   },
+///   "transport": {
+///     // See `SubscriptionTransport` type
+///   },
+///   "created_at": "2019-11-16T10:11:12.123Z"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Subscription {
+    id: String,
+    status: String,
+    #[serde(rename="type")]
+    subscription_type: String,
+    version: String,
+    #[serde(default)]
+    cost: u32,
+    condition: HashMap<String, String>,
+    transport: SubscriptionTransport,
+    #[serde(deserialize_with="deserialize_date_time")]
+    created_at: DateTime,
+}
+
+/// Delivery of the events of an EventSub subscription.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "method": "webhook",
+///   "callback": "https://example.com/webhooks/callback"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct SubscriptionTransport {
+    method: String,
+    callback: String,
+}
+
+/// Event of the `stream.online` subscription type.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": "9001",
+///   "broadcaster_user_id": "1337",
+///   "broadcaster_user_login": "cool_user",
+///   "broadcaster_user_name": "Cool_User",
+///   "type": "live",
+///   "started_at": "2020-10-11T10:11:12.123Z"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct StreamOnlineEvent {
+    id: String,
+    broadcaster_user_id: String,
+    broadcaster_user_login: String,
+    broadcaster_user_name: String,
+    #[serde(rename="type")]
+    stream_type: String,
+    #[serde(deserialize_with="deserialize_date_time")]
+    started_at: DateTime,
+}
+
+/// Event of the `channel.follow` subscription type.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "user_id": "1234",
+///   "user_login": "cool_user",
+///   "user_name": "Cool_User",
+///   "broadcaster_user_id": "1337",
+///   "broadcaster_user_login": "cooler_user",
+///   "broadcaster_user_name": "Cooler_User",
+///   "followed_at": "2020-07-15T18:16:11.17106713Z"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ChannelFollowEvent {
+    user_id: String,
+    user_login: String,
+    user_name: String,
+    broadcaster_user_id: String,
+    broadcaster_user_login: String,
+    broadcaster_user_name: String,
+    #[serde(deserialize_with="deserialize_date_time")]
+    followed_at: DateTime,
+}
+
+/// Event of the `channel.subscribe` subscription type.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "user_id": "1234",
+///   "user_login": "cool_user",
+///   "user_name": "Cool_User",
+///   "broadcaster_user_id": "1337",
+///   "broadcaster_user_login": "cooler_user",
+///   "broadcaster_user_name": "Cooler_User",
+///   "tier": "1000",
+///   "is_gift": false
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ChannelSubscribeEvent {
+    user_id: String,
+    user_login: String,
+    user_name: String,
+    broadcaster_user_id: String,
+    broadcaster_user_login: String,
+    broadcaster_user_name: String,
+    tier: String,
+    #[serde(default)]
+    is_gift: bool,
+}
+
+
+impl Subscription {
+    /// Example value: "f1c2a387-161a-49f9-a165-0f21d7a4e1c4"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "webhook_callback_verification_pending"
+    ///
+    /// Possible values: "enabled", "webhook_callback_verification_pending",
+    /// "webhook_callback_verification_failed", "notification_failures_exceeded",
+    /// "authorization_revoked", "user_removed"
+    pub fn status(&self) -> &String {
+        &self.status
+    }
+    /// Example value: "channel.follow"
+    pub fn subscription_type(&self) -> &String {
+        &self.subscription_type
+    }
+    /// Example value: "1"
+    pub fn version(&self) -> &String {
+        &self.version
+    }
+    /// Example value: 1
+    pub fn cost(&self) -> u32 {
+        self.cost
+    }
+    /// Example value: {"broadcaster_user_id": "12826"}
+    pub fn condition(&self) -> &HashMap<String, String> {
+        &self.condition
+    }
+    /// Example value: See `SubscriptionTransport` type.
+    pub fn transport(&self) -> &SubscriptionTransport {
+        &self.transport
+    }
+    /// Example value: "2019-11-16T10:11:12.123Z"
+    pub fn created_at(&self) -> &DateTime {
+        &self.created_at
+    }
+}
+
+impl SubscriptionTransport {
+    /// Example value: "webhook"
+    pub fn method(&self) -> &String {
+        &self.method
+    }
+    /// Example value: "https://example.com/webhooks/callback"
+    pub fn callback(&self) -> &String {
+        &self.callback
+    }
+}
+
+impl StreamOnlineEvent {
+    /// Id of the stream.
+    ///
+    /// Example value: "9001"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "1337"
+    pub fn broadcaster_user_id(&self) -> &String {
+        &self.broadcaster_user_id
+    }
+    /// Example value: "cool_user"
+    pub fn broadcaster_user_login(&self) -> &String {
+        &self.broadcaster_user_login
+    }
+    /// Example value: "Cool_User"
+    pub fn broadcaster_user_name(&self) -> &String {
+        &self.broadcaster_user_name
+    }
+    /// Example value: "live"
+    ///
+    /// Possible values: "live", "playlist", "watch_party", "premiere", "rerun"
+    pub fn stream_type(&self) -> &String {
+        &self.stream_type
+    }
+    /// Example value: "2020-10-11T10:11:12.123Z"
+    pub fn started_at(&self) -> &DateTime {
+        &self.started_at
+    }
+}
+
+impl ChannelFollowEvent {
+    /// Example value: "1234"
+    pub fn user_id(&self) -> &String {
+        &self.user_id
+    }
+    /// Example value: "cool_user"
+    pub fn user_login(&self) -> &String {
+        &self.user_login
+    }
+    /// Example value: "Cool_User"
+    pub fn user_name(&self) -> &String {
+        &self.user_name
+    }
+    /// Example value: "1337"
+    pub fn broadcaster_user_id(&self) -> &String {
+        &self.broadcaster_user_id
+    }
+    /// Example value: "cooler_user"
+    pub fn broadcaster_user_login(&self) -> &String {
+        &self.broadcaster_user_login
+    }
+    /// Example value: "Cooler_User"
+    pub fn broadcaster_user_name(&self) -> &String {
+        &self.broadcaster_user_name
+    }
+    /// Example value: "2020-07-15T18:16:11.17106713Z"
+    pub fn followed_at(&self) -> &DateTime {
+        &self.followed_at
+    }
+}
+
+impl ChannelSubscribeEvent {
+    /// Example value: "1234"
+    pub fn user_id(&self) -> &String {
+        &self.user_id
+    }
+    /// Example value: "cool_user"
+    pub fn user_login(&self) -> &String {
+        &self.user_login
+    }
+    /// Example value: "Cool_User"
+    pub fn user_name(&self) -> &String {
+        &self.user_name
+    }
+    /// Example value: "1337"
+    pub fn broadcaster_user_id(&self) -> &String {
+        &self.broadcaster_user_id
+    }
+    /// Example value: "cooler_user"
+    pub fn broadcaster_user_login(&self) -> &String {
+        &self.broadcaster_user_login
+    }
+    /// Example value: "Cooler_User"
+    pub fn broadcaster_user_name(&self) -> &String {
+        &self.broadcaster_user_name
+    }
+    /// Example value: "1000"
+    ///
+    /// Possible values: "1000", "2000", "3000"
+    pub fn tier(&self) -> &String {
+        &self.tier
+    }
+    /// Example value: false
+    pub fn is_gift(&self) -> bool {
+        self.is_gift
+    }
+}
@@ -0,0 +1,63 @@
+//! Twitch Helix follows.
+//!
+//! Follow relationships between users.
+
+pub use model::DateTime;
+use model::deserialize_date_time;
+
+
+/// Follow relationship between two users.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "from_id": "171003792",
+///   "from_name": "IIIsutha067III",
+///   "to_id": "23161357",
+///   "to_name": "LIRIK",
+///   "followed_at": "2017-08-22T22:55:24Z"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Follow {
+    from_id: String,
+    from_name: String,
+    to_id: String,
+    to_name: String,
+    #[serde(deserialize_with="deserialize_date_time")]
+    followed_at: DateTime,
+}
+
+
+impl Follow {
+    /// Id of the following user.
+    ///
+    /// Example value: "171003792"
+    pub fn from_id(&self) -> &String {
+        &self.from_id
+    }
+    /// Name of the following user.
+    ///
+    /// Example value: "IIIsutha067III"
+    pub fn from_name(&self) -> &String {
+        &self.from_name
+    }
+    /// Id of the followed user.
+    ///
+    /// Example value: "23161357"
+    pub fn to_id(&self) -> &String {
+        &self.to_id
+    }
+    /// Name of the followed user.
+    ///
+    /// Example value: "LIRIK"
+    pub fn to_name(&self) -> &String {
+        &self.to_name
+    }
+    /// Example value: "2017-08-22T22:55:24Z"
+    pub fn followed_at(&self) -> &DateTime {
+        &self.followed_at
+    }
+}
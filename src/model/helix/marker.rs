@@ -0,0 +1,132 @@
+//! Twitch Helix stream markers.
+//!
+//! Stream markers are timestamps in a live stream that are added by the broadcaster or editors
+//! to mark important moments for later highlighting.
+
+pub use model::DateTime;
+use model::deserialize_date_time;
+pub use model::Url;
+
+
+/// Stream markers of a user grouped by videos.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "user_id": "123",
+///   "user_name": "jswanson",
+///   "videos": [
+///     {
+///       // See `VideoMarkers` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct UserMarkers {
+    user_id: String,
+    user_name: String,
+    videos: Vec<VideoMarkers>,
+}
+
+/// Stream markers of a video.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "video_id": "456",
+///   "markers": [
+///     {
+///       // See `Marker` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct VideoMarkers {
+    video_id: String,
+    markers: Vec<Marker>,
+}
+
+/// Stream marker.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": "106b8d6243a4f883d25ad75e6cdffdc4",
+///   "created_at": "2018-08-20T20:10:03Z",
+///   "description": "hello, this is a marker!",
+///   "position_seconds": 244,
+///   "URL": "https://twitch.tv/videos/456?t=0h4m06s"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Marker {
+    id: String,
+    #[serde(deserialize_with="deserialize_date_time")]
+    created_at: DateTime,
+    description: String,
+    position_seconds: u32,
+    #[serde(rename="URL")]
+    url: Option<Url>,
+}
+
+
+impl UserMarkers {
+    /// Example value: "123"
+    pub fn user_id(&self) -> &String {
+        &self.user_id
+    }
+    /// Example value: "jswanson"
+    pub fn user_name(&self) -> &String {
+        &self.user_name
+    }
+    /// Example value: See `VideoMarkers` type.
+    pub fn videos(&self) -> &Vec<VideoMarkers> {
+        &self.videos
+    }
+}
+
+impl VideoMarkers {
+    /// Example value: "456"
+    pub fn video_id(&self) -> &String {
+        &self.video_id
+    }
+    /// Example value: See `Marker` type.
+    pub fn markers(&self) -> &Vec<Marker> {
+        &self.markers
+    }
+}
+
+impl Marker {
+    /// Example value: "106b8d6243a4f883d25ad75e6cdffdc4"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "2018-08-20T20:10:03Z"
+    pub fn created_at(&self) -> &DateTime {
+        &self.created_at
+    }
+    /// Example value: "hello, this is a marker!"
+    pub fn description(&self) -> &String {
+        &self.description
+    }
+    /// Position of the marker in the stream in seconds.
+    ///
+    /// Example value: 244
+    pub fn position_seconds(&self) -> u32 {
+        self.position_seconds
+    }
+    /// Not set for newly created markers.
+    ///
+    /// Example value: "https://twitch.tv/videos/456?t=0h4m06s"
+    pub fn url(&self) -> &Option<Url> {
+        &self.url
+    }
+}
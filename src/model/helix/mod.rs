@@ -0,0 +1,80 @@
+//! Twitch Helix return types.
+//!
+//! Helix is the new Twitch API. Every response wraps its objects in a `data` array
+//! and optionally contains a cursor for pagination.
+
+pub mod clip;
+pub mod follow;
+pub mod video;
+pub mod marker;
+pub mod tag;
+pub mod bits;
+pub mod eventsub;
+
+
+/// Helix response with a list of objects.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "total": 12345,
+///   "data": [
+///     {
+///       // See the type of the requested objects
+///     }
+///   ],
+///   "pagination": {
+///     // See `Pagination` type
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Data<T> {
+    total: Option<u32>,
+    data: Vec<T>,
+    pagination: Option<Pagination>,
+}
+
+/// Pagination information of a Helix response.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "cursor": "eyJiIjpudWxsLCJhIjoiIn0"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Pagination {
+    cursor: Option<String>,
+}
+
+
+impl<T> Data<T> {
+    /// Only set by endpoints that count all objects (e.g. the follows).
+    ///
+    /// Example value: 12345
+    pub fn total(&self) -> Option<u32> {
+        self.total
+    }
+    /// Example value: See the type of the requested objects.
+    pub fn data(&self) -> &Vec<T> {
+        &self.data
+    }
+    /// Example value: See `Pagination` type.
+    pub fn pagination(&self) -> &Option<Pagination> {
+        &self.pagination
+    }
+}
+
+impl Pagination {
+    /// Cursor for the next or previous page, used with the `after` or `before` parameters.
+    ///
+    /// Example value: "eyJiIjpudWxsLCJhIjoiIn0"
+    pub fn cursor(&self) -> &Option<String> {
+        &self.cursor
+    }
+}
@@ -0,0 +1,59 @@
+//! Twitch Helix stream tags.
+//!
+//! Tags describe streams beyond their game (e.g. the language or the play style).
+
+use std::collections::HashMap;
+
+
+/// Stream tag with localized names and descriptions.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "tag_id": "621fb5bf-5498-4d8f-b4ac-db4d40d401bf",
+///   "is_auto": false,
+///   "localization_names": {
+///     "bg-bg": "Завършване без продължаване",
+///     "en-us": "1 Credit Clear"
+///   },
+///   "localization_descriptions": {
+///     "bg-bg": "За потоци с акцент върху завършване на аркадна игра с монети, в която не се използва продължаване",
+///     "en-us": "For streams with an emphasis on completing an arcade game in a single credit"
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Tag {
+    tag_id: String,
+    is_auto: bool,
+    localization_names: HashMap<String, String>,
+    localization_descriptions: HashMap<String, String>,
+}
+
+
+impl Tag {
+    /// Example value: "621fb5bf-5498-4d8f-b4ac-db4d40d401bf"
+    pub fn tag_id(&self) -> &String {
+        &self.tag_id
+    }
+    /// Whether the tag is automatically set by Twitch and cannot be replaced.
+    ///
+    /// Example value: false
+    pub fn is_auto(&self) -> bool {
+        self.is_auto
+    }
+    /// Names of the tag by locale.
+    ///
+    /// Example value: {"en-us": "1 Credit Clear"}
+    pub fn localization_names(&self) -> &HashMap<String, String> {
+        &self.localization_names
+    }
+    /// Descriptions of the tag by locale.
+    ///
+    /// Example value: {"en-us": "For streams with an emphasis on completing an arcade game in a single credit"}
+    pub fn localization_descriptions(&self) -> &HashMap<String, String> {
+        &self.localization_descriptions
+    }
+}
@@ -0,0 +1,114 @@
+//! Twitch Helix videos.
+//!
+//! Videos are past broadcasts, highlights or uploads of a user.
+
+pub use model::DateTime;
+use model::deserialize_date_time;
+pub use model::Url;
+
+
+/// Video information.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": "234482848",
+///   "user_id": "67955580",
+///   "user_name": "ChewieMelodies",
+///   "title": "-",
+///   "description": "",
+///   "created_at": "2018-03-02T20:53:41Z",
+///   "published_at": "2018-03-02T20:53:41Z",
+///   "url": "https://www.twitch.tv/videos/234482848",
+///   "thumbnail_url": "https://static-cdn.jtvnw.net/s3_vods/bebc8cba2926d1967418_chewiemelodies_27786761696_805342775/thumb/thumb0-%{width}x%{height}.jpg",
+///   "viewable": "public",
+///   "view_count": 142,
+///   "language": "en",
+///   "type": "archive",
+///   "duration": "3h8m33s"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Video {
+    id: String,
+    user_id: String,
+    user_name: String,
+    title: String,
+    description: String,
+    #[serde(deserialize_with="deserialize_date_time")]
+    created_at: DateTime,
+    #[serde(deserialize_with="deserialize_date_time")]
+    published_at: DateTime,
+    url: Url,
+    thumbnail_url: Url,
+    viewable: String,
+    view_count: u32,
+    language: String,
+    #[serde(rename="type")]
+    video_type: String,
+    duration: String,
+}
+
+
+impl Video {
+    /// Example value: "234482848"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "67955580"
+    pub fn user_id(&self) -> &String {
+        &self.user_id
+    }
+    /// Example value: "ChewieMelodies"
+    pub fn user_name(&self) -> &String {
+        &self.user_name
+    }
+    /// Example value: "-"
+    pub fn title(&self) -> &String {
+        &self.title
+    }
+    /// Example value: ""
+    pub fn description(&self) -> &String {
+        &self.description
+    }
+    /// Example value: "2018-03-02T20:53:41Z"
+    pub fn created_at(&self) -> &DateTime {
+        &self.created_at
+    }
+    /// Example value: "2018-03-02T20:53:41Z"
+    pub fn published_at(&self) -> &DateTime {
+        &self.published_at
+    }
+    /// Example value: "https://www.twitch.tv/videos/234482848"
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+    /// Template url where `%{width}` and `%{height}` need to be replaced.
+    ///
+    /// Example value: "https://static-cdn.jtvnw.net/s3_vods/bebc8cba2926d1967418_chewiemelodies_27786761696_805342775/thumb/thumb0-%{width}x%{height}.jpg"
+    pub fn thumbnail_url(&self) -> &Url {
+        &self.thumbnail_url
+    }
+    /// Example value: "public"
+    pub fn viewable(&self) -> &String {
+        &self.viewable
+    }
+    /// Example value: 142
+    pub fn view_count(&self) -> u32 {
+        self.view_count
+    }
+    /// Example value: "en"
+    pub fn language(&self) -> &String {
+        &self.language
+    }
+    /// Example value: "archive"
+    pub fn video_type(&self) -> &String {
+        &self.video_type
+    }
+    /// Example value: "3h8m33s"
+    pub fn duration(&self) -> &String {
+        &self.duration
+    }
+}
@@ -0,0 +1,114 @@
+//! Channels that host other channels.
+//!
+//! Hosts are only available through the undocumented TMI API (see `unofficial`).
+
+pub use model::id::ChannelId;
+
+
+/// Channels that host a target channel.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "hosts": [
+///     {
+///       // See `Host` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Hosts {
+    hosts: Vec<Host>,
+}
+
+/// Channel that hosts (rebroadcasts) the stream of another channel.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "host_id": 21229404,
+///   "target_id": 12345,
+///   "host_login": "test_user1",
+///   "host_display_name": "Test_User1",
+///   "target_login": "test_channel",
+///   "target_display_name": "Test_Channel"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Host {
+    host_id: ChannelId,
+    target_id: ChannelId,
+    #[serde(default)]
+    host_login: Option<String>,
+    #[serde(default)]
+    host_display_name: Option<String>,
+    #[serde(default)]
+    target_login: Option<String>,
+    #[serde(default)]
+    target_display_name: Option<String>,
+}
+
+
+impl Hosts {
+    /// Example value: See `Host` type.
+    pub fn hosts(&self) -> &Vec<Host> {
+        &self.hosts
+    }
+}
+
+impl Host {
+    /// Example value: 21229404
+    ///
+    /// Id of the hosting channel.
+    pub fn host_id(&self) -> ChannelId {
+        self.host_id
+    }
+    /// Example value: 12345
+    ///
+    /// Id of the hosted channel.
+    pub fn target_id(&self) -> ChannelId {
+        self.target_id
+    }
+    /// Example value: "test_user1"
+    pub fn host_login(&self) -> &Option<String> {
+        &self.host_login
+    }
+    /// Example value: "Test_User1"
+    pub fn host_display_name(&self) -> &Option<String> {
+        &self.host_display_name
+    }
+    /// Example value: "test_channel"
+    pub fn target_login(&self) -> &Option<String> {
+        &self.target_login
+    }
+    /// Example value: "Test_Channel"
+    pub fn target_display_name(&self) -> &Option<String> {
+        &self.target_display_name
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+
+    #[test]
+    fn test_should_deserialize_hosts_with_and_without_logins() {
+        let json = r#"{"hosts": [
+            {"host_id": 21229404, "target_id": 12345, "host_login": "test_user1", "host_display_name": "Test_User1",
+             "target_login": "test_channel", "target_display_name": "Test_Channel"},
+            {"host_id": 21229405, "target_id": 12345}
+        ]}"#;
+        let hosts: Hosts = serde_json::from_str(json).unwrap();
+        assert_eq!(hosts.hosts()[0].host_id(), ChannelId::from(21229404));
+        assert_eq!(hosts.hosts()[0].host_display_name(), &Some("Test_User1".to_owned()));
+        assert_eq!(hosts.hosts()[1].target_id(), ChannelId::from(12345));
+        assert_eq!(hosts.hosts()[1].host_login(), &None);
+    }
+}
@@ -0,0 +1,127 @@
+//! Typed identifiers of Twitch objects.
+//!
+//! Distinct types for the ids of channels, users, games and streams prevent passing
+//! one kind of id where another is expected (e.g. a user id to a method that expects a channel).
+//! Twitch sends the ids as numbers in API version 3 and as strings in API version 5, both are accepted.
+
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{self, Visitor};
+
+
+macro_rules! id_type {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+        pub struct $name(u64);
+
+        impl $name {
+            /// Returns the numeric value of the id.
+            pub fn value(&self) -> u64 {
+                self.0
+            }
+        }
+
+        impl From<u64> for $name {
+            fn from(id: u64) -> $name {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(id: $name) -> u64 {
+                id.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIntError;
+
+            fn from_str(s: &str) -> Result<$name, ParseIntError> {
+                s.parse().map($name)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_u64(self.0)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<$name, D::Error> {
+                deserializer.deserialize_any(IdVisitor).map($name)
+            }
+        }
+    };
+}
+
+id_type! {
+    /// Id of a channel, which is the same as the id of the user that owns the channel.
+    ChannelId
+}
+
+id_type! {
+    /// Id of a user.
+    UserId
+}
+
+id_type! {
+    /// Id of a game.
+    GameId
+}
+
+id_type! {
+    /// Id of a live stream.
+    StreamId
+}
+
+/// Accepts ids as numbers (API version 3) or as strings (API version 5).
+struct IdVisitor;
+
+impl<'de> Visitor<'de> for IdVisitor {
+    type Value = u64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an id as number or string")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<u64, E> {
+        Ok(value)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<u64, E> {
+        value.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use param::IdOrName;
+    use super::*;
+
+    #[test]
+    fn test_should_deserialize_number_and_string() {
+        assert_eq!(serde_json::from_str::<ChannelId>("12826").unwrap(), ChannelId::from(12826));
+        assert_eq!(serde_json::from_str::<ChannelId>(r#""12826""#).unwrap(), ChannelId::from(12826));
+        assert!(serde_json::from_str::<ChannelId>(r#""twitch""#).is_err());
+    }
+
+    #[test]
+    fn test_should_display_and_parse() {
+        let id: UserId = "21229404".parse().unwrap();
+        assert_eq!(id.to_string(), "21229404");
+        assert_eq!(IdOrName::from(id), IdOrName::Id(21229404));
+    }
+}
@@ -3,7 +3,35 @@
 //! Image types used in other models.
 
 
-pub use model::UrlString;
+pub use model::Url;
+#[cfg(feature = "model-builders")]
+use model::url_from_str;
+
+/// Quality of a preview image (or other image) to select from the image links of a model.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::model::image::PreviewQuality;
+///
+/// let _quality = PreviewQuality::Custom { width: 1280, height: 720 };
+/// ```
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum PreviewQuality {
+    /// The small image (e.g. 80x45 for stream previews).
+    Small,
+    /// The medium image (e.g. 320x180 for stream previews).
+    Medium,
+    /// The large image (e.g. 640x360 for stream previews).
+    Large,
+    /// An image with the custom size in pixels, rendered by Twitch from the template.
+    Custom {
+        /// Width in pixels.
+        width: u32,
+        /// Height in pixels.
+        height: u32,
+    },
+}
 
 /// Twitch (preview) image links.
 ///
@@ -19,30 +47,80 @@ pub use model::UrlString;
 ///   "template": "http://static-cdn.jtvnw.net/previews-ttv/live_user_test_channel-{width}x{height}.jpg"
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct ImageLinks {
-    template: UrlString,
-    small: UrlString,
-    medium: UrlString,
-    large: UrlString,
+    template: Url,
+    small: Url,
+    medium: Url,
+    large: Url,
 }
 
 
 impl ImageLinks {
+    /// Creates the image links of the template with `{width}` and `{height}` placeholders
+    /// and the sizes (width, height) of the small, medium and large images.
+    #[cfg(feature = "model-builders")]
+    pub fn from_template(template: &str, small: (u32, u32), medium: (u32, u32), large: (u32, u32)) -> ImageLinks {
+        let image = |(width, height): (u32, u32)| url_from_str(&fill_template(template, width, height));
+        ImageLinks {
+            template: url_from_str(template),
+            small: image(small),
+            medium: image(medium),
+            large: image(large),
+        }
+    }
     /// Example value: "http://static-cdn.jtvnw.net/previews-ttv/live_user_test_channel-{width}x{height}.jpg"
-    pub fn template(&self) -> &UrlString {
+    pub fn template(&self) -> &Url {
         &self.template
     }
     /// Example value: "http://static-cdn.jtvnw.net/previews-ttv/live_user_test_channel-80x45.jpg"
-    pub fn small(&self) -> &UrlString {
+    pub fn small(&self) -> &Url {
         &self.small
     }
     /// Example value: "http://static-cdn.jtvnw.net/previews-ttv/live_user_test_channel-320x180.jpg"
-    pub fn medium(&self) -> &UrlString {
+    pub fn medium(&self) -> &Url {
         &self.medium
     }
     /// Example value: "http://static-cdn.jtvnw.net/previews-ttv/live_user_test_channel-640x360.jpg"
-    pub fn large(&self) -> &UrlString {
+    pub fn large(&self) -> &Url {
         &self.large
     }
+    /// Returns the url of the image with the quality, filling in the template for `PreviewQuality::Custom`.
+    ///
+    /// Example value: "http://static-cdn.jtvnw.net/previews-ttv/live_user_test_channel-1280x720.jpg"
+    pub fn url(&self, quality: PreviewQuality) -> String {
+        match quality {
+            PreviewQuality::Small => self.small.to_string(),
+            PreviewQuality::Medium => self.medium.to_string(),
+            PreviewQuality::Large => self.large.to_string(),
+            PreviewQuality::Custom { width, height } => fill_template(&self.template.to_string(), width, height),
+        }
+    }
+}
+
+/// Replaces the `{width}` and `{height}` placeholders of the template.
+pub(crate) fn fill_template(template: &str, width: u32, height: u32) -> String {
+    template.replace("{width}", &width.to_string()).replace("{height}", &height.to_string())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+
+    #[test]
+    fn test_url_should_select_quality() {
+        let image_links: ImageLinks = serde_json::from_str(r#"{
+            "small": "https://static-cdn.jtvnw.net/previews-ttv/live_user_test_channel-80x45.jpg",
+            "medium": "https://static-cdn.jtvnw.net/previews-ttv/live_user_test_channel-320x180.jpg",
+            "large": "https://static-cdn.jtvnw.net/previews-ttv/live_user_test_channel-640x360.jpg",
+            "template": "https://static-cdn.jtvnw.net/previews-ttv/live_user_test_channel-{width}x{height}.jpg"
+        }"#).unwrap();
+        assert_eq!(image_links.url(PreviewQuality::Medium),
+                "https://static-cdn.jtvnw.net/previews-ttv/live_user_test_channel-320x180.jpg");
+        assert_eq!(image_links.url(PreviewQuality::Custom { width: 1280, height: 720 }),
+                "https://static-cdn.jtvnw.net/previews-ttv/live_user_test_channel-1280x720.jpg");
+    }
 }
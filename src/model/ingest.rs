@@ -4,6 +4,12 @@
 //! By directing an RTMP stream with your `stream_key` injected into the `url_template`,
 //! you will broadcast your content live on Twitch.
 
+use std::cmp::Ordering;
+
+pub use model::{Links, TwitchLinks};
+use model::deserialize_links;
+
+
 /// List of ingests.
 ///
 /// # Example in JSON
@@ -20,13 +26,18 @@
 ///   ]
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Ingests {
     ingests: Vec<Ingest>,
+    #[serde(rename="_links", default, deserialize_with="deserialize_links")]
+    links: Links,
 }
 
 /// Ingest point.
 ///
+/// Ingests are ordered by their availability (then by id), e.g. to sort them from worst to best.
+///
 /// # Example in JSON
 ///
 /// ```json
@@ -38,7 +49,8 @@ pub struct Ingests {
 ///   "availability": 1.0
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Ingest {
     name: String,
     availability: f64,
@@ -54,6 +66,36 @@ impl Ingests {
     pub fn ingests(&self) -> &Vec<Ingest> {
         &self.ingests
     }
+    /// Returns the ingest with the highest availability, preferring the default ingest on ties,
+    /// or `None` if no ingest is available.
+    pub fn best_available(&self) -> Option<&Ingest> {
+        best_available(self.ingests.iter())
+    }
+    /// Returns the best available ingest (see `best_available`) whose name starts with the region prefix,
+    /// ignoring the case (e.g. "EU" or "US West").
+    pub fn closest_by_name(&self, region_prefix: &str) -> Option<&Ingest> {
+        let region_prefix = region_prefix.to_lowercase();
+        best_available(self.ingests.iter().filter(|ingest| ingest.name.to_lowercase().starts_with(&region_prefix)))
+    }
+}
+
+fn best_available<'a, I: Iterator<Item=&'a Ingest>>(ingests: I) -> Option<&'a Ingest> {
+    ingests
+            .filter(|ingest| ingest.availability > 0.0)
+            .fold(None, |best: Option<&Ingest>, ingest| match best {
+                Some(best) => match ingest.availability.partial_cmp(&best.availability) {
+                    Some(Ordering::Greater) => Some(ingest),
+                    Some(Ordering::Equal) if ingest.default => Some(ingest),
+                    _ => Some(best),
+                },
+                None => Some(ingest),
+            })
+}
+
+impl TwitchLinks for Ingests {
+    fn links(&self) -> &Links {
+        &self.links
+    }
 }
 
 impl Ingest {
@@ -77,4 +119,71 @@ impl Ingest {
     pub fn url_template(&self) -> &String {
         &self.url_template
     }
+    /// Returns the RTMP url to broadcast to with the stream key.
+    ///
+    /// Example value: "rtmp://live-ams.twitch.tv/app/live_12345_abcdef"
+    pub fn url_for_key(&self, stream_key: &str) -> String {
+        self.url_template.replace("{stream_key}", stream_key)
+    }
+}
+
+impl PartialOrd for Ingest {
+    fn partial_cmp(&self, other: &Ingest) -> Option<Ordering> {
+        let ordering = match self.availability.partial_cmp(&other.availability) {
+            Some(Ordering::Equal) => self.id.cmp(&other.id),
+            Some(ordering) => ordering,
+            None => return None,
+        };
+        if ordering == Ordering::Equal && self != other {
+            return None;
+        }
+        Some(ordering)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+
+    fn ingests() -> Ingests {
+        serde_json::from_str(r#"{"ingests": [
+            {"name": "US West: San Francisco, CA", "default": false, "_id": 1,
+             "url_template": "rtmp://live-sfo.twitch.tv/app/{stream_key}", "availability": 0.5},
+            {"name": "EU: Amsterdam, NL", "default": false, "_id": 24,
+             "url_template": "rtmp://live-ams.twitch.tv/app/{stream_key}", "availability": 1.0},
+            {"name": "EU: Frankfurt, DE", "default": true, "_id": 25,
+             "url_template": "rtmp://live-fra.twitch.tv/app/{stream_key}", "availability": 1.0},
+            {"name": "US East: New York, NY", "default": false, "_id": 26,
+             "url_template": "rtmp://live-jfk.twitch.tv/app/{stream_key}", "availability": 0.0}
+        ]}"#).unwrap()
+    }
+
+    #[test]
+    fn test_best_available_should_prefer_default_on_ties() {
+        assert_eq!(ingests().best_available().unwrap().id(), 25);
+    }
+
+    #[test]
+    fn test_closest_by_name_should_ignore_unavailable() {
+        let ingests = ingests();
+        assert_eq!(ingests.closest_by_name("us").unwrap().id(), 1);
+        assert_eq!(ingests.closest_by_name("US East").map(|ingest| ingest.id()), None);
+    }
+
+    #[test]
+    fn test_should_order_by_availability() {
+        let mut ingests = ingests().ingests().clone();
+        ingests.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        let ids: Vec<u64> = ingests.iter().map(|ingest| ingest.id()).collect();
+        assert_eq!(ids, vec![25, 24, 1, 26]);
+    }
+
+    #[test]
+    fn test_url_for_key() {
+        let ingests = ingests();
+        let ingest = ingests.closest_by_name("EU: Amsterdam").unwrap();
+        assert_eq!(ingest.url_for_key("live_12345_abcdef"), "rtmp://live-ams.twitch.tv/app/live_12345_abcdef");
+    }
 }
@@ -0,0 +1,123 @@
+//! Languages of channels, broadcasts and clips.
+
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+
+/// Language in [ISO 639-1](https://en.wikipedia.org/wiki/ISO_639-1) codes format (e.g.: "en").
+///
+/// Languages that are used by many channels have their own variant,
+/// any other code (including regional codes like "zh-tw") is kept as `Language::Other`.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::model::language::Language;
+///
+/// assert_eq!(Language::from("en"), Language::English);
+/// assert_eq!(Language::from("EN"), Language::English);
+/// assert_eq!(Language::from("zh-tw"), Language::Other("zh-tw".to_owned()));
+/// assert_eq!(Language::German.code(), "de");
+/// ```
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Language {
+    /// "en"
+    English,
+    /// "de"
+    German,
+    /// "fr"
+    French,
+    /// "es"
+    Spanish,
+    /// "it"
+    Italian,
+    /// "pt"
+    Portuguese,
+    /// "ru"
+    Russian,
+    /// "pl"
+    Polish,
+    /// "ja"
+    Japanese,
+    /// "ko"
+    Korean,
+    /// "zh"
+    Chinese,
+    /// Any other language code as sent by Twitch.
+    Other(String),
+}
+
+impl Language {
+    /// Returns the language code (e.g.: "en").
+    pub fn code(&self) -> &str {
+        match *self {
+            Language::English => "en",
+            Language::German => "de",
+            Language::French => "fr",
+            Language::Spanish => "es",
+            Language::Italian => "it",
+            Language::Portuguese => "pt",
+            Language::Russian => "ru",
+            Language::Polish => "pl",
+            Language::Japanese => "ja",
+            Language::Korean => "ko",
+            Language::Chinese => "zh",
+            Language::Other(ref code) => code,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Language {
+    fn from(code: &'a str) -> Language {
+        match code.to_lowercase().as_str() {
+            "en" => Language::English,
+            "de" => Language::German,
+            "fr" => Language::French,
+            "es" => Language::Spanish,
+            "it" => Language::Italian,
+            "pt" => Language::Portuguese,
+            "ru" => Language::Russian,
+            "pl" => Language::Polish,
+            "ja" => Language::Japanese,
+            "ko" => Language::Korean,
+            "zh" => Language::Chinese,
+            _ => Language::Other(code.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl Serialize for Language {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Language {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Language, D::Error> {
+        let code = try!(String::deserialize(deserializer));
+        Ok(Language::from(code.as_str()))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+
+    #[test]
+    fn test_should_keep_unknown_language_code() {
+        let language: Language = serde_json::from_str(r#""zh-tw""#).unwrap();
+        assert_eq!(language, Language::Other("zh-tw".to_owned()));
+        assert_eq!(serde_json::to_string(&language).unwrap(), r#""zh-tw""#);
+        assert_eq!(serde_json::to_string(&Language::English).unwrap(), r#""en""#);
+    }
+}
@@ -1,25 +1,482 @@
 //! Twitch return types.
+//!
+//! # Stability
+//!
+//! Twitch adds fields to its responses without notice, so the models are `#[non_exhaustive]`
+//! and their fields are only accessible with getters (named like the JSON field without leading underscore).
+//! New fields are added as new getters in minor versions, whereas removing or changing a getter is a breaking change.
+//! Enums of values sent by Twitch (e.g. `language::Language` or `scope::Scope`) are `#[non_exhaustive]` as well,
+//! so matches need a wildcard arm. Where Twitch may send unknown values, they are kept in an `Other` variant.
 
+pub mod id;
 pub mod image;
 pub mod game;
 pub mod ingest;
+pub mod language;
 pub mod root;
 pub mod stream;
 pub mod channel;
+pub mod user;
+pub mod follow;
+pub mod chat;
+pub mod subscription;
+pub mod block;
+pub mod video;
+pub mod clip;
+pub mod community;
+pub mod collection;
+pub mod feed;
+pub mod helix;
+pub mod auth;
+pub mod scope;
+pub mod pubsub;
+pub mod usher;
+pub mod borrowed;
+mod de;
+#[cfg(feature = "unofficial")]
+pub mod chatter;
+#[cfg(feature = "unofficial")]
+pub mod host;
+#[cfg(feature = "unofficial")]
+pub mod panel;
 
+use std::fmt;
+use std::collections::HashMap;
+use std::io::Read;
+use std::time::Duration;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::DeserializeOwned;
+use serde_ignored;
+use serde_json;
 
-/// Strings that contain a hyperlink (e.g.: "http://static-cdn.jtvnw.net/jtv_user_pictures/test_channel-profile_image-94a42b3a13c31c02-300x300.jpeg").
+use error::{Result as TwitchResult, Error as TwitchError};
+use StrictMode;
+#[cfg(feature = "chrono")]
+use serde::de::Error as DeError;
+
+/// Hyperlinks (e.g.: "http://static-cdn.jtvnw.net/jtv_user_pictures/test_channel-profile_image-94a42b3a13c31c02-300x300.jpeg").
+///
+/// With the `typed-urls` feature this is a `LenientUrl` containing the validated `url::Url`,
+/// otherwise the string sent by Twitch.
+#[cfg(feature = "typed-urls")]
+pub type Url = LenientUrl;
+
+/// Hyperlinks (e.g.: "http://static-cdn.jtvnw.net/jtv_user_pictures/test_channel-profile_image-94a42b3a13c31c02-300x300.jpeg").
+///
+/// With the `typed-urls` feature this is a `LenientUrl` containing the validated `url::Url`,
+/// otherwise the string sent by Twitch.
+#[cfg(not(feature = "typed-urls"))]
+pub type Url = String;
+
+/// Hyperlinks (e.g.: "http://www.twitch.tv/test_channel").
+///
+/// Kept as alias of `Url`, which replaced the plain strings.
+#[doc(hidden)]
+pub type UrlString = Url;
+
+/// Hyperlink that is either a valid url or the raw string sent by Twitch.
+///
+/// Malformed values and templates with placeholders
+/// (e.g.: "rtmp://live.twitch.tv/app/{stream_key}") are kept as raw strings,
+/// so that the deserialization of the whole response does not fail and templates are not percent encoded.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum LenientUrl {
+    /// Valid url.
+    Valid(::url::Url),
+    /// Raw string that is not a valid url.
+    Raw(String),
+}
+
+impl LenientUrl {
+    /// Parses the value into a valid url if possible and otherwise keeps the raw string.
+    pub fn parse(value: &str) -> LenientUrl {
+        if value.contains('{') || value.contains('}') {
+            return LenientUrl::Raw(value.to_owned());
+        }
+        match ::url::Url::parse(value) {
+            Ok(url) => LenientUrl::Valid(url),
+            Err(_) => LenientUrl::Raw(value.to_owned()),
+        }
+    }
+    /// Returns the url as string.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LenientUrl::Valid(ref url) => url.as_str(),
+            LenientUrl::Raw(ref raw) => raw,
+        }
+    }
+    /// Returns the url if it is valid.
+    pub fn url(&self) -> Option<&::url::Url> {
+        match *self {
+            LenientUrl::Valid(ref url) => Some(url),
+            LenientUrl::Raw(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for LenientUrl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for LenientUrl {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for LenientUrl {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<LenientUrl, D::Error> {
+        let value = try!(String::deserialize(deserializer));
+        Ok(LenientUrl::parse(&value))
+    }
+}
+
+/// Dates with time in UTC (e.g.: "2015-02-12T04:42:31Z").
+///
+/// With the `chrono` feature this is a `chrono::DateTime<Utc>`,
+/// otherwise the string in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format sent by Twitch.
+#[cfg(feature = "chrono")]
+pub type DateTime = ::chrono::DateTime<::chrono::Utc>;
+
+/// Dates with time in UTC (e.g.: "2015-02-12T04:42:31Z").
+///
+/// With the `chrono` feature this is a `chrono::DateTime<Utc>`,
+/// otherwise the string in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format sent by Twitch.
+#[cfg(not(feature = "chrono"))]
+pub type DateTime = String;
+
+/// Dates with time in UTC (e.g.: "2015-02-12T04:42:31Z").
+///
+/// Kept as alias of `DateTime`, which replaced the plain strings.
+#[doc(hidden)]
+pub type DateString = DateTime;
+
+/// Locale in [ISO 639-1](https://en.wikipedia.org/wiki/ISO_639-1) codes format (2 letter locales e.g.: "en").
+///
+/// Kept as alias of `language::Language`, which replaced the plain strings.
+pub type LocaleString = language::Language;
+
+
+/// Hyperlinks of a response by their relation (e.g.: "self", "next" or "channel").
+pub type Links = HashMap<String, Url>;
+
+/// Models that contain the `_links` sent by Twitch, for navigating to related resources and pages.
+///
+/// # Examples
+///
+/// ```no_run
+/// use twitch_client::TwitchClient;
+/// use twitch_client::model::TwitchLinks;
+///
+/// let client = TwitchClient::new("jzkbprff40iqj646a697cyrvl0zt2m6").unwrap();
+/// let ingests = client.ingests().unwrap();
+/// if let Some(url) = ingests.link("self") {
+///     println!("{}", url);
+/// }
+/// ```
+pub trait TwitchLinks {
+    /// Returns all hyperlinks by their relation.
+    fn links(&self) -> &Links;
+
+    /// Returns the hyperlink of the relation (e.g.: "next").
+    fn link(&self, rel: &str) -> Option<&Url> {
+        self.links().get(rel)
+    }
+}
+
+/// Converts the model into a JSON value, e.g. to persist fetched data in an own store.
+///
+/// Every model serializes symmetrically to its deserialization,
+/// so the value (or any other serde format like MessagePack or CBOR) can be converted back losslessly.
+///
+/// # Examples
+///
+/// ```
+/// extern crate serde_json;
+/// extern crate twitch_client;
 ///
-/// Is subject to be changed to a real hyperlink type in the future.
-pub type UrlString = String;
+/// use twitch_client::model;
+/// use twitch_client::model::ingest::Ingests;
+///
+/// # fn main() {
+/// let ingests: Ingests = serde_json::from_str(r#"{"ingests": []}"#).unwrap();
+/// let value = model::to_value(&ingests).unwrap();
+/// assert_eq!(model::from_value::<Ingests>(value).unwrap(), ingests);
+/// # }
+/// ```
+pub fn to_value<T: Serialize>(model: &T) -> serde_json::Result<serde_json::Value> {
+    serde_json::to_value(model)
+}
+
+/// Converts a JSON value (e.g. created by `to_value`) back into the model.
+pub fn from_value<T: DeserializeOwned>(value: serde_json::Value) -> serde_json::Result<T> {
+    serde_json::from_value(value)
+}
 
-/// Strings that contain a date in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format (e.g.: "2015-02-12T04:42:31Z").
+/// Deserializes the JSON into a model that borrows its strings from the JSON (see `borrowed`).
 ///
-/// Is subject to be changed to a real datetime type in the future.
-pub type DateString = String;
+/// The models are always deserialized leniently.
+pub fn from_json_borrowed<'a, T: Deserialize<'a>>(json: &'a str) -> serde_json::Result<T> {
+    serde_json::from_str(json)
+}
+
+/// Deserializes `_links`, skipping relations without a hyperlink (`null`).
+pub(crate) fn deserialize_links<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Links, D::Error> {
+    let links: HashMap<String, Option<Url>> = try!(HashMap::deserialize(deserializer));
+    Ok(links.into_iter()
+            .filter_map(|(rel, url)| url.map(|url| (rel, url)))
+            .collect())
+}
+
 
-/// Strings that contain a locale in [ISO 639-1](https://en.wikipedia.org/wiki/ISO_639-1) codes format (2 letter locales e.g.: "en").
+/// Deserializes the JSON response into the model.
 ///
-/// Is subject to be changed to a real locale type in the future.
-pub type LocaleString = String;
+/// In `StrictMode::Strict` fields that are not known by the model (except `_links`) are reported as error.
+#[cfg_attr(not(any(feature = "blocking", feature = "async")), allow(dead_code))]
+pub(crate) fn from_json<T: DeserializeOwned>(json: &str, strict_mode: StrictMode) -> TwitchResult<T> {
+    from_deserializer(serde_json::Deserializer::from_str(json), strict_mode)
+}
+
+/// Deserializes the JSON response directly from the reader into the model (see `from_json`),
+/// without buffering the whole response.
+#[cfg_attr(not(feature = "blocking"), allow(dead_code))]
+pub(crate) fn from_json_reader<T: DeserializeOwned, R: Read>(reader: R, strict_mode: StrictMode) -> TwitchResult<T> {
+    from_deserializer(serde_json::Deserializer::from_reader(reader), strict_mode)
+}
+
+fn from_deserializer<'de, T: DeserializeOwned, R: serde_json::de::Read<'de>>(
+        mut deserializer: serde_json::Deserializer<R>, strict_mode: StrictMode) -> TwitchResult<T> {
+    if strict_mode == StrictMode::Lenient {
+        let model = try!(T::deserialize(&mut deserializer));
+        try!(deserializer.end());
+        return Ok(model);
+    }
+
+    let mut unknown_fields = Vec::new();
+    let model: T = try!(serde_ignored::deserialize(&mut deserializer, |path| {
+        let path = path.to_string();
+        if !path.ends_with("_links") {
+            unknown_fields.push(path);
+        }
+    }));
+    try!(deserializer.end());
+    if !unknown_fields.is_empty() {
+        return Err(TwitchError::UnknownFields(unknown_fields));
+    }
+    Ok(model)
+}
+
+
+/// Deserializes a `DateTime`, tolerating the different formats used by Twitch:
+/// RFC 3339 with or without fractional seconds and dates without time zone (assumed to be UTC).
+#[cfg(feature = "chrono")]
+pub(crate) fn deserialize_date_time<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime, D::Error> {
+    let value = try!(String::deserialize(deserializer));
+    parse_date_time(&value).ok_or_else(|| D::Error::custom(format!("invalid date: {}", value)))
+}
+
+/// Deserializes a `DateTime`, which is the unchanged string without the `chrono` feature.
+#[cfg(not(feature = "chrono"))]
+pub(crate) fn deserialize_date_time<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime, D::Error> {
+    String::deserialize(deserializer)
+}
+
+/// Creates a `Url` from the string, for models that are built instead of deserialized.
+#[cfg(all(feature = "model-builders", feature = "typed-urls"))]
+pub(crate) fn url_from_str(value: &str) -> Url {
+    LenientUrl::parse(value)
+}
+
+/// Creates a `Url` from the string, for models that are built instead of deserialized.
+#[cfg(all(feature = "model-builders", not(feature = "typed-urls")))]
+pub(crate) fn url_from_str(value: &str) -> Url {
+    value.to_owned()
+}
+
+/// Start of the Unix epoch, used as default date of built models.
+#[cfg(all(feature = "model-builders", feature = "chrono"))]
+pub(crate) fn unix_epoch() -> DateTime {
+    use chrono::TimeZone;
+    ::chrono::Utc.timestamp(0, 0)
+}
+
+/// Start of the Unix epoch, used as default date of built models.
+#[cfg(all(feature = "model-builders", not(feature = "chrono")))]
+pub(crate) fn unix_epoch() -> DateTime {
+    "1970-01-01T00:00:00Z".to_owned()
+}
+
+/// Returns the duration from `since` until `now`, which is zero if `since` is after `now` (e.g. due to clock skew).
+#[cfg(feature = "chrono")]
+pub(crate) fn duration_between(since: &DateTime, now: &DateTime) -> Duration {
+    now.signed_duration_since(*since).to_std().unwrap_or_else(|_| Duration::from_secs(0))
+}
+
+/// Formats the duration with days, hours, minutes and seconds without leading zero units (e.g. "2h 5m 3s"),
+/// e.g. to answer "!uptime" in chat with `Stream::uptime`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use twitch_client::model::format_duration;
+///
+/// assert_eq!(format_duration(Duration::from_secs(2 * 3600 + 5 * 60 + 3)), "2h 5m 3s");
+/// assert_eq!(format_duration(Duration::from_secs(42)), "42s");
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let units = [(seconds / 86400, "d"), (seconds / 3600 % 24, "h"), (seconds / 60 % 60, "m"), (seconds % 60, "s")];
+    let parts: Vec<String> = units.iter()
+            .skip_while(|&&(value, unit)| value == 0 && unit != "s")
+            .map(|&(value, unit)| format!("{}{}", value, unit))
+            .collect();
+    parts.join(" ")
+}
+
+#[cfg(feature = "chrono")]
+fn parse_date_time(value: &str) -> Option<DateTime> {
+    use chrono::{NaiveDateTime, Utc};
+
+    if let Ok(date_time) = ::chrono::DateTime::parse_from_rfc3339(value) {
+        return Some(date_time.with_timezone(&Utc));
+    }
+    if let Ok(date_time) = ::chrono::DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z") {
+        return Some(date_time.with_timezone(&Utc));
+    }
+    ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"].iter()
+            .filter_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+            .map(|naive_date_time| ::chrono::DateTime::from_utc(naive_date_time, Utc))
+            .next()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "chrono")]
+    use chrono::{TimeZone, Utc};
+
+    /// Asserts that the model of the fixture serializes into a value that deserializes into an equal model
+    /// and serializes into the same value again.
+    macro_rules! assert_round_trip {
+        ($model:ty, $fixture:expr) => {{
+            let model: $model = serde_json::from_str(include_str!(concat!("../testing/fixtures/", $fixture)))
+                    .expect(concat!("deserialize ", $fixture));
+            let value = to_value(&model).unwrap();
+            let round_tripped: $model = from_value(value.clone()).expect(concat!("round trip ", $fixture));
+            assert_eq!(round_tripped, model, "round trip of {}", $fixture);
+            assert_eq!(to_value(&round_tripped).unwrap(), value, "serialization of {}", $fixture);
+        }};
+    }
+
+    #[test]
+    fn test_models_should_round_trip() {
+        assert_round_trip!(root::BasicInfo, "basic_info.json");
+        assert_round_trip!(game::TopGames, "top_games.json");
+        assert_round_trip!(game::GameSearchResults, "search_games.json");
+        assert_round_trip!(ingest::Ingests, "ingests.json");
+        assert_round_trip!(stream::ChannelStream, "channel_stream.json");
+        assert_round_trip!(stream::Streams, "streams.json");
+        assert_round_trip!(stream::FeaturedStreams, "featured_streams.json");
+        assert_round_trip!(stream::StreamsSummary, "streams_summary.json");
+        assert_round_trip!(stream::Stream, "stream_inconsistent_nulls.json");
+        assert_round_trip!(channel::Channel, "channel.json");
+        assert_round_trip!(channel::Channel, "channel_inconsistent_nulls.json");
+        assert_round_trip!(channel::Editors, "editors.json");
+        assert_round_trip!(follow::Follows, "follows.json");
+        assert_round_trip!(follow::Follow, "follow.json");
+        assert_round_trip!(subscription::Subscription, "subscription.json");
+        assert_round_trip!(block::Blocks, "blocks.json");
+        assert_round_trip!(block::Block, "block.json");
+        assert_round_trip!(video::Video, "video.json");
+        assert_round_trip!(video::Videos, "videos.json");
+        assert_round_trip!(feed::Posts, "posts.json");
+        assert_round_trip!(feed::Post, "post.json");
+        assert_round_trip!(feed::Reaction, "reaction.json");
+        assert_round_trip!(chat::Emoticons, "emoticons.json");
+        assert_round_trip!(chat::EmoticonImages, "emoticon_images.json");
+        assert_round_trip!(chat::Badges, "badges.json");
+        assert_round_trip!(user::Users, "users.json");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(0)), "0s");
+        assert_eq!(format_duration(Duration::from_secs(3600)), "1h 0m 0s");
+        assert_eq!(format_duration(Duration::from_secs(3 * 86400 + 59)), "3d 0h 0m 59s");
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_duration_between_should_not_be_negative() {
+        let since = Utc.ymd(2015, 2, 12).and_hms(4, 42, 31);
+        let now = Utc.ymd(2015, 2, 12).and_hms(6, 47, 34);
+        assert_eq!(duration_between(&since, &now), Duration::from_secs(2 * 3600 + 5 * 60 + 3));
+        assert_eq!(duration_between(&now, &since), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn test_lenient_url_should_keep_malformed_values_and_templates() {
+        let url = LenientUrl::parse("https://www.twitch.tv/test_channel");
+        assert_eq!(url.url().map(|url| url.host_str()), Some(Some("www.twitch.tv")));
+        assert_eq!(LenientUrl::parse("not a url"), LenientUrl::Raw("not a url".to_owned()));
+        let template = "rtmp://live.twitch.tv/app/{stream_key}";
+        assert_eq!(LenientUrl::parse(template).as_str(), template);
+    }
+
+    #[test]
+    fn test_links_should_skip_null_values() {
+        let json = r#"{
+            "_links": {
+                "self": "https://api.twitch.tv/kraken/ingests",
+                "next": null
+            },
+            "ingests": []
+        }"#;
+        let ingests: ingest::Ingests = ::serde_json::from_str(json).unwrap();
+        assert_eq!(ingests.links().len(), 1);
+        assert_eq!(ingests.link("self").map(|url| url.to_string()),
+                Some("https://api.twitch.tv/kraken/ingests".to_owned()));
+        assert!(ingests.link("next").is_none());
+    }
+
+    #[test]
+    fn test_from_json_should_report_unknown_fields_in_strict_mode() {
+        let json = r#"{
+            "_links": {},
+            "ingests": [],
+            "unknown": true
+        }"#;
+        let ingests: ingest::Ingests = from_json(json, StrictMode::Lenient).unwrap();
+        assert!(ingests.ingests().is_empty());
+        match from_json::<ingest::Ingests>(json, StrictMode::Strict) {
+            Err(TwitchError::UnknownFields(unknown_fields)) => assert_eq!(unknown_fields, vec!["unknown".to_owned()]),
+            result => panic!("expecting unknown fields error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_from_json_reader_should_match_from_json() {
+        let json = r#"{"_links": {}, "ingests": [], "unknown": true}"#;
+        let ingests: ingest::Ingests = from_json_reader(json.as_bytes(), StrictMode::Lenient).unwrap();
+        assert_eq!(ingests, from_json(json, StrictMode::Lenient).unwrap());
+        assert!(from_json_reader::<ingest::Ingests, _>(json.as_bytes(), StrictMode::Strict).is_err());
+        assert!(from_json_reader::<ingest::Ingests, _>(&b"{} trailing"[..], StrictMode::Lenient).is_err());
+    }
 
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_parse_date_time_formats() {
+        let expected = Utc.ymd(2015, 2, 12).and_hms(4, 42, 31);
+        assert_eq!(parse_date_time("2015-02-12T04:42:31Z"), Some(expected));
+        assert_eq!(parse_date_time("2015-02-12T04:42:31+00:00"), Some(expected));
+        assert_eq!(parse_date_time("2015-02-12T04:42:31+0000"), Some(expected));
+        assert_eq!(parse_date_time("2015-02-12T04:42:31"), Some(expected));
+        assert_eq!(parse_date_time("2015-02-12 04:42:31"), Some(expected));
+        assert_eq!(parse_date_time("2015-02-12T04:42:31.000000Z"), Some(expected));
+        assert_eq!(parse_date_time("yesterday"), None);
+    }
+}
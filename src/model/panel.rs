@@ -0,0 +1,204 @@
+//! Panels below the video player of a channel.
+//!
+//! Panels are only available through the undocumented Twitch API (see `unofficial`).
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub use model::id::UserId;
+
+
+/// Panel of a channel.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_id": 42153012,
+///   "display_order": 0,
+///   "kind": "default",
+///   "html_description": "<p>Check out my <a href=\"https://example.com\">website</a></p>",
+///   "user_id": 12345,
+///   "data": {
+///     // See `PanelData` type
+///   },
+///   "channel": "test_channel"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Panel {
+    #[serde(rename="_id")]
+    id: u64,
+    display_order: u32,
+    kind: PanelKind,
+    #[serde(default)]
+    html_description: Option<String>,
+    user_id: UserId,
+    #[serde(default)]
+    data: PanelData,
+    channel: String,
+}
+
+/// Content of a panel as entered by the broadcaster.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "link": "https://example.com",
+///   "image": "https://panels-images.twitch.tv/panel-12345-image-8f7b1ce4-ee11-4f5c-a6e2-0d3a4f7c2c38",
+///   "title": "Website",
+///   "description": "Check out my [website](https://example.com)"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct PanelData {
+    #[serde(default)]
+    link: Option<String>,
+    #[serde(default)]
+    image: Option<String>,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+/// Kind of a panel, sent as "kind".
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PanelKind {
+    /// "default": image, title, link and description entered by the broadcaster.
+    Default,
+    /// "extension": panel of a Twitch extension.
+    Extension,
+    /// Any other kind as sent by Twitch.
+    Other(String),
+}
+
+
+impl Panel {
+    /// Example value: 42153012
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+    /// Example value: 0
+    ///
+    /// Position of the panel, starting at 0 for the leftmost panel.
+    pub fn display_order(&self) -> u32 {
+        self.display_order
+    }
+    /// Example value: PanelKind::Default
+    pub fn kind(&self) -> &PanelKind {
+        &self.kind
+    }
+    /// Example value: "<p>Check out my <a href=\"https://example.com\">website</a></p>"
+    ///
+    /// Description rendered as HTML.
+    pub fn html_description(&self) -> &Option<String> {
+        &self.html_description
+    }
+    /// Example value: 12345
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+    /// Example value: See `PanelData` type.
+    pub fn data(&self) -> &PanelData {
+        &self.data
+    }
+    /// Example value: "test_channel"
+    pub fn channel(&self) -> &String {
+        &self.channel
+    }
+}
+
+impl PanelData {
+    /// Example value: "https://example.com"
+    pub fn link(&self) -> &Option<String> {
+        &self.link
+    }
+    /// Example value: "https://panels-images.twitch.tv/panel-12345-image-8f7b1ce4-ee11-4f5c-a6e2-0d3a4f7c2c38"
+    pub fn image(&self) -> &Option<String> {
+        &self.image
+    }
+    /// Example value: "Website"
+    pub fn title(&self) -> &Option<String> {
+        &self.title
+    }
+    /// Example value: "Check out my [website](https://example.com)"
+    ///
+    /// Description in markdown.
+    pub fn description(&self) -> &Option<String> {
+        &self.description
+    }
+}
+
+impl PanelKind {
+    /// Returns the kind as sent by Twitch (e.g.: "default").
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PanelKind::Default => "default",
+            PanelKind::Extension => "extension",
+            PanelKind::Other(ref kind) => kind,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PanelKind {
+    fn from(kind: &'a str) -> PanelKind {
+        match kind {
+            "default" => PanelKind::Default,
+            "extension" => PanelKind::Extension,
+            _ => PanelKind::Other(kind.to_owned()),
+        }
+    }
+}
+
+impl Serialize for PanelKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for PanelKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<PanelKind, D::Error> {
+        let kind = try!(String::deserialize(deserializer));
+        Ok(PanelKind::from(kind.as_str()))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+
+    #[test]
+    fn test_should_deserialize_panels() {
+        let json = r#"[
+            {
+                "_id": 42153012,
+                "display_order": 0,
+                "kind": "default",
+                "html_description": "<p>Website</p>",
+                "user_id": 12345,
+                "data": {"link": "https://example.com", "title": "Website"},
+                "channel": "test_channel"
+            },
+            {
+                "_id": 42153013,
+                "display_order": 1,
+                "kind": "extension",
+                "html_description": null,
+                "user_id": 12345,
+                "data": {},
+                "channel": "test_channel"
+            }
+        ]"#;
+        let panels: Vec<Panel> = serde_json::from_str(json).unwrap();
+        assert_eq!(panels[0].kind(), &PanelKind::Default);
+        assert_eq!(panels[0].data().title(), &Some("Website".to_owned()));
+        assert_eq!(panels[1].kind(), &PanelKind::Extension);
+        assert_eq!(panels[1].data().link(), &None);
+    }
+}
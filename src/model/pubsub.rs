@@ -0,0 +1,449 @@
+//! Twitch PubSub messages.
+//!
+//! Events that are pushed by Twitch PubSub for the topics a client listens to (see `pubsub`).
+
+pub use model::DateTime;
+use model::deserialize_date_time;
+
+
+/// Redemption of a channel points reward.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": "9203c6f0-51b6-4d1d-a9ae-8eafdb0d6d47",
+///   "user": {
+///     // See `PubSubUser` type
+///   },
+///   "channel_id": "30515034",
+///   "redeemed_at": "2019-12-11T18:52:53.128421623Z",
+///   "reward": {
+///     // See `Reward` type
+///   },
+///   "user_input": "yeooo",
+///   "status": "UNFULFILLED"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ChannelPointsRedemption {
+    id: String,
+    user: PubSubUser,
+    channel_id: String,
+    #[serde(deserialize_with="deserialize_date_time")]
+    redeemed_at: DateTime,
+    reward: Reward,
+    user_input: Option<String>,
+    status: String,
+}
+
+/// User that triggered a PubSub event.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": "30515034",
+///   "login": "test_user1",
+///   "display_name": "test_user1"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct PubSubUser {
+    id: String,
+    login: String,
+    display_name: String,
+}
+
+/// Channel points reward.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": "6ef17bb2-e5ae-432e-8b3f-5ac4dd774668",
+///   "channel_id": "30515034",
+///   "title": "hit a gleesh walk on stream",
+///   "prompt": "cleanside's finest \n",
+///   "cost": 10
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Reward {
+    id: String,
+    channel_id: String,
+    title: String,
+    #[serde(default)]
+    prompt: String,
+    cost: u32,
+}
+
+/// Bits that were cheered in a channel.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "user_name": "test_user1",
+///   "channel_name": "test_channel",
+///   "user_id": "129454141",
+///   "channel_id": "44322889",
+///   "time": "2017-02-09T13:23:58.168Z",
+///   "chat_message": "cheer10000 New badge hype!",
+///   "bits_used": 10000,
+///   "total_bits_used": 25000,
+///   "context": "cheer"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct BitsEvent {
+    user_name: Option<String>,
+    channel_name: String,
+    user_id: Option<String>,
+    channel_id: String,
+    #[serde(deserialize_with="deserialize_date_time")]
+    time: DateTime,
+    #[serde(default)]
+    chat_message: String,
+    bits_used: u32,
+    total_bits_used: u32,
+    context: String,
+}
+
+/// Subscription, resubscription or gifted subscription to a channel.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "user_name": "test_user1",
+///   "display_name": "test_user1",
+///   "channel_name": "test_channel",
+///   "user_id": "44322889",
+///   "channel_id": "12826",
+///   "time": "2015-12-19T16:39:57-08:00",
+///   "sub_plan": "Prime",
+///   "sub_plan_name": "Channel Subscription (test_channel)",
+///   "cumulative_months": 9,
+///   "streak_months": 3,
+///   "context": "resub",
+///   "is_gift": false,
+///   "sub_message": {
+///     "message": "A Twitch baby is born! KappaHD"
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct SubscriptionEvent {
+    user_name: Option<String>,
+    display_name: Option<String>,
+    channel_name: String,
+    user_id: Option<String>,
+    channel_id: String,
+    #[serde(deserialize_with="deserialize_date_time")]
+    time: DateTime,
+    sub_plan: String,
+    sub_plan_name: String,
+    cumulative_months: Option<u32>,
+    streak_months: Option<u32>,
+    context: String,
+    #[serde(default)]
+    is_gift: bool,
+    recipient_user_name: Option<String>,
+    sub_message: Option<SubscriptionMessage>,
+}
+
+/// Message that was shared with a subscription.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "message": "A Twitch baby is born! KappaHD"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct SubscriptionMessage {
+    message: String,
+}
+
+/// Whisper that was received by a user.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "id": 41,
+///   "thread_id": "129454141_44322889",
+///   "body": "hello",
+///   "sent_ts": 1479160009,
+///   "from_id": 44322889,
+///   "tags": {
+///     // See `WhisperTags` type
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Whisper {
+    id: u64,
+    thread_id: String,
+    body: String,
+    sent_ts: u64,
+    from_id: u64,
+    tags: WhisperTags,
+}
+
+/// Sender information of a whisper.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "login": "test_user1",
+///   "display_name": "test_user1",
+///   "color": "#8A2BE2"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct WhisperTags {
+    login: String,
+    display_name: String,
+    color: Option<String>,
+}
+
+
+impl ChannelPointsRedemption {
+    /// Example value: "9203c6f0-51b6-4d1d-a9ae-8eafdb0d6d47"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: See `PubSubUser` type.
+    pub fn user(&self) -> &PubSubUser {
+        &self.user
+    }
+    /// Example value: "30515034"
+    pub fn channel_id(&self) -> &String {
+        &self.channel_id
+    }
+    /// Example value: "2019-12-11T18:52:53.128421623Z"
+    pub fn redeemed_at(&self) -> &DateTime {
+        &self.redeemed_at
+    }
+    /// Example value: See `Reward` type.
+    pub fn reward(&self) -> &Reward {
+        &self.reward
+    }
+    /// Example value: "yeooo"
+    pub fn user_input(&self) -> &Option<String> {
+        &self.user_input
+    }
+    /// Example value: "UNFULFILLED"
+    pub fn status(&self) -> &String {
+        &self.status
+    }
+}
+
+impl PubSubUser {
+    /// Example value: "30515034"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "test_user1"
+    pub fn login(&self) -> &String {
+        &self.login
+    }
+    /// Example value: "test_user1"
+    pub fn display_name(&self) -> &String {
+        &self.display_name
+    }
+}
+
+impl Reward {
+    /// Example value: "6ef17bb2-e5ae-432e-8b3f-5ac4dd774668"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "30515034"
+    pub fn channel_id(&self) -> &String {
+        &self.channel_id
+    }
+    /// Example value: "hit a gleesh walk on stream"
+    pub fn title(&self) -> &String {
+        &self.title
+    }
+    /// Example value: "cleanside's finest \n"
+    pub fn prompt(&self) -> &String {
+        &self.prompt
+    }
+    /// Example value: 10
+    pub fn cost(&self) -> u32 {
+        self.cost
+    }
+}
+
+impl BitsEvent {
+    /// Example value: "test_user1"
+    ///
+    /// `None` for anonymous cheers.
+    pub fn user_name(&self) -> &Option<String> {
+        &self.user_name
+    }
+    /// Example value: "test_channel"
+    pub fn channel_name(&self) -> &String {
+        &self.channel_name
+    }
+    /// Example value: "129454141"
+    ///
+    /// `None` for anonymous cheers.
+    pub fn user_id(&self) -> &Option<String> {
+        &self.user_id
+    }
+    /// Example value: "44322889"
+    pub fn channel_id(&self) -> &String {
+        &self.channel_id
+    }
+    /// Example value: "2017-02-09T13:23:58.168Z"
+    pub fn time(&self) -> &DateTime {
+        &self.time
+    }
+    /// Example value: "cheer10000 New badge hype!"
+    pub fn chat_message(&self) -> &String {
+        &self.chat_message
+    }
+    /// Example value: 10000
+    pub fn bits_used(&self) -> u32 {
+        self.bits_used
+    }
+    /// Example value: 25000
+    pub fn total_bits_used(&self) -> u32 {
+        self.total_bits_used
+    }
+    /// Example value: "cheer"
+    pub fn context(&self) -> &String {
+        &self.context
+    }
+}
+
+impl SubscriptionEvent {
+    /// Example value: "test_user1"
+    pub fn user_name(&self) -> &Option<String> {
+        &self.user_name
+    }
+    /// Example value: "test_user1"
+    pub fn display_name(&self) -> &Option<String> {
+        &self.display_name
+    }
+    /// Example value: "test_channel"
+    pub fn channel_name(&self) -> &String {
+        &self.channel_name
+    }
+    /// Example value: "44322889"
+    pub fn user_id(&self) -> &Option<String> {
+        &self.user_id
+    }
+    /// Example value: "12826"
+    pub fn channel_id(&self) -> &String {
+        &self.channel_id
+    }
+    /// Example value: "2015-12-19T16:39:57-08:00"
+    pub fn time(&self) -> &DateTime {
+        &self.time
+    }
+    /// Example value: "Prime"
+    ///
+    /// Possible values: "Prime", "1000", "2000", "3000"
+    pub fn sub_plan(&self) -> &String {
+        &self.sub_plan
+    }
+    /// Example value: "Channel Subscription (test_channel)"
+    pub fn sub_plan_name(&self) -> &String {
+        &self.sub_plan_name
+    }
+    /// Example value: 9
+    pub fn cumulative_months(&self) -> Option<u32> {
+        self.cumulative_months
+    }
+    /// Example value: 3
+    pub fn streak_months(&self) -> Option<u32> {
+        self.streak_months
+    }
+    /// Example value: "resub"
+    ///
+    /// Possible values: "sub", "resub", "subgift", "anonsubgift"
+    pub fn context(&self) -> &String {
+        &self.context
+    }
+    /// Example value: false
+    pub fn is_gift(&self) -> bool {
+        self.is_gift
+    }
+    /// Example value: "test_user2"
+    ///
+    /// Only present for gifted subscriptions.
+    pub fn recipient_user_name(&self) -> &Option<String> {
+        &self.recipient_user_name
+    }
+    /// Example value: See `SubscriptionMessage` type.
+    pub fn sub_message(&self) -> &Option<SubscriptionMessage> {
+        &self.sub_message
+    }
+}
+
+impl SubscriptionMessage {
+    /// Example value: "A Twitch baby is born! KappaHD"
+    pub fn message(&self) -> &String {
+        &self.message
+    }
+}
+
+impl Whisper {
+    /// Example value: 41
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+    /// Example value: "129454141_44322889"
+    pub fn thread_id(&self) -> &String {
+        &self.thread_id
+    }
+    /// Example value: "hello"
+    pub fn body(&self) -> &String {
+        &self.body
+    }
+    /// Example value: 1479160009
+    ///
+    /// Unix epoch timestamp in seconds when the whisper was sent.
+    pub fn sent_ts(&self) -> u64 {
+        self.sent_ts
+    }
+    /// Example value: 44322889
+    pub fn from_id(&self) -> u64 {
+        self.from_id
+    }
+    /// Example value: See `WhisperTags` type.
+    pub fn tags(&self) -> &WhisperTags {
+        &self.tags
+    }
+}
+
+impl WhisperTags {
+    /// Example value: "test_user1"
+    pub fn login(&self) -> &String {
+        &self.login
+    }
+    /// Example value: "test_user1"
+    pub fn display_name(&self) -> &String {
+        &self.display_name
+    }
+    /// Example value: "#8A2BE2"
+    pub fn color(&self) -> &Option<String> {
+        &self.color
+    }
+}
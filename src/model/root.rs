@@ -1,6 +1,11 @@
 //! Top level Twitch models and authorization status.
 
-pub use model::DateString;
+pub use model::DateTime;
+use model::deserialize_date_time;
+pub use model::scope::Scope;
+use model::scope::deserialize_known_scopes;
+pub use model::{Links, TwitchLinks, Url};
+use model::deserialize_links;
 
 /// Basic information about the API and authentication status.
 ///
@@ -24,9 +29,12 @@ pub use model::DateString;
 ///   }
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct BasicInfo {
     token: Token,
+    #[serde(rename="_links", default, deserialize_with="deserialize_links")]
+    links: Links,
 }
 
 /// Authentication token.
@@ -42,7 +50,8 @@ pub struct BasicInfo {
 ///   "valid": true
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct Token {
     valid: bool,
     user_name: Option<String>,
@@ -60,11 +69,15 @@ pub struct Token {
 ///   "updated_at": "2012-05-17T21:32:13Z"
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct Authorization {
-    scopes: Vec<String>,
-    created_at: DateString,
-    updated_at: DateString,
+    #[serde(deserialize_with="deserialize_known_scopes")]
+    scopes: Vec<Scope>,
+    #[serde(deserialize_with="deserialize_date_time")]
+    created_at: DateTime,
+    #[serde(deserialize_with="deserialize_date_time")]
+    updated_at: DateTime,
 }
 
 
@@ -73,6 +86,66 @@ impl BasicInfo {
     pub fn token(&self) -> &Token {
         &self.token
     }
+    /// Example value: "https://api.twitch.tv/kraken/channel"
+    ///
+    /// Channel of the authenticated user. Shorthand for `link("channel")`.
+    pub fn link_channel(&self) -> Option<&Url> {
+        self.link("channel")
+    }
+    /// Example value: "https://api.twitch.tv/kraken/user"
+    ///
+    /// Authenticated user. Shorthand for `link("user")`.
+    pub fn link_user(&self) -> Option<&Url> {
+        self.link("user")
+    }
+    /// Example value: "https://api.twitch.tv/kraken/users/test_user1"
+    ///
+    /// Public user object of the authenticated user. Shorthand for `link("users")`.
+    pub fn link_users(&self) -> Option<&Url> {
+        self.link("users")
+    }
+    /// Example value: "https://api.twitch.tv/kraken/channels/test_user1"
+    ///
+    /// Public channel object of the authenticated user. Shorthand for `link("channels")`.
+    pub fn link_channels(&self) -> Option<&Url> {
+        self.link("channels")
+    }
+    /// Example value: "https://api.twitch.tv/kraken/chat/test_user1"
+    ///
+    /// Chat resources of the channel of the authenticated user. Shorthand for `link("chat")`.
+    pub fn link_chat(&self) -> Option<&Url> {
+        self.link("chat")
+    }
+    /// Example value: "https://api.twitch.tv/kraken/streams"
+    ///
+    /// Live streams. Shorthand for `link("streams")`.
+    pub fn link_streams(&self) -> Option<&Url> {
+        self.link("streams")
+    }
+    /// Example value: "https://api.twitch.tv/kraken/ingests"
+    ///
+    /// Ingest servers. Shorthand for `link("ingests")`.
+    pub fn link_ingests(&self) -> Option<&Url> {
+        self.link("ingests")
+    }
+    /// Example value: "https://api.twitch.tv/kraken/teams"
+    ///
+    /// Teams. Shorthand for `link("teams")`.
+    pub fn link_teams(&self) -> Option<&Url> {
+        self.link("teams")
+    }
+    /// Example value: "https://api.twitch.tv/kraken/search"
+    ///
+    /// Search of channels, streams and games. Shorthand for `link("search")`.
+    pub fn link_search(&self) -> Option<&Url> {
+        self.link("search")
+    }
+}
+
+impl TwitchLinks for BasicInfo {
+    fn links(&self) -> &Links {
+        &self.links
+    }
 }
 
 impl Token {
@@ -88,19 +161,47 @@ impl Token {
     pub fn authorization(&self) -> &Option<Authorization> {
         &self.authorization
     }
+    /// Returns whether the token is authorized for the scope.
+    ///
+    /// Can be used to check the permissions before calling endpoints that require a scope.
+    pub fn has_scope(&self, scope: Scope) -> bool {
+        match self.authorization {
+            Some(ref authorization) => authorization.scopes.contains(&scope),
+            None => false,
+        }
+    }
 }
 
 impl Authorization {
-    /// Example values: ["user_read", "channel_read", "channel_commercial", "user_read"]
-    pub fn scopes(&self) -> &Vec<String> {
+    /// Example values: [`Scope::UserRead`, `Scope::ChannelRead`, `Scope::ChannelCommercial`, `Scope::UserRead`]
+    ///
+    /// Scopes that are not known by `Scope` are skipped.
+    pub fn scopes(&self) -> &Vec<Scope> {
         &self.scopes
     }
     /// Example value: "2012-05-08T21:55:12Z"
-    pub fn created_at(&self) -> &DateString {
+    pub fn created_at(&self) -> &DateTime {
         &self.created_at
     }
     /// Example value: "2012-05-17T21:32:13Z"
-    pub fn updated_at(&self) -> &DateString {
+    pub fn updated_at(&self) -> &DateTime {
         &self.updated_at
     }
 }
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+
+    #[test]
+    fn test_link_accessors_should_read_links() {
+        let basic_info: BasicInfo = serde_json::from_str(include_str!("../testing/fixtures/basic_info.json")).unwrap();
+        assert_eq!(basic_info.link_user().map(|url| url.to_string()),
+                   Some("https://api.twitch.tv/kraken/user".to_owned()));
+        assert_eq!(basic_info.link_streams().map(|url| url.to_string()),
+                   Some("https://api.twitch.tv/kraken/streams".to_owned()));
+        assert_eq!(basic_info.link_search(), None);
+    }
+}
@@ -0,0 +1,199 @@
+//! Twitch OAuth scopes.
+//!
+//! Scopes define the permissions that are granted to an OAuth token.
+
+use std::fmt;
+use std::error::Error as StdError;
+use std::str::FromStr;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::Error as DeError;
+
+
+/// Documented [Twitch OAuth scopes].
+///
+/// Formatted (`Display`) and parsed (`FromStr`) as the scope names used by Twitch (e.g. "user_read").
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::model::scope::Scope;
+///
+/// assert_eq!("channel_read".parse::<Scope>(), Ok(Scope::ChannelRead));
+/// assert_eq!(Scope::ClipsEdit.to_string(), "clips:edit");
+/// ```
+///
+/// [Twitch OAuth scopes]: https://dev.twitch.tv/docs/authentication/#scopes
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[non_exhaustive]
+pub enum Scope {
+    /// "user_read": Read access to non-public user information, such as email address.
+    UserRead,
+    /// "user_blocks_edit": Ability to ignore or unignore on behalf of a user.
+    UserBlocksEdit,
+    /// "user_blocks_read": Read access to a user's list of ignored users.
+    UserBlocksRead,
+    /// "user_follows_edit": Access to manage a user's followed channels.
+    UserFollowsEdit,
+    /// "user_subscriptions": Read access to subscriptions of a user.
+    UserSubscriptions,
+    /// "channel_read": Read access to non-public channel information, including email address and stream key.
+    ChannelRead,
+    /// "channel_editor": Write access to channel metadata (game, status, etc).
+    ChannelEditor,
+    /// "channel_commercial": Access to trigger commercials on channel.
+    ChannelCommercial,
+    /// "channel_stream": Ability to reset a channel's stream key.
+    ChannelStream,
+    /// "channel_subscriptions": Read access to all subscribers to your channel.
+    ChannelSubscriptions,
+    /// "channel_check_subscription": Read access to check if a user is subscribed to your channel.
+    ChannelCheckSubscription,
+    /// "channel_feed_read": Ability to view to a channel feed.
+    ChannelFeedRead,
+    /// "channel_feed_edit": Ability to add posts and reactions to a channel feed.
+    ChannelFeedEdit,
+    /// "chat_login": Ability to log into chat and send messages.
+    ChatLogin,
+    /// "collections_edit": Manage a user's collections (of videos).
+    CollectionsEdit,
+    /// "communities_edit": Manage a user's communities.
+    CommunitiesEdit,
+    /// "communities_moderate": Manage community moderators.
+    CommunitiesModerate,
+    /// "viewing_activity_read": Turn on Viewer Heartbeat Service ability to record user data.
+    ViewingActivityRead,
+    /// "openid": Use OpenID Connect authentication.
+    OpenId,
+    /// "analytics:read:extensions": View analytics data for your extensions.
+    AnalyticsReadExtensions,
+    /// "analytics:read:games": View analytics data for your games.
+    AnalyticsReadGames,
+    /// "bits:read": View bits information for your channel.
+    BitsRead,
+    /// "channel:read:subscriptions": Get a list of all subscribers to your channel.
+    ChannelReadSubscriptions,
+    /// "clips:edit": Manage a clip object.
+    ClipsEdit,
+    /// "user:edit": Manage a user object.
+    UserEdit,
+    /// "user:edit:broadcast": Edit your channel's broadcast configuration, including stream markers and tags.
+    UserEditBroadcast,
+    /// "user:read:broadcast": View your broadcasting configuration, including stream markers.
+    UserReadBroadcast,
+    /// "user:read:email": Read authorized user's email address.
+    UserReadEmail,
+}
+
+const SCOPES: [(Scope, &'static str); 28] = [
+    (Scope::UserRead, "user_read"),
+    (Scope::UserBlocksEdit, "user_blocks_edit"),
+    (Scope::UserBlocksRead, "user_blocks_read"),
+    (Scope::UserFollowsEdit, "user_follows_edit"),
+    (Scope::UserSubscriptions, "user_subscriptions"),
+    (Scope::ChannelRead, "channel_read"),
+    (Scope::ChannelEditor, "channel_editor"),
+    (Scope::ChannelCommercial, "channel_commercial"),
+    (Scope::ChannelStream, "channel_stream"),
+    (Scope::ChannelSubscriptions, "channel_subscriptions"),
+    (Scope::ChannelCheckSubscription, "channel_check_subscription"),
+    (Scope::ChannelFeedRead, "channel_feed_read"),
+    (Scope::ChannelFeedEdit, "channel_feed_edit"),
+    (Scope::ChatLogin, "chat_login"),
+    (Scope::CollectionsEdit, "collections_edit"),
+    (Scope::CommunitiesEdit, "communities_edit"),
+    (Scope::CommunitiesModerate, "communities_moderate"),
+    (Scope::ViewingActivityRead, "viewing_activity_read"),
+    (Scope::OpenId, "openid"),
+    (Scope::AnalyticsReadExtensions, "analytics:read:extensions"),
+    (Scope::AnalyticsReadGames, "analytics:read:games"),
+    (Scope::BitsRead, "bits:read"),
+    (Scope::ChannelReadSubscriptions, "channel:read:subscriptions"),
+    (Scope::ClipsEdit, "clips:edit"),
+    (Scope::UserEdit, "user:edit"),
+    (Scope::UserEditBroadcast, "user:edit:broadcast"),
+    (Scope::UserReadBroadcast, "user:read:broadcast"),
+    (Scope::UserReadEmail, "user:read:email"),
+];
+
+impl Scope {
+    /// Returns the scope name used by Twitch (e.g. "user_read").
+    pub fn as_str(&self) -> &'static str {
+        SCOPES.iter()
+                .find(|&&(scope, _)| scope == *self)
+                .map(|&(_, name)| name)
+                .unwrap()
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Scope {
+    type Err = ParseScopeError;
+
+    fn from_str(s: &str) -> Result<Scope, ParseScopeError> {
+        SCOPES.iter()
+                .find(|&&(_, name)| name == s)
+                .map(|&(scope, _)| scope)
+                .ok_or_else(|| ParseScopeError(s.to_owned()))
+    }
+}
+
+impl Serialize for Scope {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Scope {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Scope, D::Error> {
+        let name = try!(String::deserialize(deserializer));
+        name.parse().map_err(D::Error::custom)
+    }
+}
+
+/// Deserializes a list of scopes, skipping the scopes that are not known (yet),
+/// so that newly introduced Twitch scopes do not break the deserialization of the whole response.
+pub(crate) fn deserialize_known_scopes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Scope>, D::Error> {
+    let names: Vec<String> = try!(Vec::deserialize(deserializer));
+    Ok(names.iter().filter_map(|name| name.parse().ok()).collect())
+}
+
+
+/// Error when parsing an unknown scope name.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct ParseScopeError(String);
+
+impl fmt::Display for ParseScopeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.description(), self.0)
+    }
+}
+
+impl StdError for ParseScopeError {
+    fn description(&self) -> &str {
+        "Unknown Twitch scope"
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scopes_should_round_trip() {
+        for &(scope, name) in SCOPES.iter() {
+            assert_eq!(scope.to_string(), name);
+            assert_eq!(name.parse::<Scope>(), Ok(scope));
+        }
+    }
+
+    #[test]
+    fn test_unknown_scope_should_fail_to_parse() {
+        assert_eq!("unknown_scope".parse::<Scope>(), Err(ParseScopeError("unknown_scope".to_owned())));
+    }
+}
@@ -3,10 +3,22 @@
 //! Streams are video broadcasts that are currently live.
 //! They have a broadcaster and are part of a channel.
 
-pub use model::DateString;
-pub use model::UrlString;
-pub use model::image::ImageLinks;
+pub use model::DateTime;
+use model::deserialize_date_time;
+use model::de::{empty_string_as_none, number_or_string, optional_number_or_string};
+#[cfg(feature = "chrono")]
+use model::duration_between;
+#[cfg(feature = "chrono")]
+use std::time::Duration;
+pub use model::Url;
+pub use model::{Links, TwitchLinks};
+use model::deserialize_links;
+pub use model::image::{ImageLinks, PreviewQuality};
 pub use model::channel::Channel;
+pub use model::id::StreamId;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "model-builders")]
+use model::unix_epoch;
 
 
 /// Streams that are queried by a number of parameters sorted by number of viewers descending.
@@ -30,11 +42,14 @@ pub use model::channel::Channel;
 ///   }
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Streams {
     #[serde(rename="_total")]
     total: u32,
     streams: Vec<Stream>,
+    #[serde(rename="_links", default, deserialize_with="deserialize_links")]
+    links: Links,
 }
 
 /// Featured (promoted) streams.
@@ -54,9 +69,12 @@ pub struct Streams {
 ///   ]
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct FeaturedStreams {
     featured: Vec<FeaturedStream>,
+    #[serde(rename="_links", default, deserialize_with="deserialize_links")]
+    links: Links,
 }
 
 /// Twitch stream information of a specific channel.
@@ -86,9 +104,12 @@ pub struct FeaturedStreams {
 ///   }
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct ChannelStream {
     stream: Option<Stream>,
+    #[serde(rename="_links", default, deserialize_with="deserialize_links")]
+    links: Links,
 }
 
 /// Summary of current streams.
@@ -104,7 +125,8 @@ pub struct ChannelStream {
 ///   }
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct StreamsSummary {
     viewers: u32,
     channels: u32,
@@ -126,13 +148,17 @@ pub struct StreamsSummary {
 ///   }
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct FeaturedStream {
     text: String,
-    image: UrlString,
+    image: Url,
     title: String,
+    #[serde(default)]
     sponsored: bool,
+    #[serde(default)]
     priority: u8,
+    #[serde(default)]
     scheduled: bool,
     stream: Stream,
 }
@@ -164,21 +190,70 @@ pub struct FeaturedStream {
 ///   }
 /// }
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Stream {
     #[serde(rename="_id")]
-    id: u64,
+    id: StreamId,
+    #[serde(default, deserialize_with="empty_string_as_none")]
     game: Option<String>,
+    #[serde(deserialize_with="number_or_string")]
     viewers: u32,
+    #[serde(default, deserialize_with="number_or_string")]
     average_fps: f64,
+    #[serde(default, deserialize_with="optional_number_or_string")]
     delay: Option<u32>,
+    #[serde(default, deserialize_with="number_or_string")]
     video_height: u16,
+    #[serde(default)]
     is_playlist: bool,
-    created_at: DateString,
+    #[serde(default)]
+    stream_type: Option<StreamType>,
+    #[serde(deserialize_with="deserialize_date_time")]
+    created_at: DateTime,
     channel: Channel,
     preview: ImageLinks,
 }
 
+/// Builder of `Stream` values that were not sent by Twitch (e.g. in tests of downstream crates).
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::model::channel::ChannelBuilder;
+/// use twitch_client::model::stream::StreamBuilder;
+///
+/// let channel = ChannelBuilder::new(12345, "test_channel").build();
+/// let stream = StreamBuilder::new(4989654544, channel)
+///         .with_game("StarCraft II: Heart of the Swarm")
+///         .with_viewers(2123)
+///         .build();
+/// assert_eq!(stream.viewers(), 2123);
+/// ```
+#[cfg(feature = "model-builders")]
+#[derive(Debug, Clone)]
+pub struct StreamBuilder {
+    stream: Stream,
+}
+
+/// Type of a stream, sent as "stream_type" by Twitch API version 5.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum StreamType {
+    /// "live"
+    Live,
+    /// "playlist"
+    Playlist,
+    /// "premiere"
+    Premiere,
+    /// "rerun"
+    Rerun,
+    /// "watch_party"
+    WatchParty,
+    /// Any other type as sent by Twitch.
+    Other(String),
+}
+
 
 impl Streams {
     /// Example value: 12345
@@ -189,6 +264,26 @@ impl Streams {
     pub fn streams(&self) -> &Vec<Stream> {
         &self.streams
     }
+
+    /// Merges the streams of multiple requests into one `Streams` without `_links`.
+    pub(crate) fn merge(pages: Vec<Streams>) -> Streams {
+        let mut merged = Streams {
+            total: 0,
+            streams: Vec::new(),
+            links: Links::new(),
+        };
+        for page in pages {
+            merged.total += page.total;
+            merged.streams.extend(page.streams);
+        }
+        merged
+    }
+}
+
+impl TwitchLinks for Streams {
+    fn links(&self) -> &Links {
+        &self.links
+    }
 }
 
 impl FeaturedStreams {
@@ -198,6 +293,12 @@ impl FeaturedStreams {
     }
 }
 
+impl TwitchLinks for FeaturedStreams {
+    fn links(&self) -> &Links {
+        &self.links
+    }
+}
+
 impl ChannelStream {
     /// Example value: See `Stream` type.
     pub fn stream(&self) -> &Option<Stream> {
@@ -205,6 +306,12 @@ impl ChannelStream {
     }
 }
 
+impl TwitchLinks for ChannelStream {
+    fn links(&self) -> &Links {
+        &self.links
+    }
+}
+
 impl StreamsSummary {
     /// Example value: 194774
     pub fn viewers(&self) -> u32 {
@@ -222,7 +329,7 @@ impl FeaturedStream {
         &self.text
     }
     /// Example value: "http://s.jtvnw.net/jtv_user_pictures/hosted_images/TwitchPartnerSpotlight.png"
-    pub fn image(&self) -> &UrlString {
+    pub fn image(&self) -> &Url {
         &self.image
     }
     /// Example value: "Twitch Partner Spotlight"
@@ -249,7 +356,7 @@ impl FeaturedStream {
 
 impl Stream {
     /// Example value: 4989654544
-    pub fn id(&self) -> u64 {
+    pub fn id(&self) -> StreamId {
         self.id
     }
     /// Example value: "StarCraft II: Heart of the Swarm"
@@ -276,10 +383,27 @@ impl Stream {
     pub fn is_playlist(&self) -> bool {
         self.is_playlist
     }
+    /// Type of the stream, derived from `is_playlist` if Twitch did not send it (API version 3).
+    ///
+    /// Example value: "live"
+    pub fn stream_type(&self) -> StreamType {
+        match self.stream_type {
+            Some(ref stream_type) => stream_type.clone(),
+            None if self.is_playlist => StreamType::Playlist,
+            None => StreamType::Live,
+        }
+    }
     /// Example value: "2015-02-12T04:42:31Z"
-    pub fn created_at(&self) -> &DateString {
+    pub fn created_at(&self) -> &DateTime {
         &self.created_at
     }
+    /// Returns how long the stream is live at `now` (e.g. `Utc::now()`), computed from `created_at`.
+    ///
+    /// Use `model::format_duration` to answer "!uptime" in chat.
+    #[cfg(feature = "chrono")]
+    pub fn uptime(&self, now: DateTime) -> Duration {
+        duration_between(&self.created_at, &now)
+    }
     /// Example value: See `Channel` type.
     pub fn channel(&self) -> &Channel {
         &self.channel
@@ -288,4 +412,153 @@ impl Stream {
     pub fn preview(&self) -> &ImageLinks {
         &self.preview
     }
+    /// Returns the url of the preview with the quality.
+    ///
+    /// Example value: "http://static-cdn.jtvnw.net/previews-ttv/live_user_test_channel-320x180.jpg"
+    pub fn preview_url(&self, quality: PreviewQuality) -> String {
+        self.preview.url(quality)
+    }
+}
+
+impl StreamType {
+    /// Returns the type as sent by Twitch (e.g.: "live").
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamType::Live => "live",
+            StreamType::Playlist => "playlist",
+            StreamType::Premiere => "premiere",
+            StreamType::Rerun => "rerun",
+            StreamType::WatchParty => "watch_party",
+            StreamType::Other(ref stream_type) => stream_type,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StreamType {
+    fn from(stream_type: &'a str) -> StreamType {
+        match stream_type {
+            "live" => StreamType::Live,
+            "playlist" => StreamType::Playlist,
+            "premiere" => StreamType::Premiere,
+            "rerun" => StreamType::Rerun,
+            "watch_party" => StreamType::WatchParty,
+            _ => StreamType::Other(stream_type.to_owned()),
+        }
+    }
+}
+
+impl Serialize for StreamType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for StreamType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<StreamType, D::Error> {
+        let stream_type = try!(String::deserialize(deserializer));
+        Ok(StreamType::from(stream_type.as_str()))
+    }
+}
+
+#[cfg(feature = "model-builders")]
+impl StreamBuilder {
+    /// Constructs a builder of a live stream of the channel without viewers,
+    /// with the game of the channel and preview links of the Twitch CDN.
+    pub fn new<I: Into<StreamId>>(id: I, channel: Channel) -> StreamBuilder {
+        let preview_template = format!("https://static-cdn.jtvnw.net/previews-ttv/live_user_{}-{{width}}x{{height}}.jpg",
+                channel.name());
+        StreamBuilder {
+            stream: Stream {
+                id: id.into(),
+                game: channel.game().clone(),
+                viewers: 0,
+                average_fps: 0.0,
+                delay: None,
+                video_height: 0,
+                is_playlist: false,
+                stream_type: Some(StreamType::Live),
+                created_at: unix_epoch(),
+                preview: ImageLinks::from_template(&preview_template, (80, 45), (320, 180), (640, 360)),
+                channel: channel,
+            },
+        }
+    }
+    /// Sets the game (name).
+    pub fn with_game(mut self, game: &str) -> StreamBuilder {
+        self.stream.game = Some(game.to_owned());
+        self
+    }
+    /// Sets the number of viewers.
+    pub fn with_viewers(mut self, viewers: u32) -> StreamBuilder {
+        self.stream.viewers = viewers;
+        self
+    }
+    /// Sets the average frames per second.
+    pub fn with_average_fps(mut self, average_fps: f64) -> StreamBuilder {
+        self.stream.average_fps = average_fps;
+        self
+    }
+    /// Sets the delay in seconds.
+    pub fn with_delay(mut self, delay: u32) -> StreamBuilder {
+        self.stream.delay = Some(delay);
+        self
+    }
+    /// Sets the video height in pixels.
+    pub fn with_video_height(mut self, video_height: u16) -> StreamBuilder {
+        self.stream.video_height = video_height;
+        self
+    }
+    /// Sets the type of the stream (and whether it is a playlist).
+    pub fn with_stream_type(mut self, stream_type: StreamType) -> StreamBuilder {
+        self.stream.is_playlist = stream_type == StreamType::Playlist;
+        self.stream.stream_type = Some(stream_type);
+        self
+    }
+    /// Sets the date when the stream started.
+    pub fn with_created_at(mut self, created_at: DateTime) -> StreamBuilder {
+        self.stream.created_at = created_at;
+        self
+    }
+    /// Builds the stream.
+    pub fn build(self) -> Stream {
+        self.stream
+    }
+}
+
+
+#[cfg(all(test, feature = "model-builders"))]
+mod tests {
+    use serde_json;
+    use model::channel::ChannelBuilder;
+    use super::*;
+
+    #[test]
+    fn test_built_stream_should_roundtrip_through_json() {
+        let channel = ChannelBuilder::new(12345, "test_channel").with_game("Gaming Talk Shows").build();
+        let stream = StreamBuilder::new(4989654544, channel)
+                .with_stream_type(StreamType::Playlist)
+                .build();
+        assert_eq!(stream.game(), &Some("Gaming Talk Shows".to_owned()));
+        assert!(stream.is_playlist());
+
+        let json = serde_json::to_string(&stream).unwrap();
+        let deserialized: Stream = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.id(), stream.id());
+        assert_eq!(deserialized.stream_type(), StreamType::Playlist);
+        assert_eq!(deserialized.preview().small().to_string(),
+                "https://static-cdn.jtvnw.net/previews-ttv/live_user_test_channel-80x45.jpg");
+    }
+
+    #[test]
+    fn test_should_deserialize_inconsistent_values() {
+        let stream: Stream = serde_json::from_str(
+            include_str!("../testing/fixtures/stream_inconsistent_nulls.json")).unwrap();
+        assert_eq!(stream.game(), &None);
+        assert_eq!(stream.viewers(), 2123);
+        assert!((stream.average_fps() - 29.9880749574).abs() < 1e-9);
+        assert_eq!(stream.delay(), None);
+        assert_eq!(stream.video_height(), 720);
+        assert_eq!(stream.channel().profile_banner_background_color().as_ref().map(|color| color.to_string()),
+                Some("#6441a5".to_owned()));
+    }
 }
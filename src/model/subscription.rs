@@ -0,0 +1,50 @@
+//! Twitch subscriptions.
+//!
+//! Subscriptions are paid memberships of users in channels of Twitch partners.
+
+pub use model::DateTime;
+use model::deserialize_date_time;
+pub use model::channel::Channel;
+
+
+/// Subscription of a user to a channel.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_id": "88d4621871b7274c34d5c2ed3b8b2e1c3f2b3b9e",
+///   "created_at": "2013-10-21T08:49:55Z",
+///   "_links": {
+///     "self": "https://api.twitch.tv/kraken/users/test_user1/subscriptions/test_channel"
+///   },
+///   "channel": {
+///     // See `Channel` type
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Subscription {
+    #[serde(rename="_id")]
+    id: String,
+    #[serde(deserialize_with="deserialize_date_time")]
+    created_at: DateTime,
+    channel: Channel,
+}
+
+
+impl Subscription {
+    /// Example value: "88d4621871b7274c34d5c2ed3b8b2e1c3f2b3b9e"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "2013-10-21T08:49:55Z"
+    pub fn created_at(&self) -> &DateTime {
+        &self.created_at
+    }
+    /// Example value: See `Channel` type.
+    pub fn channel(&self) -> &Channel {
+        &self.channel
+    }
+}
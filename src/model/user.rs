@@ -0,0 +1,115 @@
+//! Twitch users.
+//!
+//! Users are the accounts on Twitch.
+//! Every user has a channel with the same name.
+
+pub use model::Url;
+pub use model::id::UserId;
+pub use model::DateTime;
+use model::deserialize_date_time;
+
+
+/// User information.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "type": "user",
+///   "name": "test_user1",
+///   "created_at": "2011-06-03T17:49:19Z",
+///   "updated_at": "2012-06-18T17:19:57Z",
+///   "_links": {
+///     "self": "https://api.twitch.tv/kraken/users/test_user1"
+///   },
+///   "logo": "http://static-cdn.jtvnw.net/jtv_user_pictures/test_user1-profile_image-62e8318af864d6d7-300x300.jpeg",
+///   "_id": 22761313,
+///   "display_name": "test_user1",
+///   "bio": "test bio woo I'm a test user"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct User {
+    #[serde(rename="_id")]
+    id: UserId,
+    name: String,
+    display_name: String,
+    #[serde(rename="type")]
+    user_type: String,
+    bio: Option<String>,
+    logo: Option<Url>,
+    #[serde(deserialize_with="deserialize_date_time")]
+    created_at: DateTime,
+    #[serde(deserialize_with="deserialize_date_time")]
+    updated_at: DateTime,
+}
+
+/// Users that are looked up by their login names.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_total": 1,
+///   "users": [
+///     {
+///       // See `User` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Users {
+    #[serde(rename="_total")]
+    total: u32,
+    users: Vec<User>,
+}
+
+
+impl Users {
+    /// Example value: 1
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+    /// Example value: See `User` type.
+    pub fn users(&self) -> &Vec<User> {
+        &self.users
+    }
+}
+
+impl User {
+    /// Example value: 22761313
+    pub fn id(&self) -> UserId {
+        self.id
+    }
+    /// Example value: "test_user1"
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+    /// Example value: "test_user1"
+    pub fn display_name(&self) -> &String {
+        &self.display_name
+    }
+    /// Example value: "user"
+    pub fn user_type(&self) -> &String {
+        &self.user_type
+    }
+    /// Example value: "test bio woo I'm a test user"
+    pub fn bio(&self) -> &Option<String> {
+        &self.bio
+    }
+    /// Example value: "http://static-cdn.jtvnw.net/jtv_user_pictures/test_user1-profile_image-62e8318af864d6d7-300x300.jpeg"
+    pub fn logo(&self) -> &Option<Url> {
+        &self.logo
+    }
+    /// Example value: "2011-06-03T17:49:19Z"
+    pub fn created_at(&self) -> &DateTime {
+        &self.created_at
+    }
+    /// Example value: "2012-06-18T17:19:57Z"
+    pub fn updated_at(&self) -> &DateTime {
+        &self.updated_at
+    }
+}
@@ -0,0 +1,44 @@
+//! Twitch stream playlist access.
+//!
+//! Access tokens authorize fetching the HLS playlists of a live stream from usher (see `usher`).
+
+
+/// Signed access token for the playlists of a channel's live stream.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "token": "{\"channel\":\"test_channel\",\"expires\":1555950000,\"chansub\":{\"restricted_bitrates\":[]}}",
+///   "sig": "3b5f7a5a0d2bd4c3e46b8e4e8f4b2d1c9a1f3e27",
+///   "mobile_restricted": false
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct StreamAccessToken {
+    token: String,
+    sig: String,
+    #[serde(default)]
+    mobile_restricted: bool,
+}
+
+
+impl StreamAccessToken {
+    /// Serialized JSON of the token's claims.
+    ///
+    /// Example value: "{\"channel\":\"test_channel\",\"expires\":1555950000,\"chansub\":{\"restricted_bitrates\":[]}}"
+    pub fn token(&self) -> &String {
+        &self.token
+    }
+    /// Signature of the token.
+    ///
+    /// Example value: "3b5f7a5a0d2bd4c3e46b8e4e8f4b2d1c9a1f3e27"
+    pub fn sig(&self) -> &String {
+        &self.sig
+    }
+    /// Example value: false
+    pub fn mobile_restricted(&self) -> bool {
+        self.mobile_restricted
+    }
+}
@@ -0,0 +1,296 @@
+//! Twitch videos.
+//!
+//! Videos are broadcasts or highlights owned by a channel.
+//! Broadcasts are unedited videos that are saved after a streaming session.
+//! Highlights are videos edited from broadcasts by the channel's owner.
+
+pub use model::DateTime;
+use model::deserialize_date_time;
+pub use model::Url;
+pub use model::{Links, TwitchLinks};
+use model::deserialize_links;
+pub use model::image::PreviewQuality;
+use model::image::fill_template;
+
+
+/// Top videos by number of views.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_links": {
+///     "next": "https://api.twitch.tv/kraken/videos/top?game=Gaming+Talk+Shows&limit=10&offset=10&period=week",
+///     "self": "https://api.twitch.tv/kraken/videos/top?game=Gaming+Talk+Shows&limit=10&offset=0&period=week"
+///   },
+///   "videos": [
+///     {
+///       // See `Video` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Videos {
+    videos: Vec<Video>,
+    #[serde(rename="_links", default, deserialize_with="deserialize_links")]
+    links: Links,
+}
+
+/// Videos of a channel sorted by publication date, most recent first.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "_total": 42,
+///   "_links": {
+///     "next": "https://api.twitch.tv/kraken/channels/test_channel/videos?limit=10&offset=10",
+///     "self": "https://api.twitch.tv/kraken/channels/test_channel/videos?limit=10&offset=0"
+///   },
+///   "videos": [
+///     {
+///       // See `Video` type
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ChannelVideos {
+    #[serde(rename="_total")]
+    total: u32,
+    videos: Vec<Video>,
+    #[serde(rename="_links", default, deserialize_with="deserialize_links")]
+    links: Links,
+}
+
+/// Video information.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "title": "Twitch Weekly - February 6, 2015",
+///   "description": "Twitch Weekly LIVE on February 6, 2015!",
+///   "broadcast_id": 13019796368,
+///   "status": "recorded",
+///   "tag_list": "",
+///   "_id": "c6055863",
+///   "recorded_at": "2015-02-06T21:01:09Z",
+///   "game": null,
+///   "length": 4015,
+///   "preview": "http://static-cdn.jtvnw.net/jtv.thumbs/archive-621292653-320x240.jpg",
+///   "url": "http://www.twitch.tv/twitch/c/6055863",
+///   "views": 318,
+///   "broadcast_type": "highlight",
+///   "_links": {
+///     "self": "https://api.twitch.tv/kraken/videos/c6055863",
+///     "channel": "https://api.twitch.tv/kraken/channels/twitch"
+///   },
+///   "channel": {
+///     // See `VideoChannel` type
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Video {
+    #[serde(rename="_id")]
+    id: String,
+    title: String,
+    description: Option<String>,
+    broadcast_id: Option<u64>,
+    broadcast_type: String,
+    status: String,
+    tag_list: Option<String>,
+    #[serde(deserialize_with="deserialize_date_time")]
+    recorded_at: DateTime,
+    game: Option<String>,
+    length: u32,
+    preview: Url,
+    url: Url,
+    views: u32,
+    channel: VideoChannel,
+}
+
+/// Channel that owns a video.
+///
+/// # Example in JSON
+///
+/// ```json
+/// {
+///   "name": "twitch",
+///   "display_name": "Twitch"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct VideoChannel {
+    name: String,
+    display_name: String,
+}
+
+
+impl Videos {
+    /// Example value: See `Video` type.
+    pub fn videos(&self) -> &Vec<Video> {
+        &self.videos
+    }
+}
+
+impl TwitchLinks for Videos {
+    fn links(&self) -> &Links {
+        &self.links
+    }
+}
+
+impl ChannelVideos {
+    /// Example value: 42
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+    /// Example value: See `Video` type.
+    pub fn videos(&self) -> &Vec<Video> {
+        &self.videos
+    }
+}
+
+impl TwitchLinks for ChannelVideos {
+    fn links(&self) -> &Links {
+        &self.links
+    }
+}
+
+impl Video {
+    /// Example value: "c6055863"
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+    /// Example value: "Twitch Weekly - February 6, 2015"
+    pub fn title(&self) -> &String {
+        &self.title
+    }
+    /// Example value: "Twitch Weekly LIVE on February 6, 2015!"
+    pub fn description(&self) -> &Option<String> {
+        &self.description
+    }
+    /// Example value: 13019796368
+    pub fn broadcast_id(&self) -> Option<u64> {
+        self.broadcast_id
+    }
+    /// Example value: "highlight"
+    pub fn broadcast_type(&self) -> &String {
+        &self.broadcast_type
+    }
+    /// Example value: "recorded"
+    pub fn status(&self) -> &String {
+        &self.status
+    }
+    /// Example value: ""
+    pub fn tag_list(&self) -> &Option<String> {
+        &self.tag_list
+    }
+    /// Example value: "2015-02-06T21:01:09Z"
+    pub fn recorded_at(&self) -> &DateTime {
+        &self.recorded_at
+    }
+    /// Example value: `None`
+    pub fn game(&self) -> &Option<String> {
+        &self.game
+    }
+    /// Length of the video in seconds.
+    ///
+    /// Example value: 4015
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+    /// Example value: "http://static-cdn.jtvnw.net/jtv.thumbs/archive-621292653-320x240.jpg"
+    pub fn preview(&self) -> &Url {
+        &self.preview
+    }
+    /// Returns the url of the preview with the quality (80x45, 320x180 or 640x360 pixels or a custom size),
+    /// by replacing the size in the preview url.
+    ///
+    /// Returns the unchanged preview url if it does not contain a size.
+    ///
+    /// Example value: "http://static-cdn.jtvnw.net/jtv.thumbs/archive-621292653-640x360.jpg"
+    pub fn preview_url(&self, quality: PreviewQuality) -> String {
+        let preview = self.preview.to_string();
+        let template = match preview_template(&preview) {
+            Some(template) => template,
+            None => return preview,
+        };
+        let (width, height) = match quality {
+            PreviewQuality::Small => (80, 45),
+            PreviewQuality::Medium => (320, 180),
+            PreviewQuality::Large => (640, 360),
+            PreviewQuality::Custom { width, height } => (width, height),
+        };
+        fill_template(&template, width, height)
+    }
+    /// Example value: "http://www.twitch.tv/twitch/c/6055863"
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+    /// Example value: 318
+    pub fn views(&self) -> u32 {
+        self.views
+    }
+    /// Example value: See `VideoChannel` type.
+    pub fn channel(&self) -> &VideoChannel {
+        &self.channel
+    }
+}
+
+impl VideoChannel {
+    /// Example value: "twitch"
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+    /// Example value: "Twitch"
+    pub fn display_name(&self) -> &String {
+        &self.display_name
+    }
+}
+
+/// Replaces the size (e.g. "-320x240") before the file extension of the preview url
+/// with `{width}` and `{height}` placeholders.
+fn preview_template(preview: &str) -> Option<String> {
+    let extension_start = match preview.rfind('.') {
+        Some(extension_start) => extension_start,
+        None => return None,
+    };
+    let size_start = match preview[..extension_start].rfind('-') {
+        Some(size_start) => size_start,
+        None => return None,
+    };
+    let size = &preview[size_start + 1..extension_start];
+    let is_size = match size.find('x') {
+        Some(separator) => {
+            let (width, height) = (&size[..separator], &size[separator + 1..]);
+            !width.is_empty() && !height.is_empty()
+                    && width.chars().chain(height.chars()).all(|c| c.is_ascii_digit())
+        },
+        None => false,
+    };
+    if !is_size {
+        return None;
+    }
+    Some(format!("{}-{{width}}x{{height}}{}", &preview[..size_start], &preview[extension_start..]))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preview_template() {
+        assert_eq!(preview_template("http://static-cdn.jtvnw.net/jtv.thumbs/archive-621292653-320x240.jpg"),
+                Some("http://static-cdn.jtvnw.net/jtv.thumbs/archive-621292653-{width}x{height}.jpg".to_owned()));
+        assert_eq!(preview_template("https://vod-secure.twitch.tv/_404/404_processing_320x240.png"), None);
+        assert_eq!(preview_template("http://static-cdn.jtvnw.net/jtv.thumbs/archive-621292653"), None);
+    }
+}
@@ -0,0 +1,72 @@
+//! Overview of a channel that is combined from several endpoints.
+//!
+//! `TwitchClient::channel_overview` fetches the channel, its live stream, its recent videos
+//! and its follower count concurrently, e.g. for a card or profile page of a streamer.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use twitch_client::TwitchClient;
+//!
+//! let twitch_client = TwitchClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap();
+//! let overview = twitch_client.channel_overview("test_channel").unwrap();
+//! println!("{} ({} followers, {} recent videos, live: {})",
+//!         overview.channel().display_name(), overview.follower_count(),
+//!         overview.recent_videos().len(), overview.is_live());
+//! ```
+
+use model::channel::Channel;
+use model::stream::Stream;
+use model::video::Video;
+
+
+/// Number of recent videos that are requested by `TwitchClient::channel_overview`.
+pub const RECENT_VIDEOS_LIMIT: u8 = 10;
+
+
+/// Channel with its live stream, recent videos and follower count.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelOverview {
+    channel: Channel,
+    stream: Option<Stream>,
+    recent_videos: Vec<Video>,
+    follower_count: u32,
+}
+
+impl ChannelOverview {
+    pub(crate) fn new(channel: Channel, stream: Option<Stream>, recent_videos: Vec<Video>, follower_count: u32)
+            -> ChannelOverview {
+        ChannelOverview {
+            channel: channel,
+            stream: stream,
+            recent_videos: recent_videos,
+            follower_count: follower_count,
+        }
+    }
+    /// Example value: See `Channel` type.
+    pub fn channel(&self) -> &Channel {
+        &self.channel
+    }
+    /// Live stream of the channel, or `None` if the channel is offline.
+    ///
+    /// Example value: See `Stream` type.
+    pub fn stream(&self) -> &Option<Stream> {
+        &self.stream
+    }
+    /// Whether the channel is live.
+    pub fn is_live(&self) -> bool {
+        self.stream.is_some()
+    }
+    /// Up to `RECENT_VIDEOS_LIMIT` videos of the channel, most recent first.
+    ///
+    /// Example value: See `Video` type.
+    pub fn recent_videos(&self) -> &Vec<Video> {
+        &self.recent_videos
+    }
+    /// Total number of users that follow the channel.
+    ///
+    /// Example value: 1234
+    pub fn follower_count(&self) -> u32 {
+        self.follower_count
+    }
+}
@@ -0,0 +1,487 @@
+//! Iteration over the items of paginated endpoints.
+//!
+//! Most endpoints are paginated with an offset (e.g. the top games),
+//! whereas some API version 5 endpoints (e.g. the clips) return a cursor for the next page instead.
+
+use std::fmt;
+#[cfg(feature = "blocking")]
+use std::vec;
+
+use serde::{Serialize, Serializer};
+use serde::de::DeserializeOwned;
+
+#[cfg(feature = "blocking")]
+use error::Result;
+use model::TwitchLinks;
+use model::game::{TopGames, GameInfo};
+use model::stream::{Streams, FeaturedStreams, Stream, FeaturedStream};
+use model::clip::{Clips, Clip};
+use model::collection::{Collections, CollectionMetadata};
+use model::community::{TopCommunities, TopCommunity};
+use model::feed::{Posts, Post};
+use model::follow::{Follows, Follow};
+use model::video::{Videos, ChannelVideos, Video};
+use model::block::{Blocks, Block};
+
+
+/// Maximum number of items per page (`limit`) that Twitch accepts for the paginated endpoints.
+pub const MAX_LIMIT: u8 = 100;
+
+/// Opaque position of a page of an endpoint that is paginated with cursors.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::paging::Cursor;
+///
+/// let cursor = Cursor::from("MTA=");
+/// assert_eq!(cursor.as_str(), "MTA=");
+/// ```
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Cursor(String);
+
+impl Cursor {
+    /// Creates a cursor, e.g. from a cursor that was stored to continue later.
+    pub fn new<S: Into<String>>(cursor: S) -> Cursor {
+        Cursor(cursor.into())
+    }
+    /// Returns the cursor as it is sent to Twitch.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the cursor of a response, or `None` if Twitch sent no cursor or an empty one (i.e. the last page).
+    fn from_response(cursor: &Option<String>) -> Option<Cursor> {
+        match *cursor {
+            Some(ref cursor) if !cursor.is_empty() => Some(Cursor::new(cursor.as_str())),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for Cursor {
+    fn from(cursor: String) -> Cursor {
+        Cursor(cursor)
+    }
+}
+
+impl<'a> From<&'a str> for Cursor {
+    fn from(cursor: &'a str) -> Cursor {
+        Cursor::new(cursor)
+    }
+}
+
+impl<'a> From<&'a String> for Cursor {
+    fn from(cursor: &'a String) -> Cursor {
+        Cursor::new(cursor.as_str())
+    }
+}
+
+impl Serialize for Cursor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Iterator that lazily fetches the pages of a paginated endpoint and yields the individual items.
+///
+/// When all items of the current page were yielded, the next page is requested
+/// with the offset incremented by the number of received items or with the cursor of the current page.
+/// The iteration ends after an empty page, after a page without cursor (cursor pagination)
+/// or after the first error, which is yielded as last item.
+///
+/// # Examples
+///
+/// ```no_run
+/// use twitch_client::*;
+///
+/// let twitch_client = TwitchClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap();
+///
+/// for game_info in twitch_client.top_games_iter(TopGamesParams::new().with_limit(100)).take(250) {
+///     match game_info {
+///         Ok(game_info) => println!("Game: {}", game_info.game().name()),
+///         Err(err) => println!("Failed to retrieve top games: {}", err),
+///     }
+/// }
+/// ```
+#[cfg(feature = "blocking")]
+pub struct PageIterator<'a, T> {
+    fetch_next_page: Box<FnMut() -> Result<Vec<T>> + 'a>,
+    items: vec::IntoIter<T>,
+    finished: bool,
+}
+
+#[cfg(feature = "blocking")]
+impl<'a, T> PageIterator<'a, T> {
+    /// Creates an iterator starting at the offset,
+    /// which fetches the items of a page with the offset passed to `fetch_page`.
+    pub(crate) fn new<F>(offset: u32, mut fetch_page: F) -> PageIterator<'a, T>
+            where F: FnMut(u32) -> Result<Vec<T>> + 'a {
+        let mut offset = offset;
+        PageIterator::with_next_page(move || {
+            let items = try!(fetch_page(offset));
+            offset += items.len() as u32;
+            Ok(items)
+        })
+    }
+
+    /// Creates an iterator starting at the optional cursor (`None` for the first page),
+    /// which fetches the items and the cursor of the next page with the cursor passed to `fetch_page`.
+    pub(crate) fn with_cursor<F>(cursor: Option<Cursor>, mut fetch_page: F) -> PageIterator<'a, T>
+            where F: FnMut(Option<Cursor>) -> Result<(Vec<T>, Option<Cursor>)> + 'a {
+        let mut cursor = cursor;
+        let mut last_page_fetched = false;
+        PageIterator::with_next_page(move || {
+            if last_page_fetched {
+                return Ok(Vec::new());
+            }
+            let (items, next_cursor) = try!(fetch_page(cursor.take()));
+            last_page_fetched = next_cursor.is_none();
+            cursor = next_cursor;
+            Ok(items)
+        })
+    }
+
+    fn with_next_page<F>(fetch_next_page: F) -> PageIterator<'a, T>
+            where F: FnMut() -> Result<Vec<T>> + 'a {
+        PageIterator {
+            fetch_next_page: Box::new(fetch_next_page),
+            items: Vec::new().into_iter(),
+            finished: false,
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<'a, T> Iterator for PageIterator<'a, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        if let Some(item) = self.items.next() {
+            return Some(Ok(item));
+        }
+        if self.finished {
+            return None;
+        }
+
+        match (self.fetch_next_page)() {
+            Ok(items) => {
+                if items.is_empty() {
+                    self.finished = true;
+                    return None;
+                }
+                self.items = items.into_iter();
+                self.items.next().map(Ok)
+            },
+            Err(err) => {
+                self.finished = true;
+                Some(Err(err))
+            },
+        }
+    }
+}
+
+
+/// Page of a paginated endpoint with its pagination metadata.
+///
+/// The offset and limit are taken from the `self` relation of the `_links`
+/// and the total number of items from `_total` (if sent by Twitch).
+/// All list responses that are paginated with an offset implement it (e.g. `TopGames`, `Streams`, `Follows`,
+/// `Videos`, `ChannelVideos` and `Blocks`). Subscriptions have no list response in this crate,
+/// because they can only be looked up per channel (see `TwitchClient::user_subscribed_to_channel`).
+/// Pages can be passed to `TwitchClient::next_page` to fetch the following page
+/// without keeping track of the offset.
+///
+/// # Examples
+///
+/// ```no_run
+/// use twitch_client::*;
+/// use twitch_client::paging::Paginated;
+///
+/// let twitch_client = TwitchClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap();
+///
+/// let mut page = Some(twitch_client.top_games(TopGamesParams::new().with_limit(100)).unwrap());
+/// while let Some(top_games) = page {
+///     println!("Games {:?} of {:?}", top_games.offset(), top_games.total());
+///     for game_info in top_games.items() {
+///         println!("Game: {}", game_info.game().name());
+///     }
+///     page = twitch_client.next_page(&top_games).unwrap();
+/// }
+/// ```
+pub trait Paginated<T>: TwitchLinks + DeserializeOwned {
+    /// Returns the items of the page.
+    fn items(&self) -> &[T];
+
+    /// Returns the total number of items of all pages, if sent by Twitch.
+    fn total(&self) -> Option<u32>;
+
+    /// Returns the offset of the first item of the page, if it is known from the `self` link.
+    fn offset(&self) -> Option<u32> {
+        self.link("self").and_then(|url| query_value(&url.to_string(), "offset"))
+    }
+
+    /// Returns the requested (maximum) number of items of the page, if it is known from the `self` link.
+    fn limit(&self) -> Option<u32> {
+        self.link("self").and_then(|url| query_value(&url.to_string(), "limit"))
+    }
+
+    /// Returns whether there are items after this page.
+    ///
+    /// Compares the offset and number of items with the total if both are known,
+    /// otherwise there are more items if the page is not empty and links to a next page.
+    fn has_more(&self) -> bool {
+        if self.items().is_empty() {
+            return false;
+        }
+        match (self.total(), self.offset()) {
+            (Some(total), Some(offset)) => offset + (self.items().len() as u32) < total,
+            _ => self.link("next").is_some(),
+        }
+    }
+}
+
+impl Paginated<GameInfo> for TopGames {
+    fn items(&self) -> &[GameInfo] {
+        self.top()
+    }
+
+    fn total(&self) -> Option<u32> {
+        Some(TopGames::total(self))
+    }
+}
+
+impl Paginated<Stream> for Streams {
+    fn items(&self) -> &[Stream] {
+        self.streams()
+    }
+
+    fn total(&self) -> Option<u32> {
+        Some(Streams::total(self))
+    }
+}
+
+impl Paginated<FeaturedStream> for FeaturedStreams {
+    fn items(&self) -> &[FeaturedStream] {
+        self.featured()
+    }
+
+    fn total(&self) -> Option<u32> {
+        None
+    }
+}
+
+impl Paginated<Follow> for Follows {
+    fn items(&self) -> &[Follow] {
+        self.follows()
+    }
+
+    fn total(&self) -> Option<u32> {
+        Some(Follows::total(self))
+    }
+}
+
+impl Paginated<Video> for Videos {
+    fn items(&self) -> &[Video] {
+        self.videos()
+    }
+
+    fn total(&self) -> Option<u32> {
+        None
+    }
+}
+
+impl Paginated<Video> for ChannelVideos {
+    fn items(&self) -> &[Video] {
+        self.videos()
+    }
+
+    fn total(&self) -> Option<u32> {
+        Some(ChannelVideos::total(self))
+    }
+}
+
+impl Paginated<Block> for Blocks {
+    fn items(&self) -> &[Block] {
+        self.blocks()
+    }
+
+    fn total(&self) -> Option<u32> {
+        None
+    }
+}
+
+/// Page of an endpoint that is paginated with cursors instead of offsets.
+///
+/// # Examples
+///
+/// ```no_run
+/// use twitch_client::*;
+/// use twitch_client::paging::CursorPaginated;
+///
+/// let twitch_client = TwitchClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap();
+///
+/// let clips = twitch_client.top_clips(ClipsParams::new().with_limit(10)).unwrap();
+/// if let Some(cursor) = clips.next_cursor() {
+///     let next_clips = twitch_client.top_clips(ClipsParams::new().with_limit(10).with_cursor(cursor)).unwrap();
+///     println!("Next clips: {}", next_clips.items().len());
+/// }
+/// ```
+pub trait CursorPaginated<T>: DeserializeOwned {
+    /// Returns the items of the page.
+    fn items(&self) -> &[T];
+
+    /// Returns the cursor of the next page, or `None` if this is the last page.
+    fn next_cursor(&self) -> Option<Cursor>;
+}
+
+impl CursorPaginated<Clip> for Clips {
+    fn items(&self) -> &[Clip] {
+        self.clips()
+    }
+
+    fn next_cursor(&self) -> Option<Cursor> {
+        Cursor::from_response(self.cursor())
+    }
+}
+
+impl CursorPaginated<TopCommunity> for TopCommunities {
+    fn items(&self) -> &[TopCommunity] {
+        self.communities()
+    }
+
+    fn next_cursor(&self) -> Option<Cursor> {
+        Cursor::from_response(self.cursor())
+    }
+}
+
+impl CursorPaginated<CollectionMetadata> for Collections {
+    fn items(&self) -> &[CollectionMetadata] {
+        self.collections()
+    }
+
+    fn next_cursor(&self) -> Option<Cursor> {
+        Cursor::from_response(self.cursor())
+    }
+}
+
+impl CursorPaginated<Post> for Posts {
+    fn items(&self) -> &[Post] {
+        self.posts()
+    }
+
+    fn next_cursor(&self) -> Option<Cursor> {
+        Cursor::from_response(self.cursor())
+    }
+}
+
+/// Returns the numeric value of the query parameter of the url.
+fn query_value(url: &str, name: &str) -> Option<u32> {
+    let url = match ::url::Url::parse(url) {
+        Ok(url) => url,
+        Err(_) => return None,
+    };
+    let value = url.query_pairs().find(|&(ref key, _)| key == name).map(|(_, value)| value.into_owned());
+    value.and_then(|value| value.parse().ok())
+}
+
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use super::*;
+    use error::Error;
+
+    #[test]
+    fn test_should_fetch_pages_until_empty_page() {
+        let mut offsets = Vec::new();
+        let items: Vec<u32> = PageIterator::new(10, |offset| {
+            offsets.push(offset);
+            if offset < 14 {
+                Ok(vec![offset, offset + 1])
+            } else {
+                Ok(Vec::new())
+            }
+        }).map(|item| item.unwrap()).collect();
+
+        assert_eq!(items, vec![10, 11, 12, 13]);
+        assert_eq!(offsets, vec![10, 12, 14]);
+    }
+
+    #[test]
+    fn test_should_read_pagination_from_links() {
+        let top_games: TopGames = ::serde_json::from_str(r#"{
+            "_total": 3,
+            "_links": {
+                "self": "https://api.twitch.tv/kraken/games/top?limit=2&offset=0",
+                "next": "https://api.twitch.tv/kraken/games/top?limit=2&offset=2"
+            },
+            "top": []
+        }"#).unwrap();
+        assert_eq!(top_games.offset(), Some(0));
+        assert_eq!(top_games.limit(), Some(2));
+        assert_eq!(Paginated::total(&top_games), Some(3));
+        assert!(!top_games.has_more());
+        assert_eq!(query_value("https://api.twitch.tv/kraken/streams?offset=25", "limit"), None);
+    }
+
+    #[test]
+    fn test_should_read_pagination_of_follows_and_videos() {
+        let follows: Follows = ::serde_json::from_str(r#"{
+            "_total": 3,
+            "_links": {
+                "self": "https://api.twitch.tv/kraken/channels/test_channel/follows?limit=2&offset=0",
+                "next": "https://api.twitch.tv/kraken/channels/test_channel/follows?limit=2&offset=2"
+            },
+            "follows": []
+        }"#).unwrap();
+        assert_eq!(follows.offset(), Some(0));
+        assert_eq!(follows.limit(), Some(2));
+        assert_eq!(Paginated::total(&follows), Some(3));
+
+        let videos: Videos = ::serde_json::from_str(r#"{
+            "_links": {
+                "self": "https://api.twitch.tv/kraken/videos/top?limit=10&offset=20"
+            },
+            "videos": []
+        }"#).unwrap();
+        assert_eq!(videos.offset(), Some(20));
+        assert_eq!(Paginated::total(&videos), None);
+        assert!(!videos.has_more());
+    }
+
+    #[test]
+    fn test_should_fetch_pages_until_last_cursor() {
+        let mut cursors = Vec::new();
+        let items: Vec<u32> = PageIterator::with_cursor(None, |cursor| {
+            cursors.push(cursor.clone());
+            match cursor.as_ref().map(|cursor| cursor.as_str()) {
+                None => Ok((vec![1, 2], Some(Cursor::from("Mg==")))),
+                Some("Mg==") => Ok((vec![3], None)),
+                Some(cursor) => panic!("unexpected cursor: {}", cursor),
+            }
+        }).map(|item| item.unwrap()).collect();
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(cursors, vec![None, Some(Cursor::from("Mg=="))]);
+    }
+
+    #[test]
+    fn test_should_treat_empty_cursor_as_last_page() {
+        let clips: Clips = ::serde_json::from_str(r#"{"clips": [], "_cursor": ""}"#).unwrap();
+        assert_eq!(clips.next_cursor(), None);
+        let clips: Clips = ::serde_json::from_str(r#"{"clips": [], "_cursor": "MTA="}"#).unwrap();
+        assert_eq!(clips.next_cursor(), Some(Cursor::from("MTA=")));
+    }
+
+    #[test]
+    fn test_should_stop_after_error() {
+        let mut iterator = PageIterator::<u32>::new(0, |_| Err(Error::InvalidCommercialLength(0)));
+        assert!(iterator.next().unwrap().is_err());
+        assert!(iterator.next().is_none());
+    }
+}
@@ -1,13 +1,150 @@
 //! Parameters for `TwitchClient` methods.
 
 use std::string::ToString;
-use std::borrow::Cow;
-use url::percent_encoding::utf8_percent_encode;
-use url::percent_encoding::QUERY_ENCODE_SET;
+use std::error::Error as StdError;
+use std::fmt;
+use std::time::Duration;
+use serde::{Serialize, Serializer};
+use serde_json;
+use serde_urlencoded;
 
-use http::IntoQueryString;
+use model::id::{ChannelId, UserId};
+use paging::Cursor;
+pub use paging::MAX_LIMIT;
+use query_string;
+use ApiVersion;
 
 
+/// Parameters that are sent as query string (e.g. "?offset=0&limit=10").
+pub(crate) trait IntoQueryString {
+    fn into_query_string(self) -> String;
+}
+
+/// Parameters that are sent as JSON request body.
+pub(crate) trait IntoRequestBody {
+    fn into_request_body(self) -> String;
+}
+
+/// Parameters that are sent as query string, serialized with serde from the fields of the params struct.
+///
+/// `None` values are left out and lists are sent as repeated names (e.g. "id=1&id=2"),
+/// unless they are joined with commas (see `serialize_comma_separated`).
+pub(crate) trait QueryParams: Serialize {}
+
+impl<T: QueryParams> IntoQueryString for T {
+    fn into_query_string(self) -> String {
+        serialize_query_string(&self)
+    }
+}
+
+/// Largest `offset` that passes the validation of the params,
+/// since Twitch does not serve pages beyond it for the top games and featured streams.
+pub const MAX_OFFSET: u32 = 900;
+
+/// Invalid parameter value that Twitch would reject or silently clamp,
+/// returned by the `validate` methods of the params.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum ParamError {
+    /// The limit is not in the range from 1 to `MAX_LIMIT`.
+    InvalidLimit(u8),
+    /// The offset is larger than `MAX_OFFSET`.
+    InvalidOffset(u32),
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParamError::InvalidLimit(limit) => {
+                write!(f, "{}: {} (allowed: 1 to {})", self.description(), limit, MAX_LIMIT)
+            },
+            ParamError::InvalidOffset(offset) => {
+                write!(f, "{}: {} (allowed: 0 to {})", self.description(), offset, MAX_OFFSET)
+            },
+        }
+    }
+}
+
+impl StdError for ParamError {
+    fn description(&self) -> &str {
+        match *self {
+            ParamError::InvalidLimit(_) => "The limit is out of the range allowed by Twitch",
+            ParamError::InvalidOffset(_) => "The offset is larger than the offsets served by Twitch",
+        }
+    }
+}
+
+/// Checks the optional offset and limit of paginated params.
+fn validate_paging(offset: Option<u32>, limit: Option<u8>) -> Result<(), ParamError> {
+    if let Some(limit) = limit {
+        if limit == 0 || limit > MAX_LIMIT {
+            return Err(ParamError::InvalidLimit(limit));
+        }
+    }
+    if let Some(offset) = offset {
+        if offset > MAX_OFFSET {
+            return Err(ParamError::InvalidOffset(offset));
+        }
+    }
+    Ok(())
+}
+
+/// Identifies a channel or user either by its id or its name.
+///
+/// Twitch API version 3 requires names, whereas version 5 requires ids (see `ApiVersion`).
+/// Usually created implicitly by passing a `&str`/`String` (name), a `ChannelId`/`UserId`
+/// or a `u64` (id) to a `TwitchClient` method.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::model::id::ChannelId;
+/// use twitch_client::param::IdOrName;
+///
+/// assert_eq!(IdOrName::from("test_channel"), IdOrName::Name("test_channel".to_owned()));
+/// assert_eq!(IdOrName::from(ChannelId::from(12345)), IdOrName::Id(12345));
+/// assert_eq!(IdOrName::from(12345u64), IdOrName::Id(12345));
+/// ```
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum IdOrName {
+    /// Id of the channel or user, required by Twitch API version 5.
+    Id(u64),
+    /// Name of the channel or user, required by Twitch API version 3.
+    Name(String),
+}
+
+/// Reference to a channel by its id or name, accepted by the channel methods of `TwitchClient`.
+pub type ChannelRef = IdOrName;
+
+impl From<u64> for IdOrName {
+    fn from(id: u64) -> IdOrName {
+        IdOrName::Id(id)
+    }
+}
+
+impl From<ChannelId> for IdOrName {
+    fn from(id: ChannelId) -> IdOrName {
+        IdOrName::Id(id.value())
+    }
+}
+
+impl From<UserId> for IdOrName {
+    fn from(id: UserId) -> IdOrName {
+        IdOrName::Id(id.value())
+    }
+}
+
+impl<'a> From<&'a str> for IdOrName {
+    fn from(name: &'a str) -> IdOrName {
+        IdOrName::Name(name.to_owned())
+    }
+}
+
+impl From<String> for IdOrName {
+    fn from(name: String) -> IdOrName {
+        IdOrName::Name(name)
+    }
+}
+
 /// Parameters for the top games.
 ///
 /// # Examples
@@ -20,7 +157,7 @@ use http::IntoQueryString;
 ///         .with_offset(40)
 ///         .with_limit(20);
 /// ```
-#[derive(Default, Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
 pub struct TopGamesParams {
     offset: Option<u32>,
     limit: Option<u8>,
@@ -33,6 +170,9 @@ impl TopGamesParams {
     pub fn new() -> TopGamesParams {
         TopGamesParams::default()
     }
+    pub(crate) fn offset(&self) -> Option<u32> {
+        self.offset
+    }
     /// Offset for pagination.
     ///
     /// Twitch defaults to 0 if not set.
@@ -47,19 +187,28 @@ impl TopGamesParams {
         self.limit = Some(limit);
         self
     }
-}
-
-impl IntoQueryString for TopGamesParams {
-    fn into_query_string(self) -> String {
-        params_into_query_string(vec![
-            ("offset", self.offset.map(|offset| offset.to_string())),
-            ("limit", self.limit.map(|limit| limit.to_string())),
-        ])
+    /// Returns the params if the limit is in the range from 1 to `MAX_LIMIT`
+    /// and the offset is not larger than `MAX_OFFSET`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twitch_client::param::{TopGamesParams, ParamError};
+    ///
+    /// assert!(TopGamesParams::new().with_limit(100).validate().is_ok());
+    /// assert_eq!(TopGamesParams::new().with_limit(0).validate(), Err(ParamError::InvalidLimit(0)));
+    /// ```
+    pub fn validate(self) -> Result<TopGamesParams, ParamError> {
+        try!(validate_paging(self.offset, self.limit));
+        Ok(self)
     }
 }
 
+impl QueryParams for TopGamesParams {}
+
 /// `StreamType` for `StreamsParams` to only show streams from a certain type.
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[derive(Serialize, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[serde(rename_all="lowercase")]
 pub enum StreamType {
     /// Show all streams.
     All,
@@ -69,12 +218,6 @@ pub enum StreamType {
     Live,
 }
 
-impl StreamType {
-    fn to_query_string_value(&self) -> String {
-        format!("{:?}", &self).to_lowercase()
-    }
-}
-
 /// Parameters for the streams.
 ///
 /// # Examples
@@ -90,14 +233,18 @@ impl StreamType {
 ///         .with_game("StarCraft II: Heart of the Swarm")
 ///         .with_stream_type(StreamType::Live);
 /// ```
-#[derive(Default, Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
 pub struct StreamsParams {
     game: Option<String>,
+    #[serde(rename="channel", skip_serializing_if="Vec::is_empty", serialize_with="serialize_comma_separated")]
     channels: Vec<String>,
     offset: Option<u32>,
     limit: Option<u8>,
     client_id: Option<String>,
     stream_type: Option<StreamType>,
+    community_id: Option<String>,
+    language: Option<String>,
+    broadcaster_language: Option<String>,
 }
 
 impl StreamsParams {
@@ -107,6 +254,12 @@ impl StreamsParams {
     pub fn new() -> StreamsParams {
         StreamsParams::default()
     }
+    pub(crate) fn offset(&self) -> Option<u32> {
+        self.offset
+    }
+    pub(crate) fn limit(&self) -> Option<u8> {
+        self.limit
+    }
     /// Streams categorized under game.
     ///
     /// Twitch defaults to all games if not set.
@@ -158,28 +311,84 @@ impl StreamsParams {
         self.stream_type = Some(stream_type);
         self
     }
+    /// Only shows streams of the community (id).
+    ///
+    /// Requires `ApiVersion::V5`. Twitch defaults to all communities if not set.
+    pub fn with_community_id(mut self, community_id: &str) -> StreamsParams {
+        self.community_id = Some(community_id.to_owned());
+        self
+    }
+    /// Only shows streams in the language (locale id, e.g. "en", "fi" or "es-mx").
+    ///
+    /// Twitch defaults to all languages if not set.
+    pub fn with_language(mut self, language: &str) -> StreamsParams {
+        self.language = Some(language.to_owned());
+        self
+    }
+    /// Only shows streams of broadcasters with the language set in the channel settings (e.g. "en").
+    ///
+    /// Twitch defaults to all broadcaster languages if not set.
+    pub fn with_broadcaster_language(mut self, broadcaster_language: &str) -> StreamsParams {
+        self.broadcaster_language = Some(broadcaster_language.to_owned());
+        self
+    }
 }
 
-impl IntoQueryString for StreamsParams {
-    fn into_query_string(self) -> String {
-        params_into_query_string(vec![
-            ("game", self.game.map(|game| game)),
-            (
-                "channel",
-                if self.channels.is_empty() {
-                    None
-                } else {
-                    Some(self.channels.join(","))
-                }
-            ),
-            ("offset", self.offset.map(|offset| offset.to_string())),
-            ("limit", self.limit.map(|limit| limit.to_string())),
-            ("client_id", self.client_id.map(|client_id| client_id)),
-            ("stream_type", self.stream_type.map(|stream_type| stream_type.to_query_string_value())),
-        ])
+impl QueryParams for StreamsParams {}
+
+/// Parameters for the followed streams.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::param::FollowedStreamsParams;
+/// use twitch_client::param::StreamType;
+///
+/// let _default_params = FollowedStreamsParams::default();
+/// let _custom_params = FollowedStreamsParams::new()
+///         .with_offset(25)
+///         .with_limit(25)
+///         .with_stream_type(StreamType::Live);
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct FollowedStreamsParams {
+    offset: Option<u32>,
+    limit: Option<u8>,
+    stream_type: Option<StreamType>,
+}
+
+impl FollowedStreamsParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for FollowedStreamsParams::default() but preferred if custom parameters are set.
+    pub fn new() -> FollowedStreamsParams {
+        FollowedStreamsParams::default()
+    }
+    /// Offset for pagination.
+    ///
+    /// Twitch defaults to 0 if not set.
+    pub fn with_offset(mut self, offset: u32) -> FollowedStreamsParams {
+        self.offset = Some(offset);
+        self
+    }
+    /// Maximum number of objects in array.
+    ///
+    /// Twitch defaults to 25 if not set. Maximum is 100.
+    pub fn with_limit(mut self, limit: u8) -> FollowedStreamsParams {
+        self.limit = Some(limit);
+        self
+    }
+    /// Only shows streams from a certain type.
+    ///
+    /// Twitch defaults to all if not set.
+    pub fn with_stream_type(mut self, stream_type: StreamType) -> FollowedStreamsParams {
+        self.stream_type = Some(stream_type);
+        self
     }
 }
 
+impl QueryParams for FollowedStreamsParams {}
+
 /// Parameters for the featured streams.
 ///
 /// Note that the number of promoted streams varies from day to day,
@@ -195,7 +404,7 @@ impl IntoQueryString for StreamsParams {
 ///         .with_offset(5)
 ///         .with_limit(5);
 /// ```
-#[derive(Default, Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
 pub struct FeaturedStreamsParams {
     offset: Option<u32>,
     limit: Option<u8>,
@@ -208,6 +417,9 @@ impl FeaturedStreamsParams {
     pub fn new() -> FeaturedStreamsParams {
         FeaturedStreamsParams::default()
     }
+    pub(crate) fn offset(&self) -> Option<u32> {
+        self.offset
+    }
     /// Offset for pagination.
     ///
     /// Twitch defaults to 0 if not set.
@@ -222,17 +434,16 @@ impl FeaturedStreamsParams {
         self.limit = Some(limit);
         self
     }
-}
-
-impl IntoQueryString for FeaturedStreamsParams {
-    fn into_query_string(self) -> String {
-        params_into_query_string(vec![
-            ("offset", self.offset.map(|offset| offset.to_string())),
-            ("limit", self.limit.map(|limit| limit.to_string())),
-        ])
+    /// Returns the params if the limit is in the range from 1 to `MAX_LIMIT`
+    /// and the offset is not larger than `MAX_OFFSET`.
+    pub fn validate(self) -> Result<FeaturedStreamsParams, ParamError> {
+        try!(validate_paging(self.offset, self.limit));
+        Ok(self)
     }
 }
 
+impl QueryParams for FeaturedStreamsParams {}
+
 /// Parameters for the streams summary.
 ///
 /// # Examples
@@ -244,7 +455,7 @@ impl IntoQueryString for FeaturedStreamsParams {
 /// let _custom_params = StreamsSummaryParams::new()
 ///         .with_game("StarCraft II: Heart of the Swarm");
 /// ```
-#[derive(Default, Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
 pub struct StreamsSummaryParams {
     game: Option<String>,
 }
@@ -265,91 +476,963 @@ impl StreamsSummaryParams {
     }
 }
 
-impl IntoQueryString for StreamsSummaryParams {
-    fn into_query_string(self) -> String {
-        params_into_query_string(vec![
-            ("game", self.game.map(|game| game)),
-        ])
-    }
+impl QueryParams for StreamsSummaryParams {}
+
+
+/// `Direction` for `FollowsParams` to sort the follows by their creation date.
+#[derive(Serialize, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[serde(rename_all="lowercase")]
+pub enum Direction {
+    /// Oldest follows first.
+    Asc,
+    /// Newest follows first.
+    Desc,
+}
+
+/// Parameters for the follows of a channel.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::param::FollowsParams;
+/// use twitch_client::param::Direction;
+///
+/// let _default_params = FollowsParams::default();
+/// let _custom_params = FollowsParams::new()
+///         .with_offset(25)
+///         .with_limit(25)
+///         .with_direction(Direction::Asc);
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct FollowsParams {
+    offset: Option<u32>,
+    limit: Option<u8>,
+    direction: Option<Direction>,
 }
 
+impl FollowsParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for FollowsParams::default() but preferred if custom parameters are set.
+    pub fn new() -> FollowsParams {
+        FollowsParams::default()
+    }
+    /// Offset for pagination.
+    ///
+    /// Twitch defaults to 0 if not set.
+    pub fn with_offset(mut self, offset: u32) -> FollowsParams {
+        self.offset = Some(offset);
+        self
+    }
+    /// Maximum number of objects in array.
+    ///
+    /// Twitch defaults to 25 if not set. Maximum is 100.
+    pub fn with_limit(mut self, limit: u8) -> FollowsParams {
+        self.limit = Some(limit);
+        self
+    }
+    /// Sorting direction of the follows by their creation date.
+    ///
+    /// Twitch defaults to `Direction::Desc` (newest first) if not set.
+    pub fn with_direction(mut self, direction: Direction) -> FollowsParams {
+        self.direction = Some(direction);
+        self
+    }
+}
 
+impl QueryParams for FollowsParams {}
 
-fn params_into_query_string(params: Vec<(&str, Option<String>)>) -> String {
-    let mut query_string = String::new();
 
-    for (param_name, param_value) in params {
-        match param_value {
-            Some(ref value) => {
-                if query_string.is_empty() {
-                    query_string.push('?');
-                } else {
-                    query_string.push('&');
-                }
+/// Parameters for the blocked users.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::param::BlocksParams;
+///
+/// let _default_params = BlocksParams::default();
+/// let _custom_params = BlocksParams::new()
+///         .with_offset(25)
+///         .with_limit(25);
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct BlocksParams {
+    offset: Option<u32>,
+    limit: Option<u8>,
+}
 
-                query_string.push_str(param_name);
-                query_string.push('=');
-                query_string.push_str(&encode(value));
-            },
-            None => {},
-        }
+impl BlocksParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for BlocksParams::default() but preferred if custom parameters are set.
+    pub fn new() -> BlocksParams {
+        BlocksParams::default()
+    }
+    /// Offset for pagination.
+    ///
+    /// Twitch defaults to 0 if not set.
+    pub fn with_offset(mut self, offset: u32) -> BlocksParams {
+        self.offset = Some(offset);
+        self
+    }
+    /// Maximum number of objects in array.
+    ///
+    /// Twitch defaults to 25 if not set. Maximum is 100.
+    pub fn with_limit(mut self, limit: u8) -> BlocksParams {
+        self.limit = Some(limit);
+        self
     }
-
-    query_string
 }
 
-fn encode(param_value: &str) -> Cow<str> {
-    utf8_percent_encode(param_value, QUERY_ENCODE_SET).collect()
+impl QueryParams for BlocksParams {}
+
+/// Parameters for following a channel.
+///
+/// Only used internally by `TwitchClient::follow_channel`.
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub(crate) struct FollowChannelParams {
+    notifications: Option<bool>,
 }
 
+impl FollowChannelParams {
+    pub(crate) fn new() -> FollowChannelParams {
+        FollowChannelParams::default()
+    }
+    pub(crate) fn with_notifications(mut self, notifications: bool) -> FollowChannelParams {
+        self.notifications = Some(notifications);
+        self
+    }
+}
 
+impl QueryParams for FollowChannelParams {}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use http::IntoQueryString;
+/// Parameters for searching games.
+///
+/// Only used internally by `TwitchClient::search_games`.
+#[derive(Serialize, Debug, Clone, Hash, Eq, PartialEq)]
+pub(crate) struct SearchGamesParams {
+    query: String,
+    #[serde(rename="type")]
+    search_type: &'static str,
+    live: bool,
+}
 
-    #[test]
-    fn test_default_params_query_string_should_be_empty_to_use_twitch_default() {
-        let params = TopGamesParams::default();
-        assert_eq!(params.into_query_string(), "");
+impl SearchGamesParams {
+    pub(crate) fn new(query: &str, live: bool) -> SearchGamesParams {
+        SearchGamesParams {
+            query: query.to_owned(),
+            search_type: "suggest",
+            live: live,
+        }
     }
+}
 
-    #[test]
-    fn test_one_param_should_only_set_one_query_value() {
-        let params = TopGamesParams::new()
-                .with_limit(10);
-        assert_eq!(params.into_query_string(), "?limit=10");
-    }
+impl QueryParams for SearchGamesParams {}
 
-    #[test]
-    fn test_multiple_params_should_concatenate_query_string_values_correctly() {
-        let params = TopGamesParams::new()
-                .with_offset(5)
-                .with_limit(10);
-        assert_eq!(params.into_query_string(), "?offset=5&limit=10");
+/// Parameters for looking up users by their login names.
+///
+/// Only used internally by `TwitchClient::users_by_login`.
+#[derive(Serialize, Debug, Clone, Hash, Eq, PartialEq)]
+pub(crate) struct UsersByLoginParams {
+    #[serde(rename="login", skip_serializing_if="Vec::is_empty", serialize_with="serialize_comma_separated")]
+    logins: Vec<String>,
+}
+
+impl UsersByLoginParams {
+    pub(crate) fn new(logins: &[&str]) -> UsersByLoginParams {
+        UsersByLoginParams {
+            logins: logins.iter().map(|login| login.to_string()).collect(),
+        }
     }
+}
 
-    #[test]
-    fn test_string_params_should_be_escaped_correctly() {
-        let params = StreamsParams::new()
-                .with_game("StarCraft II: Heart of the Swarm");
-        assert_eq!(params.into_query_string(), "?game=StarCraft%20II:%20Heart%20of%20the%20Swarm");
+impl QueryParams for UsersByLoginParams {}
+
+/// Parameters for updating a channel.
+///
+/// Only the parameters that are set are changed.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::param::UpdateChannelParams;
+///
+/// let _params = UpdateChannelParams::new()
+///         .with_status("Playing cool new game!")
+///         .with_game("Diablo")
+///         .with_delay(60)
+///         .with_channel_feed_enabled(true);
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct UpdateChannelParams {
+    #[serde(skip_serializing_if="Option::is_none")]
+    status: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    game: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    delay: Option<u32>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    channel_feed_enabled: Option<bool>,
+}
+
+impl UpdateChannelParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for UpdateChannelParams::default() but preferred if custom parameters are set.
+    pub fn new() -> UpdateChannelParams {
+        UpdateChannelParams::default()
+    }
+    /// New status (title) of the channel.
+    ///
+    /// The status is not changed if not set.
+    pub fn with_status(mut self, status: &str) -> UpdateChannelParams {
+        self.status = Some(status.to_owned());
+        self
+    }
+    /// New game of the channel.
+    ///
+    /// The game is not changed if not set.
+    pub fn with_game(mut self, game: &str) -> UpdateChannelParams {
+        self.game = Some(game.to_owned());
+        self
     }
+    /// New stream delay in seconds.
+    /// Requires the channel owner's OAuth token.
+    ///
+    /// The delay is not changed if not set.
+    pub fn with_delay(mut self, delay: u32) -> UpdateChannelParams {
+        self.delay = Some(delay);
+        self
+    }
+    /// Whether the channel feed should be enabled.
+    /// Requires the channel owner's OAuth token.
+    ///
+    /// The channel feed setting is not changed if not set.
+    pub fn with_channel_feed_enabled(mut self, channel_feed_enabled: bool) -> UpdateChannelParams {
+        self.channel_feed_enabled = Some(channel_feed_enabled);
+        self
+    }
+}
 
-    #[test]
-    fn test_empty_vec_query_string_should_be_empty_to_use_twitch_default() {
-        // StreamsParams contains channels with type Vec<String>
-        let params = StreamsParams::default();
-        assert_eq!(params.into_query_string(), "");
+impl IntoRequestBody for UpdateChannelParams {
+    fn into_request_body(self) -> String {
+        #[derive(Serialize)]
+        struct UpdateChannelBody {
+            channel: UpdateChannelParams,
+        }
+
+        serde_json::to_string(&UpdateChannelBody { channel: self }).unwrap()
     }
+}
 
-    #[test]
+/// Parameters for running a commercial.
+///
+/// Only used internally by `TwitchClient::run_commercial`.
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub(crate) struct CommercialParams {
+    length: Option<u16>,
+}
+
+impl CommercialParams {
+    pub(crate) fn new() -> CommercialParams {
+        CommercialParams::default()
+    }
+    pub(crate) fn with_length(mut self, length: u16) -> CommercialParams {
+        self.length = Some(length);
+        self
+    }
+}
+
+impl QueryParams for CommercialParams {}
+
+/// Parameters for the emoticon images.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::param::EmoticonImagesParams;
+///
+/// let _default_params = EmoticonImagesParams::default();
+/// let _custom_params = EmoticonImagesParams::new()
+///         .with_emoticon_set(19151)
+///         .with_emoticon_set(33);
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct EmoticonImagesParams {
+    #[serde(rename="emotesets", skip_serializing_if="Vec::is_empty", serialize_with="serialize_comma_separated")]
+    emoticon_sets: Vec<u64>,
+}
+
+impl EmoticonImagesParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for EmoticonImagesParams::default() but preferred if custom parameters are set.
+    pub fn new() -> EmoticonImagesParams {
+        EmoticonImagesParams::default()
+    }
+    /// Emoticons from an emoticon set.
+    /// Can be called multiple times to specify a list of emoticon sets.
+    ///
+    /// Twitch returns all emoticons ungrouped if not set.
+    pub fn with_emoticon_set(mut self, emoticon_set: u64) -> EmoticonImagesParams {
+        self.emoticon_sets.push(emoticon_set);
+        self
+    }
+    /// Emoticons from a list of emoticon sets.
+    /// Can be called with an empty Vec to clear the list and use the default again.
+    ///
+    /// Twitch returns all emoticons ungrouped if not set or empty.
+    pub fn with_emoticon_sets(mut self, emoticon_sets: Vec<u64>) -> EmoticonImagesParams {
+        self.emoticon_sets = emoticon_sets;
+        self
+    }
+}
+
+impl QueryParams for EmoticonImagesParams {}
+
+
+/// `Period` for `TopVideosParams` to only show videos created in a certain time period.
+#[derive(Serialize, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[serde(rename_all="lowercase")]
+pub enum Period {
+    /// Show videos from the last week.
+    Week,
+    /// Show videos from the last month.
+    Month,
+    /// Show videos from all time.
+    All,
+}
+
+/// Parameters for the top videos.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::param::TopVideosParams;
+/// use twitch_client::param::Period;
+///
+/// let _default_params = TopVideosParams::default();
+/// let _custom_params = TopVideosParams::new()
+///         .with_offset(10)
+///         .with_limit(10)
+///         .with_game("Gaming Talk Shows")
+///         .with_period(Period::Month);
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct TopVideosParams {
+    offset: Option<u32>,
+    limit: Option<u8>,
+    game: Option<String>,
+    period: Option<Period>,
+}
+
+impl TopVideosParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for TopVideosParams::default() but preferred if custom parameters are set.
+    pub fn new() -> TopVideosParams {
+        TopVideosParams::default()
+    }
+    /// Offset for pagination.
+    ///
+    /// Twitch defaults to 0 if not set.
+    pub fn with_offset(mut self, offset: u32) -> TopVideosParams {
+        self.offset = Some(offset);
+        self
+    }
+    /// Maximum number of objects in array.
+    ///
+    /// Twitch defaults to 10 if not set. Maximum is 100.
+    pub fn with_limit(mut self, limit: u8) -> TopVideosParams {
+        self.limit = Some(limit);
+        self
+    }
+    /// Videos categorized under game.
+    ///
+    /// Twitch defaults to all games if not set.
+    pub fn with_game(mut self, game: &str) -> TopVideosParams {
+        self.game = Some(game.to_owned());
+        self
+    }
+    /// Only shows videos created in a certain time period.
+    ///
+    /// Twitch defaults to `Period::Week` if not set.
+    pub fn with_period(mut self, period: Period) -> TopVideosParams {
+        self.period = Some(period);
+        self
+    }
+}
+
+impl QueryParams for TopVideosParams {}
+
+/// Parameters for the videos of the followed channels.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::param::FollowedVideosParams;
+///
+/// let _default_params = FollowedVideosParams::default();
+/// let _custom_params = FollowedVideosParams::new()
+///         .with_offset(10)
+///         .with_limit(10);
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct FollowedVideosParams {
+    offset: Option<u32>,
+    limit: Option<u8>,
+}
+
+impl FollowedVideosParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for FollowedVideosParams::default() but preferred if custom parameters are set.
+    pub fn new() -> FollowedVideosParams {
+        FollowedVideosParams::default()
+    }
+    /// Offset for pagination.
+    ///
+    /// Twitch defaults to 0 if not set.
+    pub fn with_offset(mut self, offset: u32) -> FollowedVideosParams {
+        self.offset = Some(offset);
+        self
+    }
+    /// Maximum number of objects in array.
+    ///
+    /// Twitch defaults to 10 if not set. Maximum is 100.
+    pub fn with_limit(mut self, limit: u8) -> FollowedVideosParams {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl QueryParams for FollowedVideosParams {}
+
+/// Parameters for the videos of a channel.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::param::ChannelVideosParams;
+///
+/// let _default_params = ChannelVideosParams::default();
+/// let _custom_params = ChannelVideosParams::new()
+///         .with_offset(10)
+///         .with_limit(10)
+///         .with_broadcasts(true);
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct ChannelVideosParams {
+    offset: Option<u32>,
+    limit: Option<u8>,
+    broadcasts: Option<bool>,
+}
+
+impl ChannelVideosParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for ChannelVideosParams::default() but preferred if custom parameters are set.
+    pub fn new() -> ChannelVideosParams {
+        ChannelVideosParams::default()
+    }
+    /// Offset for pagination.
+    ///
+    /// Twitch defaults to 0 if not set.
+    pub fn with_offset(mut self, offset: u32) -> ChannelVideosParams {
+        self.offset = Some(offset);
+        self
+    }
+    /// Maximum number of objects in array.
+    ///
+    /// Twitch defaults to 10 if not set. Maximum is 100.
+    pub fn with_limit(mut self, limit: u8) -> ChannelVideosParams {
+        self.limit = Some(limit);
+        self
+    }
+    /// Returns only broadcasts when `true`, otherwise only highlights are returned.
+    ///
+    /// Twitch defaults to `false` if not set.
+    pub fn with_broadcasts(mut self, broadcasts: bool) -> ChannelVideosParams {
+        self.broadcasts = Some(broadcasts);
+        self
+    }
+}
+
+impl QueryParams for ChannelVideosParams {}
+
+
+/// `ClipPeriod` for `ClipsParams` to only show clips created in a certain time period.
+#[derive(Serialize, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[serde(rename_all="lowercase")]
+pub enum ClipPeriod {
+    /// Show clips from the last day.
+    Day,
+    /// Show clips from the last week.
+    Week,
+    /// Show clips from the last month.
+    Month,
+    /// Show clips from all time.
+    All,
+}
+
+/// Parameters for the top clips and the followed clips.
+///
+/// The followed clips only support the trending, limit and cursor parameters.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::param::ClipsParams;
+/// use twitch_client::param::ClipPeriod;
+///
+/// let _default_params = ClipsParams::default();
+/// let _custom_params = ClipsParams::new()
+///         .with_channel("dansgaming")
+///         .with_period(ClipPeriod::Month)
+///         .with_trending(true)
+///         .with_limit(10);
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct ClipsParams {
+    #[serde(rename="channel", skip_serializing_if="Vec::is_empty", serialize_with="serialize_comma_separated")]
+    channels: Vec<String>,
+    #[serde(rename="game", skip_serializing_if="Vec::is_empty", serialize_with="serialize_comma_separated")]
+    games: Vec<String>,
+    period: Option<ClipPeriod>,
+    trending: Option<bool>,
+    #[serde(rename="language", skip_serializing_if="Vec::is_empty", serialize_with="serialize_comma_separated")]
+    languages: Vec<String>,
+    limit: Option<u8>,
+    cursor: Option<Cursor>,
+}
+
+impl ClipsParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for ClipsParams::default() but preferred if custom parameters are set.
+    pub fn new() -> ClipsParams {
+        ClipsParams::default()
+    }
+    /// Clips of the channel (name).
+    ///
+    /// Can be called multiple times for up to 10 channels.
+    /// Twitch defaults to all channels if neither channels nor games are set.
+    pub fn with_channel(mut self, channel: &str) -> ClipsParams {
+        self.channels.push(channel.to_owned());
+        self
+    }
+    /// Clips of the game (name).
+    ///
+    /// Can be called multiple times for up to 10 games.
+    pub fn with_game(mut self, game: &str) -> ClipsParams {
+        self.games.push(game.to_owned());
+        self
+    }
+    /// Only shows clips created in a certain time period.
+    ///
+    /// Twitch defaults to `ClipPeriod::Week` if not set.
+    pub fn with_period(mut self, period: ClipPeriod) -> ClipsParams {
+        self.period = Some(period);
+        self
+    }
+    /// Sorts the clips by their popularity in the period instead of their views.
+    ///
+    /// Twitch defaults to false if not set.
+    pub fn with_trending(mut self, trending: bool) -> ClipsParams {
+        self.trending = Some(trending);
+        self
+    }
+    /// Clips in the language (e.g. "en").
+    ///
+    /// Can be called multiple times for up to 28 languages.
+    /// Twitch defaults to all languages if not set.
+    pub fn with_language(mut self, language: &str) -> ClipsParams {
+        self.languages.push(language.to_owned());
+        self
+    }
+    /// Maximum number of objects in array.
+    ///
+    /// Twitch defaults to 10 if not set. Maximum is 100.
+    pub fn with_limit(mut self, limit: u8) -> ClipsParams {
+        self.limit = Some(limit);
+        self
+    }
+    /// Cursor for pagination, as returned by `Clips::next_cursor()` (see `CursorPaginated`).
+    pub fn with_cursor<C: Into<Cursor>>(mut self, cursor: C) -> ClipsParams {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+impl QueryParams for ClipsParams {}
+
+
+/// Parameters for the top communities.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::param::TopCommunitiesParams;
+///
+/// let _default_params = TopCommunitiesParams::default();
+/// let _custom_params = TopCommunitiesParams::new()
+///         .with_limit(10)
+///         .with_cursor("MTA=");
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct TopCommunitiesParams {
+    limit: Option<u8>,
+    cursor: Option<Cursor>,
+}
+
+impl TopCommunitiesParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for TopCommunitiesParams::default() but preferred if custom parameters are set.
+    pub fn new() -> TopCommunitiesParams {
+        TopCommunitiesParams::default()
+    }
+    /// Maximum number of objects in array.
+    ///
+    /// Twitch defaults to 10 if not set. Maximum is 100.
+    pub fn with_limit(mut self, limit: u8) -> TopCommunitiesParams {
+        self.limit = Some(limit);
+        self
+    }
+    /// Cursor for pagination, as returned by `TopCommunities::next_cursor()` (see `CursorPaginated`).
+    pub fn with_cursor<C: Into<Cursor>>(mut self, cursor: C) -> TopCommunitiesParams {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+impl QueryParams for TopCommunitiesParams {}
+
+
+/// Parameters for the collections of a channel.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::param::CollectionsParams;
+///
+/// let _default_params = CollectionsParams::default();
+/// let _custom_params = CollectionsParams::new()
+///         .with_limit(10)
+///         .with_containing_item("video:122138849");
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct CollectionsParams {
+    limit: Option<u8>,
+    cursor: Option<Cursor>,
+    containing_item: Option<String>,
+}
+
+impl CollectionsParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for CollectionsParams::default() but preferred if custom parameters are set.
+    pub fn new() -> CollectionsParams {
+        CollectionsParams::default()
+    }
+    /// Maximum number of objects in array.
+    ///
+    /// Twitch defaults to 10 if not set. Maximum is 100.
+    pub fn with_limit(mut self, limit: u8) -> CollectionsParams {
+        self.limit = Some(limit);
+        self
+    }
+    /// Cursor for pagination, as returned by `Collections::next_cursor()` (see `CursorPaginated`).
+    pub fn with_cursor<C: Into<Cursor>>(mut self, cursor: C) -> CollectionsParams {
+        self.cursor = Some(cursor.into());
+        self
+    }
+    /// Only shows collections that contain the item (e.g. "video:122138849").
+    ///
+    /// Twitch defaults to all collections if not set.
+    pub fn with_containing_item(mut self, containing_item: &str) -> CollectionsParams {
+        self.containing_item = Some(containing_item.to_owned());
+        self
+    }
+}
+
+impl QueryParams for CollectionsParams {}
+
+
+/// Parameters for the posts of a channel feed.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::param::PostsParams;
+///
+/// let _default_params = PostsParams::default();
+/// let _custom_params = PostsParams::new()
+///         .with_limit(10)
+///         .with_cursor("1454026686000000000");
+/// ```
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct PostsParams {
+    limit: Option<u8>,
+    cursor: Option<Cursor>,
+}
+
+impl PostsParams {
+    /// Constructs a new instance.
+    ///
+    /// Synonym for PostsParams::default() but preferred if custom parameters are set.
+    pub fn new() -> PostsParams {
+        PostsParams::default()
+    }
+    /// Maximum number of objects in array.
+    ///
+    /// Twitch defaults to 10 if not set. Maximum is 100.
+    pub fn with_limit(mut self, limit: u8) -> PostsParams {
+        self.limit = Some(limit);
+        self
+    }
+    /// Cursor for pagination, as returned by `Posts::next_cursor()` (see `CursorPaginated`).
+    ///
+    /// Twitch starts with the newest post if not set.
+    pub fn with_cursor<C: Into<Cursor>>(mut self, cursor: C) -> PostsParams {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+impl QueryParams for PostsParams {}
+
+/// Parameters for creating a post in a channel feed.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::param::CreatePostParams;
+///
+/// let _params = CreatePostParams::new("Kappa post")
+///         .with_share(true);
+/// ```
+#[derive(Serialize, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct CreatePostParams {
+    content: String,
+    #[serde(skip_serializing)]
+    share: Option<bool>,
+}
+
+impl CreatePostParams {
+    /// Constructs a new instance with the content of the post.
+    pub fn new(content: &str) -> CreatePostParams {
+        CreatePostParams {
+            content: content.to_owned(),
+            share: None,
+        }
+    }
+    /// Whether the post should also be shared on the channel owner's connected Twitter account.
+    ///
+    /// Twitch defaults to false if not set.
+    pub fn with_share(mut self, share: bool) -> CreatePostParams {
+        self.share = Some(share);
+        self
+    }
+}
+
+impl IntoQueryString for CreatePostParams {
+    fn into_query_string(self) -> String {
+        #[derive(Serialize)]
+        struct CreatePostQuery {
+            share: Option<bool>,
+        }
+        serialize_query_string(&CreatePostQuery { share: self.share })
+    }
+}
+
+impl IntoRequestBody for CreatePostParams {
+    fn into_request_body(self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+/// Parameters for reacting to a post.
+///
+/// Only used internally by `TwitchClient::create_reaction` and `TwitchClient::delete_reaction`.
+#[derive(Serialize, Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub(crate) struct ReactionParams {
+    emote_id: Option<String>,
+}
+
+impl ReactionParams {
+    pub(crate) fn new() -> ReactionParams {
+        ReactionParams::default()
+    }
+    pub(crate) fn with_emote_id(mut self, emote_id: &str) -> ReactionParams {
+        self.emote_id = Some(emote_id.to_owned());
+        self
+    }
+}
+
+impl QueryParams for ReactionParams {}
+
+/// Options that apply to every request of a client created with `TwitchClient::with_options`.
+///
+/// Intended for single calls that need special treatment, e.g. a longer timeout for large follower pages,
+/// an additional header or a different api version than the one the client was built with.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use twitch_client::ApiVersion;
+/// use twitch_client::param::RequestOptions;
+///
+/// let _options = RequestOptions::new()
+///         .with_timeout(Duration::from_secs(60))
+///         .with_header("X-Request-Id", "42")
+///         .with_api_version(ApiVersion::V5);
+/// ```
+#[derive(Default, Debug, Clone, Hash, Eq, PartialEq)]
+pub struct RequestOptions {
+    timeout: Option<Duration>,
+    headers: Vec<(String, String)>,
+    api_version: Option<ApiVersion>,
+}
+
+impl RequestOptions {
+    /// Constructs a new instance without any overrides.
+    pub fn new() -> RequestOptions {
+        RequestOptions::default()
+    }
+    /// Timeout of the whole request, overriding the read timeout of the transport.
+    ///
+    /// Ignored by transports that only support client wide timeouts (e.g. the hyper transport).
+    pub fn with_timeout(mut self, timeout: Duration) -> RequestOptions {
+        self.timeout = Some(timeout);
+        self
+    }
+    /// Additional header that is sent with every request.
+    ///
+    /// Replaces a default header with the same (case-insensitive) name, e.g. "Accept".
+    pub fn with_header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> RequestOptions {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+    /// Api version that is sent in the `Accept` header instead of the version of the client.
+    ///
+    /// Channels and users are then also resolved by id or name as required by this version.
+    pub fn with_api_version(mut self, api_version: ApiVersion) -> RequestOptions {
+        self.api_version = Some(api_version);
+        self
+    }
+
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+    pub(crate) fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+    pub(crate) fn api_version(&self) -> Option<ApiVersion> {
+        self.api_version
+    }
+}
+
+impl<'a> IntoQueryString for &'a [(&'a str, &'a str)] {
+    fn into_query_string(self) -> String {
+        encode_query_string(&self)
+    }
+}
+
+
+/// Serializes the params as url encoded query string with a leading `?`, or as empty string without any values.
+fn serialize_query_string<T: Serialize>(params: &T) -> String {
+    let pairs = query_string::to_pairs(params).expect("params are serializable as query string");
+    encode_query_string(&pairs)
+}
+
+/// Url encodes the name-value pairs as query string with a leading `?`, or as empty string without any pairs.
+fn encode_query_string<T: Serialize>(pairs: &T) -> String {
+    let query = serde_urlencoded::to_string(pairs).expect("pairs are serializable as query string");
+    if query.is_empty() {
+        query
+    } else {
+        format!("?{}", query)
+    }
+}
+
+/// Serializes a list as comma separated value (e.g. "channel1,channel2").
+fn serialize_comma_separated<T: ToString, S: Serializer>(values: &[T], serializer: S)
+        -> ::std::result::Result<S::Ok, S::Error> {
+    let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+    serializer.serialize_str(&values.join(","))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::{IntoQueryString, IntoRequestBody};
+    use model::id::{ChannelId, UserId};
+
+    #[test]
+    fn test_default_params_query_string_should_be_empty_to_use_twitch_default() {
+        let params = TopGamesParams::default();
+        assert_eq!(params.into_query_string(), "");
+    }
+
+    #[test]
+    fn test_one_param_should_only_set_one_query_value() {
+        let params = TopGamesParams::new()
+                .with_limit(10);
+        assert_eq!(params.into_query_string(), "?limit=10");
+    }
+
+    #[test]
+    fn test_multiple_params_should_concatenate_query_string_values_correctly() {
+        let params = TopGamesParams::new()
+                .with_offset(5)
+                .with_limit(10);
+        assert_eq!(params.into_query_string(), "?offset=5&limit=10");
+    }
+
+    #[test]
+    fn test_validate_should_check_limit_and_offset() {
+        assert!(TopGamesParams::default().validate().is_ok());
+        assert!(TopGamesParams::new().with_offset(MAX_OFFSET).with_limit(1).validate().is_ok());
+        assert_eq!(TopGamesParams::new().with_limit(101).validate(), Err(ParamError::InvalidLimit(101)));
+        assert_eq!(TopGamesParams::new().with_offset(901).validate(), Err(ParamError::InvalidOffset(901)));
+
+        assert!(FeaturedStreamsParams::new().with_limit(MAX_LIMIT).validate().is_ok());
+        assert_eq!(FeaturedStreamsParams::new().with_limit(0).validate(), Err(ParamError::InvalidLimit(0)));
+        assert_eq!(FeaturedStreamsParams::new().with_offset(1000).validate(), Err(ParamError::InvalidOffset(1000)));
+    }
+
+    #[test]
+    fn test_string_params_should_be_escaped_correctly() {
+        let params = StreamsParams::new()
+                .with_game("StarCraft II: Heart of the Swarm");
+        assert_eq!(params.into_query_string(), "?game=StarCraft+II%3A+Heart+of+the+Swarm");
+    }
+
+    #[test]
+    fn test_reserved_characters_should_be_escaped() {
+        let params = StreamsParams::new()
+                .with_game("C++ & C#")
+                .with_channel("a,b");
+        assert_eq!(params.into_query_string(), "?game=C%2B%2B+%26+C%23&channel=a%2Cb");
+    }
+
+    #[test]
+    fn test_empty_vec_query_string_should_be_empty_to_use_twitch_default() {
+        // StreamsParams contains channels with type Vec<String>
+        let params = StreamsParams::default();
+        assert_eq!(params.into_query_string(), "");
+    }
+
+    #[test]
     fn test_strings_in_vec_should_be_concatenated_and_escaped_correctly() {
         let params = StreamsParams::new()
                 .with_channel("StarCraft I")
                 .with_channel("StarCraft II");
-        assert_eq!(params.into_query_string(), "?channel=StarCraft%20I,StarCraft%20II");
+        assert_eq!(params.into_query_string(), "?channel=StarCraft+I%2CStarCraft+II");
     }
 
     #[test]
@@ -358,4 +1441,90 @@ mod tests {
                 .with_stream_type(StreamType::All);
         assert_eq!(params.into_query_string(), "?stream_type=all");
     }
+
+    #[test]
+    fn test_community_id_should_set_correctly() {
+        let params = StreamsParams::new()
+                .with_community_id("fd0eab99-832a-4d7e-8cc0-04d73deb2e54");
+        assert_eq!(params.into_query_string(), "?community_id=fd0eab99-832a-4d7e-8cc0-04d73deb2e54");
+    }
+
+    #[test]
+    fn test_languages_should_set_correctly() {
+        let params = StreamsParams::new()
+                .with_language("es-mx")
+                .with_broadcaster_language("en");
+        assert_eq!(params.into_query_string(), "?language=es-mx&broadcaster_language=en");
+    }
+
+    #[test]
+    fn test_languages_should_follow_other_streams_params() {
+        let params = StreamsParams::new()
+                .with_game("Dota 2")
+                .with_limit(10)
+                .with_language("fi");
+        assert_eq!(params.into_query_string(), "?game=Dota+2&limit=10&language=fi");
+    }
+
+    #[test]
+    fn test_containing_item_should_set_correctly() {
+        let params = CollectionsParams::new()
+                .with_containing_item("video:122138849");
+        assert_eq!(params.into_query_string(), "?containing_item=video%3A122138849");
+    }
+
+    #[test]
+    fn test_direction_should_set_correctly() {
+        let params = FollowsParams::new()
+                .with_direction(Direction::Asc);
+        assert_eq!(params.into_query_string(), "?direction=asc");
+    }
+
+    #[test]
+    fn test_clips_params_should_set_correctly() {
+        let params = ClipsParams::new()
+                .with_channel("dansgaming")
+                .with_channel("twitch")
+                .with_period(ClipPeriod::Day)
+                .with_trending(true)
+                .with_language("en");
+        assert_eq!(params.into_query_string(), "?channel=dansgaming%2Ctwitch&period=day&trending=true&language=en");
+    }
+
+    #[test]
+    fn test_emoticon_sets_should_be_concatenated_correctly() {
+        let params = EmoticonImagesParams::new()
+                .with_emoticon_set(19151)
+                .with_emoticon_set(33);
+        assert_eq!(params.into_query_string(), "?emotesets=19151%2C33");
+    }
+
+    #[test]
+    fn test_default_request_body_should_be_empty_to_not_change_anything() {
+        let params = UpdateChannelParams::default();
+        assert_eq!(params.into_request_body(), r#"{"channel":{}}"#);
+    }
+
+    #[test]
+    fn test_request_body_should_only_contain_set_values() {
+        let params = UpdateChannelParams::new()
+                .with_status("Playing cool new game!")
+                .with_delay(60);
+        assert_eq!(params.into_request_body(), r#"{"channel":{"status":"Playing cool new game!","delay":60}}"#);
+    }
+
+    #[test]
+    fn test_period_should_set_correctly() {
+        let params = TopVideosParams::new()
+                .with_period(Period::Month);
+        assert_eq!(params.into_query_string(), "?period=month");
+    }
+
+    #[test]
+    fn test_create_post_params_should_separate_query_string_and_request_body() {
+        let params = CreatePostParams::new("Kappa post")
+                .with_share(true);
+        assert_eq!(params.clone().into_query_string(), "?share=true");
+        assert_eq!(params.into_request_body(), r#"{"content":"Kappa post"}"#);
+    }
 }
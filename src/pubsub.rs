@@ -0,0 +1,495 @@
+//! Client for [Twitch PubSub].
+//!
+//! Twitch PubSub pushes events (e.g. cheered bits or new subscriptions) over a WebSocket connection
+//! for the topics the client listens to, instead of having to poll the REST API.
+//! The connection is kept alive with PING messages and transparently reestablished
+//! if Twitch requests a reconnect or the connection is lost, listening to the same topics again.
+//!
+//! Only available with the `pubsub` feature.
+//!
+//! [Twitch PubSub]: https://dev.twitch.tv/docs/pubsub
+
+use std::collections::VecDeque;
+use std::io::ErrorKind;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+use serde_json;
+use tungstenite::{self, WebSocket};
+use tungstenite::client::AutoStream;
+use tungstenite::stream::Stream;
+use url::Url;
+
+use error::{Result, Error};
+use model::pubsub::{ChannelPointsRedemption, BitsEvent, SubscriptionEvent, Whisper};
+
+
+const PUBSUB_URL: &'static str = "wss://pubsub-edge.twitch.tv";
+/// Twitch disconnects clients that do not send a PING at least every 5 minutes.
+const PING_INTERVAL_SECS: u64 = 4 * 60;
+/// Twitch recommends to reconnect if no PONG was received within 10 seconds after a PING.
+const PONG_TIMEOUT_SECS: u64 = 10;
+/// Interval in which reading is interrupted to send PING messages.
+const READ_TIMEOUT_SECS: u64 = 1;
+const MAX_RECONNECT_ATTEMPTS: u32 = 5;
+
+
+/// Topic that a `PubSubClient` can listen to.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum Topic {
+    /// Redemptions of channel points rewards in the channel with the id.
+    ///
+    /// Requires an OAuth token of the channel with the `channel:read:redemptions` scope.
+    ChannelPoints(String),
+    /// Bits cheered in the channel with the id.
+    ///
+    /// Requires an OAuth token of the channel with the `bits:read` scope.
+    Bits(String),
+    /// Subscriptions to the channel with the id.
+    ///
+    /// Requires an OAuth token of the channel with the `channel_subscriptions` scope.
+    Subscriptions(String),
+    /// Whispers received by the user with the id.
+    ///
+    /// Requires an OAuth token of the user with the `whispers:read` scope.
+    Whispers(String),
+}
+
+impl Topic {
+    /// Returns the name of the topic as used by Twitch (e.g. "channel-bits-events-v2.44322889").
+    pub fn name(&self) -> String {
+        match *self {
+            Topic::ChannelPoints(ref channel_id) => format!("channel-points-channel-v1.{}", channel_id),
+            Topic::Bits(ref channel_id) => format!("channel-bits-events-v2.{}", channel_id),
+            Topic::Subscriptions(ref channel_id) => format!("channel-subscribe-events-v1.{}", channel_id),
+            Topic::Whispers(ref user_id) => format!("whispers.{}", user_id),
+        }
+    }
+}
+
+/// Message that was pushed for a topic.
+///
+/// This list is intended to grow over time
+/// and it is not recommended to exhaustively match against it.
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// A channel points reward was redeemed (`Topic::ChannelPoints`).
+    ChannelPoints(ChannelPointsRedemption),
+    /// Bits were cheered (`Topic::Bits`).
+    Bits(BitsEvent),
+    /// A user subscribed (`Topic::Subscriptions`).
+    Subscription(SubscriptionEvent),
+    /// A whisper was received (`Topic::Whispers`).
+    Whisper(Whisper),
+    /// Message of a topic or type that is not modeled by this crate (or with an unexpected shape),
+    /// containing the raw JSON message.
+    Other {
+        /// Name of the topic.
+        topic: String,
+        /// Raw JSON message.
+        message: String,
+    },
+}
+
+
+/// Client that is connected to Twitch PubSub.
+///
+/// # Examples
+///
+/// ```no_run
+/// use twitch_client::pubsub::{PubSubClient, Topic, Message};
+///
+/// let mut pubsub_client = PubSubClient::connect().unwrap();
+/// pubsub_client.listen(&[Topic::Bits("44322889".to_owned())], "<YOUR_OAUTH_TOKEN>").unwrap();
+///
+/// for message in pubsub_client.into_receiver() {
+///     match message {
+///         Ok(Message::Bits(bits_event)) => println!("Bits: {}", bits_event.bits_used()),
+///         Ok(_) => {},
+///         Err(err) => println!("PubSub connection failed: {}", err),
+///     }
+/// }
+/// ```
+pub struct PubSubClient {
+    socket: WebSocket<AutoStream>,
+    listens: Vec<(Vec<Topic>, String)>,
+    pending_messages: VecDeque<Message>,
+    last_ping: Instant,
+    awaiting_pong_since: Option<Instant>,
+    next_nonce: u64,
+}
+
+impl PubSubClient {
+    /// Connects to Twitch PubSub.
+    pub fn connect() -> Result<PubSubClient> {
+        let socket = try!(open_socket());
+        Ok(PubSubClient {
+            socket: socket,
+            listens: Vec::new(),
+            pending_messages: VecDeque::new(),
+            last_ping: Instant::now(),
+            awaiting_pong_since: None,
+            next_nonce: 1,
+        })
+    }
+
+    /// Listens to the topics, authenticated with the OAuth token.
+    ///
+    /// Waits until Twitch confirmed the request and returns `Error::PubSubRejected` if it was rejected
+    /// (e.g. with "ERR_BADAUTH" if the token does not have the required scope).
+    pub fn listen<S: Into<String>>(&mut self, topics: &[Topic], auth_token: S) -> Result<()> {
+        let auth_token = auth_token.into();
+        try!(self.send_listen_request("LISTEN", topics, Some(auth_token.clone())));
+        self.listens.push((topics.to_vec(), auth_token));
+        Ok(())
+    }
+
+    /// Stops listening to the topics.
+    pub fn unlisten(&mut self, topics: &[Topic]) -> Result<()> {
+        try!(self.send_listen_request("UNLISTEN", topics, None));
+        for &mut (ref mut listened_topics, _) in self.listens.iter_mut() {
+            listened_topics.retain(|topic| !topics.contains(topic));
+        }
+        self.listens.retain(|&(ref listened_topics, _)| !listened_topics.is_empty());
+        Ok(())
+    }
+
+    /// Blocks until the next message was pushed for any of the topics.
+    ///
+    /// Sends the PING messages and reconnects if necessary while waiting.
+    pub fn next_message(&mut self) -> Result<Message> {
+        loop {
+            if let Some(message) = self.pending_messages.pop_front() {
+                return Ok(message);
+            }
+            match try!(self.read_frame()) {
+                Some(Frame::Message(message)) => return Ok(message),
+                Some(Frame::Reconnect) => try!(self.reconnect()),
+                _ => {},
+            }
+        }
+    }
+
+    /// Calls the callback with every pushed message until an error occurs.
+    pub fn run<F: FnMut(Message)>(&mut self, mut callback: F) -> Result<()> {
+        loop {
+            let message = try!(self.next_message());
+            callback(message);
+        }
+    }
+
+    /// Moves the client to a new thread that sends the pushed messages to the returned receiver.
+    ///
+    /// The thread stops after the first error, which is sent as last item,
+    /// or when the receiver is dropped.
+    pub fn into_receiver(mut self) -> Receiver<Result<Message>> {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            loop {
+                let result = self.next_message();
+                let failed = result.is_err();
+                if sender.send(result).is_err() || failed {
+                    break;
+                }
+            }
+        });
+        receiver
+    }
+
+    fn send_listen_request(&mut self, request_type: &'static str, topics: &[Topic], auth_token: Option<String>)
+            -> Result<()> {
+        let nonce = self.next_nonce.to_string();
+        self.next_nonce += 1;
+        let request = OutgoingFrame {
+            frame_type: request_type,
+            nonce: Some(nonce.clone()),
+            data: Some(ListenData {
+                topics: topics.iter().map(Topic::name).collect(),
+                auth_token: auth_token,
+            }),
+        };
+        try!(self.send_frame(&request));
+
+        loop {
+            match try!(self.read_frame()) {
+                Some(Frame::Response { nonce: ref response_nonce, ref error }) if *response_nonce == nonce => {
+                    return match *error {
+                        Some(ref error) if !error.is_empty() => Err(Error::PubSubRejected(error.clone())),
+                        _ => Ok(()),
+                    };
+                },
+                Some(Frame::Message(message)) => self.pending_messages.push_back(message),
+                Some(Frame::Reconnect) => {
+                    try!(self.reconnect());
+                    try!(self.send_frame(&request));
+                },
+                _ => {},
+            }
+        }
+    }
+
+    /// Reads the next frame, returning `None` if no frame was received within the read timeout.
+    fn read_frame(&mut self) -> Result<Option<Frame>> {
+        try!(self.keep_alive());
+        match self.socket.read_message() {
+            Ok(tungstenite::Message::Text(text)) => {
+                let frame = try!(parse_frame(&text));
+                if let Some(Frame::Pong) = frame {
+                    self.awaiting_pong_since = None;
+                }
+                Ok(frame)
+            },
+            Ok(_) => Ok(None),
+            Err(tungstenite::Error::Io(ref error))
+                    if error.kind() == ErrorKind::WouldBlock || error.kind() == ErrorKind::TimedOut => Ok(None),
+            Err(tungstenite::Error::ConnectionClosed) | Err(tungstenite::Error::AlreadyClosed) => {
+                try!(self.reconnect());
+                Ok(None)
+            },
+            Err(error) => Err(Error::from(error)),
+        }
+    }
+
+    fn keep_alive(&mut self) -> Result<()> {
+        match self.awaiting_pong_since {
+            Some(ping_sent_at) if ping_sent_at.elapsed() > Duration::from_secs(PONG_TIMEOUT_SECS) => self.reconnect(),
+            Some(_) => Ok(()),
+            None if self.last_ping.elapsed() >= Duration::from_secs(PING_INTERVAL_SECS) => {
+                try!(self.send_frame(&OutgoingFrame { frame_type: "PING", nonce: None, data: None }));
+                self.last_ping = Instant::now();
+                self.awaiting_pong_since = Some(self.last_ping);
+                Ok(())
+            },
+            None => Ok(()),
+        }
+    }
+
+    /// Opens a new connection with exponential backoff and listens to the same topics again.
+    fn reconnect(&mut self) -> Result<()> {
+        let mut attempt = 1;
+        self.socket = loop {
+            match open_socket() {
+                Ok(socket) => break socket,
+                Err(ref error) if attempt < MAX_RECONNECT_ATTEMPTS && error.is_retryable() => {
+                    thread::sleep(Duration::from_secs(1 << attempt));
+                    attempt += 1;
+                },
+                Err(error) => return Err(error),
+            }
+        };
+        self.last_ping = Instant::now();
+        self.awaiting_pong_since = None;
+
+        let listens = self.listens.clone();
+        for (topics, auth_token) in listens {
+            try!(self.send_listen_request("LISTEN", &topics, Some(auth_token)));
+        }
+        Ok(())
+    }
+
+    fn send_frame(&mut self, frame: &OutgoingFrame) -> Result<()> {
+        let text = try!(serde_json::to_string(frame));
+        try!(self.socket.write_message(tungstenite::Message::Text(text)));
+        Ok(())
+    }
+}
+
+fn open_socket() -> Result<WebSocket<AutoStream>> {
+    let (mut socket, _) = try!(tungstenite::connect(Url::parse(PUBSUB_URL).unwrap()));
+    let read_timeout = Some(Duration::from_secs(READ_TIMEOUT_SECS));
+    match *socket.get_mut() {
+        Stream::Plain(ref stream) => try!(stream.set_read_timeout(read_timeout)),
+        Stream::Tls(ref stream) => try!(stream.get_ref().set_read_timeout(read_timeout)),
+    }
+    Ok(socket)
+}
+
+
+#[derive(Serialize)]
+struct OutgoingFrame {
+    #[serde(rename="type")]
+    frame_type: &'static str,
+    #[serde(skip_serializing_if="Option::is_none")]
+    nonce: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    data: Option<ListenData>,
+}
+
+#[derive(Serialize)]
+struct ListenData {
+    topics: Vec<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    auth_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct IncomingFrame {
+    #[serde(rename="type")]
+    frame_type: String,
+    nonce: Option<String>,
+    error: Option<String>,
+    data: Option<MessageData>,
+}
+
+#[derive(Deserialize)]
+struct MessageData {
+    topic: String,
+    message: String,
+}
+
+#[derive(Debug)]
+enum Frame {
+    Pong,
+    Reconnect,
+    Response {
+        nonce: String,
+        error: Option<String>,
+    },
+    Message(Message),
+}
+
+/// Parses a frame sent by Twitch, returning `None` for unknown frame types.
+fn parse_frame(text: &str) -> Result<Option<Frame>> {
+    let frame: IncomingFrame = try!(serde_json::from_str(text));
+    let frame = match frame.frame_type.as_str() {
+        "PONG" => Frame::Pong,
+        "RECONNECT" => Frame::Reconnect,
+        "RESPONSE" => Frame::Response {
+            nonce: frame.nonce.unwrap_or_default(),
+            error: frame.error,
+        },
+        "MESSAGE" => match frame.data {
+            Some(data) => Frame::Message(parse_message(data.topic, data.message)),
+            None => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    Ok(Some(frame))
+}
+
+/// Parses the message of a known topic, falling back to `Message::Other` for unknown topics and types
+/// and for messages with an unexpected shape, so that a single message does not stop the subscription.
+fn parse_message(topic: String, message: String) -> Message {
+    #[derive(Deserialize)]
+    struct TypedMessage<T> {
+        #[serde(rename="type")]
+        message_type: Option<String>,
+        data: Option<T>,
+    }
+    /// Whisper messages contain the whisper as JSON encoded string in `data`, so only `data_object` is read.
+    #[derive(Deserialize)]
+    struct WhisperMessage {
+        #[serde(rename="type")]
+        message_type: Option<String>,
+        data_object: Option<Whisper>,
+    }
+    #[derive(Deserialize)]
+    struct RedemptionData {
+        redemption: ChannelPointsRedemption,
+    }
+
+    let topic_prefix = topic.split('.').next().unwrap_or("").to_owned();
+    let parsed = match topic_prefix.as_str() {
+        "channel-points-channel-v1" => {
+            let typed: Option<TypedMessage<RedemptionData>> = serde_json::from_str(&message).ok();
+            match typed.map(|typed| (typed.message_type, typed.data)) {
+                Some((Some(ref message_type), Some(data))) if message_type == "reward-redeemed" =>
+                    Some(Message::ChannelPoints(data.redemption)),
+                _ => None,
+            }
+        },
+        "channel-bits-events-v2" => {
+            let typed: Option<TypedMessage<BitsEvent>> = serde_json::from_str(&message).ok();
+            typed.and_then(|typed| typed.data).map(Message::Bits)
+        },
+        "channel-subscribe-events-v1" => serde_json::from_str(&message).ok().map(Message::Subscription),
+        "whispers" => {
+            let typed: Option<WhisperMessage> = serde_json::from_str(&message).ok();
+            match typed.map(|typed| (typed.message_type, typed.data_object)) {
+                Some((Some(ref message_type), Some(whisper))) if message_type == "whisper_received" =>
+                    Some(Message::Whisper(whisper)),
+                _ => None,
+            }
+        },
+        _ => None,
+    };
+    parsed.unwrap_or_else(|| Message::Other { topic: topic, message: message })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topic_name() {
+        assert_eq!(Topic::Bits("44322889".to_owned()).name(), "channel-bits-events-v2.44322889");
+        assert_eq!(Topic::Whispers("129454141".to_owned()).name(), "whispers.129454141");
+    }
+
+    #[test]
+    fn test_parse_response_frame() {
+        match parse_frame(r#"{"type":"RESPONSE","nonce":"1","error":"ERR_BADAUTH"}"#).unwrap() {
+            Some(Frame::Response { nonce, error }) => {
+                assert_eq!(nonce, "1");
+                assert_eq!(error, Some("ERR_BADAUTH".to_owned()));
+            },
+            frame => panic!("expecting response frame, but got: {:?}", frame),
+        }
+        assert!(parse_frame(r#"{"type":"UNKNOWN"}"#).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_parse_bits_message() {
+        let bits_message = r#"{"data":{"user_name":"test_user1","channel_name":"test_channel","user_id":"129454141",
+            "channel_id":"44322889","time":"2017-02-09T13:23:58.168Z","chat_message":"cheer10000 New badge hype!",
+            "bits_used":10000,"total_bits_used":25000,"context":"cheer"},"version":"1.0","message_type":"bits_event"}"#;
+        let frame = format!(r#"{{"type":"MESSAGE","data":{{"topic":"channel-bits-events-v2.44322889","message":{}}}}}"#,
+                serde_json::to_string(bits_message).unwrap());
+        match parse_frame(&frame).unwrap() {
+            Some(Frame::Message(Message::Bits(bits_event))) => assert_eq!(bits_event.bits_used(), 10000),
+            frame => panic!("expecting bits message, but got: {:?}", frame),
+        }
+    }
+
+    #[test]
+    fn test_parse_whisper_message() {
+        let whisper = r#"{"id":41,"thread_id":"129454141_44322889","body":"hello","sent_ts":1479160009,
+            "from_id":39141793,"tags":{"login":"dallas","display_name":"dallas","color":"#8A2BE2","emotes":[],
+            "badges":[{"id":"staff","version":"1"}]},"recipient":{"id":129454141,"username":"dallasnchains",
+            "display_name":"dallasnchains","color":"","badges":[]},"nonce":"6GVBTfBXNj7d71BULYKjpiKapegDI1"}"#;
+        // Twitch sends the whisper twice: as JSON encoded string in `data` and as object in `data_object`.
+        let whisper_message = format!(r#"{{"type":"whisper_received","data":{},"data_object":{}}}"#,
+                serde_json::to_string(whisper).unwrap(), whisper);
+        let frame = format!(r#"{{"type":"MESSAGE","data":{{"topic":"whispers.129454141","message":{}}}}}"#,
+                serde_json::to_string(&whisper_message).unwrap());
+        match parse_frame(&frame).unwrap() {
+            Some(Frame::Message(Message::Whisper(whisper))) => {
+                assert_eq!(whisper.id(), 41);
+                assert_eq!(whisper.body(), "hello");
+            },
+            frame => panic!("expecting whisper message, but got: {:?}", frame),
+        }
+    }
+
+    #[test]
+    fn test_parse_unknown_message() {
+        let message = parse_message("video-playback.test_channel".to_owned(), "{}".to_owned());
+        match message {
+            Message::Other { topic, .. } => assert_eq!(topic, "video-playback.test_channel"),
+            message => panic!("expecting other message, but got: {:?}", message),
+        }
+    }
+
+    #[test]
+    fn test_parse_malformed_message_of_known_topic() {
+        let message = parse_message("channel-bits-events-v2.44322889".to_owned(), r#"{"data":42}"#.to_owned());
+        match message {
+            Message::Other { topic, message } => {
+                assert_eq!(topic, "channel-bits-events-v2.44322889");
+                assert_eq!(message, r#"{"data":42}"#);
+            },
+            message => panic!("expecting other message, but got: {:?}", message),
+        }
+    }
+}
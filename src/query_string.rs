@@ -0,0 +1,283 @@
+//! Serialization of the params structs into the name-value pairs of a query string.
+//!
+//! `serde_urlencoded` rejects lists in struct fields, but the Helix API expects them as repeated names
+//! (e.g. "id=1&id=2"), so the params are first serialized into pairs, which `serde_urlencoded` then encodes.
+//! Missing (`None`) values are left out.
+
+use std::fmt::Display;
+
+use serde::ser::{Error as SerError, Impossible, Serialize, SerializeSeq, SerializeStruct, Serializer};
+use serde_urlencoded::ser::Error;
+
+
+/// Serializes the fields of the params struct into name-value pairs in the order of the fields.
+pub(crate) fn to_pairs<T: Serialize + ?Sized>(params: &T) -> Result<Vec<(String, String)>, Error> {
+    let mut pairs = Vec::new();
+    try!(params.serialize(ParamsSerializer { pairs: &mut pairs }));
+    Ok(pairs)
+}
+
+
+macro_rules! unsupported {
+    ($message:expr; $($method:ident($ty:ty),)*) => {
+        $(
+            fn $method(self, _value: $ty) -> Result<(), Error> {
+                Err(Error::custom($message))
+            }
+        )*
+    }
+}
+
+macro_rules! unsupported_compound {
+    ($message:expr) => {
+        fn serialize_unit(self) -> Result<(), Error> {
+            Err(Error::custom($message))
+        }
+        fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+            Err(Error::custom($message))
+        }
+        fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _name: &'static str, _variant_index: u32,
+                                                             _variant: &'static str, _value: &T) -> Result<(), Error> {
+            Err(Error::custom($message))
+        }
+        fn serialize_tuple(self, _len: usize) -> Result<Impossible<(), Error>, Error> {
+            Err(Error::custom($message))
+        }
+        fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Impossible<(), Error>, Error> {
+            Err(Error::custom($message))
+        }
+        fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str,
+                                   _len: usize) -> Result<Impossible<(), Error>, Error> {
+            Err(Error::custom($message))
+        }
+        fn serialize_map(self, _len: Option<usize>) -> Result<Impossible<(), Error>, Error> {
+            Err(Error::custom($message))
+        }
+        fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str,
+                                    _len: usize) -> Result<Impossible<(), Error>, Error> {
+            Err(Error::custom($message))
+        }
+    }
+}
+
+
+/// Serializer of the params struct itself.
+struct ParamsSerializer<'a> {
+    pairs: &'a mut Vec<(String, String)>,
+}
+
+impl<'a> Serializer for ParamsSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Impossible<(), Error>;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Impossible<(), Error>;
+    type SerializeStruct = FieldsSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    unsupported! { "params must be a struct";
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+    }
+    unsupported_compound!("params must be a struct");
+
+    fn serialize_none(self) -> Result<(), Error> {
+        Err(Error::custom("params must be a struct"))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str)
+            -> Result<(), Error> {
+        Err(Error::custom("params must be a struct"))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Impossible<(), Error>, Error> {
+        Err(Error::custom("params must be a struct"))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<FieldsSerializer<'a>, Error> {
+        Ok(FieldsSerializer { pairs: self.pairs })
+    }
+}
+
+/// Serializer of the fields of the params struct.
+struct FieldsSerializer<'a> {
+    pairs: &'a mut Vec<(String, String)>,
+}
+
+impl<'a> SerializeStruct for FieldsSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, name: &'static str, value: &T) -> Result<(), Error> {
+        value.serialize(ValueSerializer { name: name, pairs: &mut *self.pairs })
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Serializer of a field value, which adds a pair for a single value and for each value of a list.
+struct ValueSerializer<'a> {
+    name: &'static str,
+    pairs: &'a mut Vec<(String, String)>,
+}
+
+impl<'a> ValueSerializer<'a> {
+    fn push<V: Display>(self, value: V) -> Result<(), Error> {
+        self.pairs.push((self.name.to_owned(), value.to_string()));
+        Ok(())
+    }
+}
+
+macro_rules! serialize_display {
+    ($($method:ident($ty:ty),)*) => {
+        $(
+            fn $method(self, value: $ty) -> Result<(), Error> {
+                self.push(value)
+            }
+        )*
+    }
+}
+
+impl<'a> Serializer for ValueSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = ListSerializer<'a>;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Impossible<(), Error>;
+    type SerializeStruct = Impossible<(), Error>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    serialize_display! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+    }
+    unsupported! { "unsupported query string value";
+        serialize_bytes(&[u8]),
+    }
+    unsupported_compound!("unsupported query string value");
+
+    fn serialize_none(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str)
+            -> Result<(), Error> {
+        self.push(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<ListSerializer<'a>, Error> {
+        Ok(ListSerializer { name: self.name, pairs: self.pairs })
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Impossible<(), Error>, Error> {
+        Err(Error::custom("unsupported query string value"))
+    }
+}
+
+/// Serializer of a list, which repeats the name for each value.
+struct ListSerializer<'a> {
+    name: &'static str,
+    pairs: &'a mut Vec<(String, String)>,
+}
+
+impl<'a> SerializeSeq for ListSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(ValueSerializer { name: self.name, pairs: &mut *self.pairs })
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    #[serde(rename_all="lowercase")]
+    enum Sort {
+        Views,
+    }
+
+    #[derive(Serialize)]
+    struct Params {
+        #[serde(rename="id")]
+        ids: Vec<String>,
+        first: Option<u8>,
+        after: Option<String>,
+        sort: Option<Sort>,
+        live: bool,
+    }
+
+    #[test]
+    fn test_should_repeat_names_of_lists_and_skip_missing_values() {
+        let params = Params {
+            ids: vec!["a".to_owned(), "b".to_owned()],
+            first: Some(10),
+            after: None,
+            sort: Some(Sort::Views),
+            live: true,
+        };
+        let expected: Vec<(String, String)> = vec![
+            ("id", "a"), ("id", "b"), ("first", "10"), ("sort", "views"), ("live", "true"),
+        ].into_iter()
+                .map(|(name, value)| (name.to_owned(), value.to_owned()))
+                .collect();
+        assert_eq!(to_pairs(&params).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_should_reject_params_that_are_no_struct() {
+        assert!(to_pairs(&42).is_err());
+    }
+}
@@ -0,0 +1,161 @@
+//! Twitch rate limits.
+//!
+//! Twitch limits the number of requests per client in a bucket that is refilled over time
+//! and reports the state of the bucket in the `Ratelimit-*` response headers.
+//! Clients that share a Client ID can additionally throttle themselves with a `Throttle`
+//! to stay below the limit proactively.
+
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use transport::Response;
+
+
+/// State of the rate limit bucket as reported by the last response.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct RateLimitStatus {
+    limit: u32,
+    remaining: u32,
+    reset: u64,
+}
+
+impl RateLimitStatus {
+    /// Parses the `Ratelimit-Limit`, `Ratelimit-Remaining` and `Ratelimit-Reset` headers of the response.
+    ///
+    /// Returns `None` if any of the headers is missing or invalid.
+    pub(crate) fn from_response(response: &Response) -> Option<RateLimitStatus> {
+        RateLimitStatus::from_headers(response.headers())
+    }
+
+    /// Parses the `Ratelimit-Limit`, `Ratelimit-Remaining` and `Ratelimit-Reset` headers.
+    pub(crate) fn from_headers(headers: &[(String, String)]) -> Option<RateLimitStatus> {
+        let header = |name: &str| {
+            headers.iter()
+                    .find(|&&(ref header_name, _)| header_name.eq_ignore_ascii_case(name))
+                    .map(|&(_, ref value)| value.trim())
+        };
+        let limit = header("Ratelimit-Limit").and_then(|value| value.parse().ok());
+        let remaining = header("Ratelimit-Remaining").and_then(|value| value.parse().ok());
+        let reset = header("Ratelimit-Reset").and_then(|value| value.parse().ok());
+        match (limit, remaining, reset) {
+            (Some(limit), Some(remaining), Some(reset)) => Some(RateLimitStatus {
+                limit: limit,
+                remaining: remaining,
+                reset: reset,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Example value: 800
+    ///
+    /// Maximum number of requests in the bucket.
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+    /// Example value: 799
+    ///
+    /// Number of requests that can be sent until the bucket is reset.
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+    /// Example value: 1529183210
+    ///
+    /// Unix epoch timestamp in seconds when the bucket is reset.
+    pub fn reset(&self) -> u64 {
+        self.reset
+    }
+    /// Whether no requests are remaining in the bucket.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+    /// Returns the duration until the bucket is reset, which is zero if it already was reset.
+    pub fn duration_until_reset(&self) -> Duration {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or(Duration::from_secs(0));
+        Duration::from_secs(self.reset).checked_sub(now).unwrap_or(Duration::from_secs(0))
+    }
+}
+
+/// Leaky bucket that lets requests pass at a constant rate of at most `max_requests_per_minute`.
+///
+/// Requests beyond the budget are delayed in the order in which they arrive.
+#[derive(Debug)]
+#[cfg_attr(not(feature = "blocking"), allow(dead_code))]
+pub(crate) struct Throttle {
+    interval: Duration,
+    next_slot: Mutex<Option<Instant>>,
+}
+
+#[cfg_attr(not(feature = "blocking"), allow(dead_code))]
+impl Throttle {
+    /// Creates a throttle that lets a request pass every `60s / max_requests_per_minute`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_requests_per_minute` is zero.
+    pub(crate) fn new(max_requests_per_minute: u32) -> Throttle {
+        assert!(max_requests_per_minute > 0, "max_requests_per_minute must be greater than zero");
+        let interval_nanos = 60_000_000_000 / u64::from(max_requests_per_minute);
+        Throttle {
+            interval: Duration::new(interval_nanos / 1_000_000_000, (interval_nanos % 1_000_000_000) as u32),
+            next_slot: Mutex::new(None),
+        }
+    }
+
+    /// Blocks the current thread until the request may be sent.
+    pub(crate) fn acquire(&self) {
+        let delay = self.reserve(Instant::now());
+        if delay > Duration::from_secs(0) {
+            thread::sleep(delay);
+        }
+    }
+
+    /// Reserves the next free slot and returns the duration from `now` until it starts.
+    fn reserve(&self, now: Instant) -> Duration {
+        let mut next_slot = self.next_slot.lock().unwrap();
+        let slot = match *next_slot {
+            Some(next_slot) if next_slot > now => next_slot,
+            _ => now,
+        };
+        *next_slot = Some(slot + self.interval);
+        slot - now
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_response() {
+        let headers = vec![
+            ("ratelimit-limit".to_owned(), "800".to_owned()),
+            ("ratelimit-remaining".to_owned(), "0".to_owned()),
+            ("ratelimit-reset".to_owned(), "1529183210".to_owned()),
+        ];
+        let response = Response::new(200, headers, String::new());
+
+        let rate_limit_status = RateLimitStatus::from_response(&response).unwrap();
+        assert_eq!(rate_limit_status.limit(), 800);
+        assert!(rate_limit_status.is_exhausted());
+        assert_eq!(rate_limit_status.duration_until_reset(), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn test_from_response_without_headers() {
+        let response = Response::new(200, Vec::new(), String::new());
+        assert_eq!(RateLimitStatus::from_response(&response), None);
+    }
+
+    #[test]
+    fn test_throttle_should_delay_requests_beyond_budget() {
+        let throttle = Throttle::new(120);
+        let now = Instant::now();
+        assert_eq!(throttle.reserve(now), Duration::from_secs(0));
+        assert_eq!(throttle.reserve(now), Duration::from_millis(500));
+        assert_eq!(throttle.reserve(now + Duration::from_millis(200)), Duration::from_millis(800));
+        assert_eq!(throttle.reserve(now + Duration::from_secs(10)), Duration::from_secs(0));
+    }
+}
@@ -0,0 +1,100 @@
+//! Resolution of login names to user ids.
+//!
+//! Twitch API version 5 identifies channels and users by their ids instead of their names.
+//! With a `UserIdResolver` the client looks up the ids of the names once and caches them,
+//! so that code passing names keeps working with `ApiVersion::V5`.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use error::Result;
+use model::id::UserId;
+
+
+/// Cache of login names to user ids, which are looked up with `TwitchClient::users_by_login` if unknown.
+///
+/// The ids of users never change, so the mappings do not expire.
+/// Login names are case-insensitive.
+///
+/// # Examples
+///
+/// ```
+/// use twitch_client::*;
+/// use twitch_client::model::id::UserId;
+/// use twitch_client::resolver::UserIdResolver;
+///
+/// let user_id_resolver = UserIdResolver::new();
+/// user_id_resolver.insert("test_channel", UserId::from(12345));
+/// let twitch_client = TwitchClientBuilder::new("<YOUR_TWITCH_CLIENT_ID>")
+///         .with_api_version(ApiVersion::V5)
+///         .with_user_id_resolver(user_id_resolver)
+///         .build()
+///         .unwrap();
+/// ```
+#[derive(Debug, Default)]
+pub struct UserIdResolver {
+    ids: Mutex<HashMap<String, UserId>>,
+}
+
+impl UserIdResolver {
+    /// Creates a resolver without any known ids.
+    pub fn new() -> UserIdResolver {
+        UserIdResolver::default()
+    }
+    /// Adds the id of the login name, e.g. to avoid the lookup of well-known channels.
+    pub fn insert(&self, name: &str, id: UserId) {
+        self.ids.lock().unwrap().insert(name.to_lowercase(), id);
+    }
+    /// Returns the known id of the login name without looking it up.
+    pub fn get(&self, name: &str) -> Option<UserId> {
+        self.ids.lock().unwrap().get(&name.to_lowercase()).cloned()
+    }
+    /// Number of known ids.
+    pub fn len(&self) -> usize {
+        self.ids.lock().unwrap().len()
+    }
+    /// Whether no ids are known.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Forgets all known ids.
+    pub fn clear(&self) {
+        self.ids.lock().unwrap().clear();
+    }
+
+    /// Returns the id of the login name, looking it up if it is not known yet.
+    ///
+    /// Returns `None` if no user has the login name.
+    pub(crate) fn resolve<F>(&self, name: &str, lookup: F) -> Result<Option<UserId>>
+            where F: FnOnce(&str) -> Result<Option<UserId>> {
+        if let Some(id) = self.get(name) {
+            return Ok(Some(id));
+        }
+        let id = try!(lookup(name));
+        if let Some(id) = id {
+            self.insert(name, id);
+        }
+        Ok(id)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_look_up_unknown_names_once() {
+        let user_id_resolver = UserIdResolver::new();
+        let resolved = user_id_resolver.resolve("Test_User1", |name| {
+            assert_eq!(name, "Test_User1");
+            Ok(Some(UserId::from(22761313)))
+        });
+        assert_eq!(resolved.unwrap(), Some(UserId::from(22761313)));
+
+        let resolved = user_id_resolver.resolve("test_user1", |_| panic!("expecting the cached id"));
+        assert_eq!(resolved.unwrap(), Some(UserId::from(22761313)));
+        assert_eq!(user_id_resolver.resolve("unknown_user", |_| Ok(None)).unwrap(), None);
+        assert_eq!(user_id_resolver.len(), 1);
+    }
+}
@@ -0,0 +1,110 @@
+//! Metadata of Twitch responses.
+//!
+//! Twitch support asks for the request id of a response when investigating a problem,
+//! which is only sent as response header and therefore not part of the response models.
+
+#[cfg(feature = "blocking")]
+use std::cell::RefCell;
+
+use rate_limit::RateLimitStatus;
+
+
+/// Headers that contain the id of the request assigned by Twitch, in the order of preference.
+const REQUEST_ID_HEADERS: &'static [&'static str] = &["Twitch-Trace-Id", "X-Request-Id"];
+
+#[cfg(feature = "blocking")]
+thread_local! {
+    static LAST_RESPONSE_META: RefCell<Option<ResponseMeta>> = RefCell::new(None);
+}
+
+
+/// Metadata of a response that is only sent in the response headers.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct ResponseMeta {
+    status: u16,
+    request_id: Option<String>,
+    date: Option<String>,
+    rate_limit_status: Option<RateLimitStatus>,
+}
+
+impl ResponseMeta {
+    /// Extracts the metadata from the status and headers of a response.
+    pub(crate) fn from_headers(status: u16, headers: &[(String, String)]) -> ResponseMeta {
+        let header = |name: &str| {
+            headers.iter()
+                    .find(|&&(ref header_name, _)| header_name.eq_ignore_ascii_case(name))
+                    .map(|&(_, ref value)| value.trim().to_owned())
+        };
+        ResponseMeta {
+            status: status,
+            request_id: REQUEST_ID_HEADERS.iter().filter_map(|name| header(name)).next(),
+            date: header("Date"),
+            rate_limit_status: RateLimitStatus::from_headers(headers),
+        }
+    }
+
+    /// Example value: 200
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+    /// Example value: "3a5b4e2d1c0f9e8d7c6b5a4f3e2d1c0b"
+    ///
+    /// Id of the request assigned by Twitch (`Twitch-Trace-Id` header), which is useful when contacting Twitch support.
+    pub fn request_id(&self) -> &Option<String> {
+        &self.request_id
+    }
+    /// Example value: "Tue, 16 Oct 2018 20:00:00 GMT"
+    ///
+    /// Date of the response in the format of the `Date` header.
+    pub fn date(&self) -> &Option<String> {
+        &self.date
+    }
+    /// State of the rate limit bucket after the request, if the rate limit headers were sent.
+    pub fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.rate_limit_status
+    }
+}
+
+/// Returns the metadata of the last response that was received on the current thread.
+#[cfg(feature = "blocking")]
+pub(crate) fn last_response_meta() -> Option<ResponseMeta> {
+    LAST_RESPONSE_META.with(|meta| meta.borrow().clone())
+}
+
+/// Remembers the metadata as the last response that was received on the current thread.
+#[cfg(feature = "blocking")]
+pub(crate) fn set_last_response_meta(response_meta: ResponseMeta) {
+    LAST_RESPONSE_META.with(|meta| *meta.borrow_mut() = Some(response_meta));
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_headers() {
+        let headers = vec![
+            ("date".to_owned(), "Tue, 16 Oct 2018 20:00:00 GMT".to_owned()),
+            ("x-request-id".to_owned(), "fallback".to_owned()),
+            ("twitch-trace-id".to_owned(), "3a5b4e2d1c0f9e8d7c6b5a4f3e2d1c0b".to_owned()),
+            ("ratelimit-limit".to_owned(), "800".to_owned()),
+            ("ratelimit-remaining".to_owned(), "799".to_owned()),
+            ("ratelimit-reset".to_owned(), "1529183210".to_owned()),
+        ];
+
+        let response_meta = ResponseMeta::from_headers(200, &headers);
+        assert_eq!(response_meta.status(), 200);
+        assert_eq!(response_meta.request_id(), &Some("3a5b4e2d1c0f9e8d7c6b5a4f3e2d1c0b".to_owned()));
+        assert_eq!(response_meta.date(), &Some("Tue, 16 Oct 2018 20:00:00 GMT".to_owned()));
+        assert_eq!(response_meta.rate_limit_status().map(|status| status.remaining()), Some(799));
+    }
+
+    #[test]
+    fn test_from_headers_without_headers() {
+        let response_meta = ResponseMeta::from_headers(404, &[]);
+        assert_eq!(response_meta.request_id(), &None);
+        assert_eq!(response_meta.date(), &None);
+        assert_eq!(response_meta.rate_limit_status(), None);
+    }
+}
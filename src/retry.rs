@@ -0,0 +1,115 @@
+//! Retry of failed requests.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+
+/// Policy for retrying idempotent requests (GET) that failed with a server error (5xx) or an io error,
+/// waiting with an exponential backoff between the attempts.
+///
+/// The delay before the n-th retry is `base_delay * 2^(n-1)` plus a random duration up to `jitter`.
+/// Requests of any method that were rejected with `429 Too Many Requests` are retried
+/// after the duration requested by Twitch instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use twitch_client::retry::RetryPolicy;
+///
+/// let retry_policy = RetryPolicy::new(5)
+///         .with_base_delay(Duration::from_millis(200))
+///         .with_jitter(Duration::from_millis(50));
+/// assert_eq!(retry_policy.max_attempts(), 5);
+/// ```
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    jitter: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a policy with the maximum number of attempts (including the first one),
+    /// a base delay of 100 milliseconds and a jitter of 100 milliseconds.
+    pub fn new(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: max_attempts,
+            base_delay: Duration::from_millis(100),
+            jitter: Duration::from_millis(100),
+        }
+    }
+    /// Sets the delay before the first retry, which is doubled for each further retry.
+    pub fn with_base_delay(mut self, base_delay: Duration) -> RetryPolicy {
+        self.base_delay = base_delay;
+        self
+    }
+    /// Sets the maximum random duration that is added to each delay,
+    /// to avoid that many clients retry at the same time.
+    pub fn with_jitter(mut self, jitter: Duration) -> RetryPolicy {
+        self.jitter = jitter;
+        self
+    }
+    /// Maximum number of attempts including the first one.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+    /// Delay before the first retry.
+    pub fn base_delay(&self) -> Duration {
+        self.base_delay
+    }
+    /// Maximum random duration that is added to each delay.
+    pub fn jitter(&self) -> Duration {
+        self.jitter
+    }
+
+    /// Returns the delay before the retry after the failed attempt (starting with 1).
+    pub(crate) fn delay(&self, failed_attempt: u32) -> Duration {
+        let backoff = self.base_delay * 2u32.saturating_pow(failed_attempt.saturating_sub(1));
+        backoff + random_duration(self.jitter)
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Policy with 3 attempts, a base delay of 100 milliseconds and a jitter of 100 milliseconds.
+    fn default() -> RetryPolicy {
+        RetryPolicy::new(3)
+    }
+}
+
+/// Good enough randomness for spreading retries without depending on a random number generator.
+fn random_duration(max: Duration) -> Duration {
+    let max_nanos = max.as_secs() * 1_000_000_000 + max.subsec_nanos() as u64;
+    if max_nanos == 0 {
+        return Duration::from_secs(0);
+    }
+    let seed = SystemTime::now().duration_since(UNIX_EPOCH)
+            .map(|duration| duration.subsec_nanos() as u64)
+            .unwrap_or(0);
+    let nanos = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407) % max_nanos;
+    Duration::new(nanos / 1_000_000_000, (nanos % 1_000_000_000) as u32)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_should_double_per_attempt() {
+        let retry_policy = RetryPolicy::new(4)
+                .with_base_delay(Duration::from_millis(100))
+                .with_jitter(Duration::from_secs(0));
+        assert_eq!(retry_policy.delay(1), Duration::from_millis(100));
+        assert_eq!(retry_policy.delay(2), Duration::from_millis(200));
+        assert_eq!(retry_policy.delay(3), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn test_delay_should_not_exceed_jitter() {
+        let retry_policy = RetryPolicy::new(2)
+                .with_base_delay(Duration::from_millis(100))
+                .with_jitter(Duration::from_millis(50));
+        let delay = retry_policy.delay(1);
+        assert!(delay >= Duration::from_millis(100) && delay < Duration::from_millis(150));
+    }
+}
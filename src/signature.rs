@@ -0,0 +1,53 @@
+//! Verification of the HMAC signatures that Twitch sends with EventSub and Webhooks hub notifications.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+
+/// Verifies a signature in the `sha256=<hex digest>` format in constant time.
+pub(crate) fn verify_hmac_sha256(secret: &[u8], message: &[u8], signature: &str) -> bool {
+    let expected = match decode_hex(signature.trim_left_matches("sha256=")) {
+        Some(expected) => expected,
+        None => return false,
+    };
+    let mut mac = match Hmac::<Sha256>::new_varkey(secret) {
+        Ok(mac) => mac,
+        Err(_) => return false,
+    };
+    mac.input(message);
+    mac.verify(&expected).is_ok()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    let mut bytes = Vec::with_capacity(hex.len() / 2);
+    for i in (0..hex.len()).step_by(2) {
+        match hex.get(i..i + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()) {
+            Some(byte) => bytes.push(byte),
+            None => return None,
+        }
+    }
+    Some(bytes)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIGNATURE: &'static str = "sha256=8b5f48702995c1598c573db1e21866a9b825d4a794d169d7060a03605796360b";
+
+    #[test]
+    fn test_should_accept_valid_signature() {
+        assert!(verify_hmac_sha256(b"secret", b"message", SIGNATURE));
+    }
+
+    #[test]
+    fn test_should_reject_invalid_signature() {
+        assert!(!verify_hmac_sha256(b"other secret", b"message", SIGNATURE));
+        assert!(!verify_hmac_sha256(b"secret", b"other message", SIGNATURE));
+        assert!(!verify_hmac_sha256(b"secret", b"message", "sha256=not hex"));
+    }
+}
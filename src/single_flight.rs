@@ -0,0 +1,155 @@
+//! Coalescing of identical GET requests that are in flight at the same time.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+
+use error::Result;
+
+
+/// Lets only one thread (the leader) send a request for a key at a time,
+/// while other threads requesting the same key wait for the content received by the leader.
+///
+/// Errors can not be shared, so waiting threads send the request themselves if the leader failed.
+#[derive(Debug, Default)]
+pub(crate) struct SingleFlight {
+    calls: Mutex<HashMap<String, Arc<Call>>>,
+}
+
+#[derive(Debug, Default)]
+struct Call {
+    outcome: Mutex<Option<Option<String>>>,
+    done: Condvar,
+}
+
+/// Completes the call of the leader when dropped, also if the request panicked,
+/// so that the waiting threads do not block forever and later requests do not join the dead call.
+struct CompletionGuard<'a> {
+    calls: &'a Mutex<HashMap<String, Arc<Call>>>,
+    key: &'a str,
+    call: Arc<Call>,
+    content: Option<String>,
+}
+
+impl<'a> Drop for CompletionGuard<'a> {
+    fn drop(&mut self) {
+        // Panicking again while unwinding would abort, so poisoned locks are used anyway.
+        let mut outcome = self.call.outcome.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        *outcome = Some(self.content.take());
+        self.call.done.notify_all();
+        let mut calls = self.calls.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        calls.remove(self.key);
+    }
+}
+
+impl SingleFlight {
+    pub(crate) fn new() -> SingleFlight {
+        SingleFlight::default()
+    }
+
+    /// Returns the content for the key, sending the request only if no request for the key is in flight.
+    pub(crate) fn execute<F: Fn() -> Result<String>>(&self, key: &str, send_request: F) -> Result<String> {
+        let (call, is_leader) = {
+            let mut calls = self.calls.lock().unwrap();
+            match calls.get(key) {
+                Some(call) => (call.clone(), false),
+                None => {
+                    let call = Arc::new(Call::default());
+                    calls.insert(key.to_owned(), call.clone());
+                    (call, true)
+                },
+            }
+        };
+
+        if !is_leader {
+            let mut outcome = call.outcome.lock().unwrap();
+            while outcome.is_none() {
+                outcome = call.done.wait(outcome).unwrap();
+            }
+            return match *outcome {
+                Some(Some(ref content)) => Ok(content.clone()),
+                _ => send_request(),
+            };
+        }
+
+        let mut completion_guard = CompletionGuard {
+            calls: &self.calls,
+            key: key,
+            call: call,
+            content: None,
+        };
+        let result = send_request();
+        completion_guard.content = result.as_ref().ok().cloned();
+        result
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Barrier;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration;
+    use error::Error;
+    use super::*;
+
+    #[test]
+    fn test_should_coalesce_concurrent_calls() {
+        let single_flight = Arc::new(SingleFlight::new());
+        let sent_requests = Arc::new(AtomicUsize::new(0));
+        let barrier = Arc::new(Barrier::new(4));
+
+        let threads: Vec<_> = (0..4).map(|_| {
+            let single_flight = single_flight.clone();
+            let sent_requests = sent_requests.clone();
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                single_flight.execute("/kraken/streams", || {
+                    sent_requests.fetch_add(1, Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(200));
+                    Ok("content".to_owned())
+                })
+            })
+        }).collect();
+
+        for handle in threads {
+            assert_eq!(handle.join().unwrap().unwrap(), "content");
+        }
+        assert_eq!(sent_requests.load(Ordering::SeqCst), 1);
+        assert!(single_flight.calls.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_should_not_share_errors() {
+        let single_flight = SingleFlight::new();
+        let result = single_flight.execute("/kraken/streams", || Err(Error::Unauthorized("user_read")));
+        assert!(result.is_err());
+        let result = single_flight.execute("/kraken/streams", || Ok("content".to_owned()));
+        assert_eq!(result.unwrap(), "content");
+    }
+
+    #[test]
+    fn test_should_complete_call_if_leader_panics() {
+        let single_flight = Arc::new(SingleFlight::new());
+        let barrier = Arc::new(Barrier::new(2));
+
+        let leader = {
+            let single_flight = single_flight.clone();
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                single_flight.execute("/kraken/streams", || -> Result<String> {
+                    barrier.wait();
+                    thread::sleep(Duration::from_millis(200));
+                    panic!("request panicked");
+                })
+            })
+        };
+        barrier.wait();
+        let result = single_flight.execute("/kraken/streams", || Ok("content".to_owned()));
+
+        assert_eq!(result.unwrap(), "content");
+        assert!(leader.join().is_err());
+        assert!(single_flight.calls.lock().unwrap().is_empty());
+    }
+}
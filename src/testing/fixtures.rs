@@ -0,0 +1,97 @@
+//! Recorded responses of the endpoints of the `TwitchClient` (`ApiVersion::V3`).
+
+use transport::Method;
+
+
+/// Response of `TwitchClient::basic_info`.
+pub const BASIC_INFO: &'static str = include_str!("fixtures/basic_info.json");
+/// Response of `TwitchClient::top_games`.
+pub const TOP_GAMES: &'static str = include_str!("fixtures/top_games.json");
+/// Response of `TwitchClient::search_games`.
+pub const SEARCH_GAMES: &'static str = include_str!("fixtures/search_games.json");
+/// Response of `TwitchClient::ingests`.
+pub const INGESTS: &'static str = include_str!("fixtures/ingests.json");
+/// Response of `TwitchClient::stream`.
+pub const CHANNEL_STREAM: &'static str = include_str!("fixtures/channel_stream.json");
+/// Response of `TwitchClient::streams` and `TwitchClient::followed_streams`.
+pub const STREAMS: &'static str = include_str!("fixtures/streams.json");
+/// Response of `TwitchClient::featured_streams`.
+pub const FEATURED_STREAMS: &'static str = include_str!("fixtures/featured_streams.json");
+/// Response of `TwitchClient::streams_summary`.
+pub const STREAMS_SUMMARY: &'static str = include_str!("fixtures/streams_summary.json");
+/// Response of `TwitchClient::channel`, `TwitchClient::update_channel` and `TwitchClient::reset_stream_key`.
+pub const CHANNEL: &'static str = include_str!("fixtures/channel.json");
+/// Response of `TwitchClient::channel_editors`.
+pub const EDITORS: &'static str = include_str!("fixtures/editors.json");
+/// Response of `TwitchClient::channel_follows` and `TwitchClient::user_follows`.
+pub const FOLLOWS: &'static str = include_str!("fixtures/follows.json");
+/// Response of `TwitchClient::user_follows_channel` and `TwitchClient::follow_channel`.
+pub const FOLLOW: &'static str = include_str!("fixtures/follow.json");
+/// Response of `TwitchClient::user_subscribed_to_channel`.
+pub const SUBSCRIPTION: &'static str = include_str!("fixtures/subscription.json");
+/// Response of `TwitchClient::blocks`.
+pub const BLOCKS: &'static str = include_str!("fixtures/blocks.json");
+/// Response of `TwitchClient::block_user`.
+pub const BLOCK: &'static str = include_str!("fixtures/block.json");
+/// Response of `TwitchClient::video`.
+pub const VIDEO: &'static str = include_str!("fixtures/video.json");
+/// Response of `TwitchClient::top_videos`.
+pub const VIDEOS: &'static str = include_str!("fixtures/videos.json");
+/// Response of `TwitchClient::channel_videos`.
+pub const CHANNEL_VIDEOS: &'static str = include_str!("fixtures/channel_videos.json");
+/// Response of `TwitchClient::channel_posts`.
+pub const POSTS: &'static str = include_str!("fixtures/posts.json");
+/// Response of `TwitchClient::channel_post` and `TwitchClient::delete_post`.
+pub const POST: &'static str = include_str!("fixtures/post.json");
+/// Response of `TwitchClient::create_post`.
+pub const CREATED_POST: &'static str = include_str!("fixtures/created_post.json");
+/// Response of `TwitchClient::create_reaction`.
+pub const REACTION: &'static str = include_str!("fixtures/reaction.json");
+/// Response of `TwitchClient::chat_emoticons`.
+pub const EMOTICONS: &'static str = include_str!("fixtures/emoticons.json");
+/// Response of `TwitchClient::users_by_login`.
+pub const USERS: &'static str = include_str!("fixtures/users.json");
+/// Response of `TwitchClient::chat_emoticon_images`.
+pub const EMOTICON_IMAGES: &'static str = include_str!("fixtures/emoticon_images.json");
+/// Response of `TwitchClient::chat_badges`.
+pub const BADGES: &'static str = include_str!("fixtures/badges.json");
+
+/// Method, path, status and body of the responses that are served by `MockTransport::with_fixtures`.
+pub static RESPONSES: &'static [(Method, &'static str, u16, &'static str)] = &[
+    (Method::Get, "/kraken/", 200, BASIC_INFO),
+    (Method::Get, "/kraken/games/top", 200, TOP_GAMES),
+    (Method::Get, "/kraken/search/games", 200, SEARCH_GAMES),
+    (Method::Get, "/kraken/ingests", 200, INGESTS),
+    (Method::Get, "/kraken/streams/test_channel", 200, CHANNEL_STREAM),
+    (Method::Get, "/kraken/streams", 200, STREAMS),
+    (Method::Get, "/kraken/streams/followed", 200, STREAMS),
+    (Method::Get, "/kraken/streams/featured", 200, FEATURED_STREAMS),
+    (Method::Get, "/kraken/streams/summary", 200, STREAMS_SUMMARY),
+    (Method::Get, "/kraken/channels/test_channel", 200, CHANNEL),
+    (Method::Put, "/kraken/channels/test_channel", 200, CHANNEL),
+    (Method::Get, "/kraken/channels/test_channel/editors", 200, EDITORS),
+    (Method::Delete, "/kraken/channels/test_channel/stream_key", 200, CHANNEL),
+    (Method::Post, "/kraken/channels/test_channel/commercial", 204, ""),
+    (Method::Get, "/kraken/channels/test_channel/follows", 200, FOLLOWS),
+    (Method::Get, "/kraken/channels/test_channel/videos", 200, CHANNEL_VIDEOS),
+    (Method::Get, "/kraken/users", 200, USERS),
+    (Method::Get, "/kraken/users/test_user1/follows/channels", 200, FOLLOWS),
+    (Method::Get, "/kraken/users/test_user1/follows/channels/test_channel", 200, FOLLOW),
+    (Method::Put, "/kraken/users/test_user1/follows/channels/test_channel", 200, FOLLOW),
+    (Method::Delete, "/kraken/users/test_user1/follows/channels/test_channel", 204, ""),
+    (Method::Get, "/kraken/users/test_user1/subscriptions/test_channel", 200, SUBSCRIPTION),
+    (Method::Get, "/kraken/users/test_user1/blocks", 200, BLOCKS),
+    (Method::Put, "/kraken/users/test_user1/blocks/test_user2", 200, BLOCK),
+    (Method::Delete, "/kraken/users/test_user1/blocks/test_user2", 204, ""),
+    (Method::Get, "/kraken/videos/c6055863", 200, VIDEO),
+    (Method::Get, "/kraken/videos/top", 200, VIDEOS),
+    (Method::Get, "/kraken/feed/test_channel/posts", 200, POSTS),
+    (Method::Post, "/kraken/feed/test_channel/posts", 200, CREATED_POST),
+    (Method::Get, "/kraken/feed/test_channel/posts/20", 200, POST),
+    (Method::Delete, "/kraken/feed/test_channel/posts/20", 200, POST),
+    (Method::Post, "/kraken/feed/test_channel/posts/20/reactions", 200, REACTION),
+    (Method::Delete, "/kraken/feed/test_channel/posts/20/reactions", 200, r#"{"deleted":true}"#),
+    (Method::Get, "/kraken/chat/emoticons", 200, EMOTICONS),
+    (Method::Get, "/kraken/chat/emoticon_images", 200, EMOTICON_IMAGES),
+    (Method::Get, "/kraken/chat/test_channel/badges", 200, BADGES),
+];
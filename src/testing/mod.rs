@@ -0,0 +1,473 @@
+//! Offline testing without network access or a Twitch Client ID.
+//!
+//! The `MockTransport` serves canned responses instead of sending the requests to Twitch
+//! and the `fixtures` contain recorded responses for every endpoint of the `TwitchClient`.
+//! Interactions with Twitch can be recorded to cassette files with the `RecordingTransport`
+//! and replayed with the `ReplayingTransport`.
+//!
+//! Only available with the `testing` feature.
+
+pub mod fixtures;
+mod vcr;
+
+pub use self::vcr::{RecordingTransport, ReplayingTransport};
+
+use std::sync::Mutex;
+use url::Url;
+
+use error::Result;
+use transport::{Transport, Method, Request, Response};
+
+
+/// Transport that serves canned responses by method and path and records the requests.
+///
+/// Responses are registered by the path of the url (e.g. "/kraken/streams"),
+/// optionally including the query string (e.g. "/kraken/streams?limit=1"),
+/// which takes precedence over the path alone.
+/// Requests without a registered response are answered with `404 Not Found`.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use twitch_client::*;
+/// use twitch_client::testing::MockTransport;
+///
+/// let transport = Arc::new(MockTransport::with_fixtures());
+/// let twitch_client = TwitchClientBuilder::new("client_id")
+///         .with_transport(transport.clone())
+///         .build()
+///         .unwrap();
+///
+/// let top_games = twitch_client.top_games(TopGamesParams::default()).unwrap();
+/// assert_eq!(top_games.top()[0].game().name(), "Counter-Strike: Global Offensive");
+/// assert_eq!(transport.requests()[0].url(), "https://api.twitch.tv/kraken/games/top");
+/// ```
+#[derive(Debug)]
+pub struct MockTransport {
+    responses: Vec<MockResponse>,
+    requests: Mutex<Vec<Request>>,
+}
+
+#[derive(Debug)]
+struct MockResponse {
+    method: Method,
+    path: String,
+    response: Response,
+}
+
+impl MockTransport {
+    /// Creates a transport without any responses.
+    pub fn new() -> MockTransport {
+        MockTransport {
+            responses: Vec::new(),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+    /// Creates a transport that serves the `fixtures` for every endpoint of the `TwitchClient`,
+    /// for the channel "test_channel", the users "test_user1" and "test_user2",
+    /// the video "c6055863" and the post "20" with `ApiVersion::V3`.
+    pub fn with_fixtures() -> MockTransport {
+        let mut transport = MockTransport::new();
+        for &(method, path, status, body) in fixtures::RESPONSES.iter() {
+            transport = transport.with_response(method, path, status, body);
+        }
+        transport
+    }
+    /// Registers the response with the status and body for requests with the method and path.
+    ///
+    /// Responses that are registered later replace responses for the same method and path.
+    pub fn with_response<S: Into<String>>(self, method: Method, path: &str, status: u16, body: S) -> MockTransport {
+        self.with_response_headers(method, path, status, Vec::new(), body)
+    }
+    /// Registers the response with the status, headers and body for requests with the method and path.
+    ///
+    /// Responses that are registered later replace responses for the same method and path.
+    pub fn with_response_headers<S: Into<String>>(mut self, method: Method, path: &str, status: u16,
+                                                  headers: Vec<(String, String)>, body: S) -> MockTransport {
+        self.responses.retain(|mock_response| mock_response.method != method || mock_response.path != path);
+        self.responses.push(MockResponse {
+            method: method,
+            path: path.to_owned(),
+            response: Response::new(status, headers, body.into()),
+        });
+        self
+    }
+    /// Returns the requests that were sent so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn find_response(&self, method: Method, url: &str) -> Option<&Response> {
+        let url = match Url::parse(url) {
+            Ok(url) => url,
+            Err(_) => return None,
+        };
+        let path = url.path();
+        let path_and_query = match url.query() {
+            Some(query) => format!("{}?{}", path, query),
+            None => path.to_owned(),
+        };
+        let find = |path: &str| {
+            self.responses.iter()
+                    .find(|mock_response| mock_response.method == method && mock_response.path == path)
+                    .map(|mock_response| &mock_response.response)
+        };
+        find(&path_and_query).or_else(|| find(path))
+    }
+}
+
+impl Default for MockTransport {
+    fn default() -> MockTransport {
+        MockTransport::new()
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&self, request: Request) -> Result<Response> {
+        let response = match self.find_response(request.method(), request.url()) {
+            Some(response) => response.clone(),
+            None => {
+                let body = format!(r#"{{"error":"Not Found","status":404,"message":"No mock response for {:?} {}"}}"#,
+                        request.method(), request.url());
+                Response::new(404, Vec::new(), body)
+            },
+        };
+        self.requests.lock().unwrap().push(request);
+        Ok(response)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use error::Error;
+    use circuit_breaker::{CircuitBreakerPolicy, CircuitState};
+    use credentials::{Credentials, RoundRobinCredentials};
+    use cache::{DiskResponseCache, ResponseCache};
+    use resolver::UserIdResolver;
+    use param::*;
+    use {ApiVersion, TwitchClient, TwitchClientBuilder};
+
+    fn create_mock_twitch_client() -> TwitchClient {
+        TwitchClientBuilder::new("client_id")
+                .with_oauth_token("oauth_token")
+                .with_transport(MockTransport::with_fixtures())
+                .build()
+                .unwrap()
+    }
+
+    #[test]
+    fn test_should_prefer_response_with_query() {
+        let transport = MockTransport::new()
+                .with_response(Method::Get, "/kraken/streams", 200, "all")
+                .with_response(Method::Get, "/kraken/streams?limit=1", 200, "one");
+        let request = |url: &str| Request::new(Method::Get, url.to_owned(), Vec::new(), None);
+
+        let response = transport.send(request("https://api.twitch.tv/kraken/streams?limit=1")).unwrap();
+        assert_eq!(response.body(), "one");
+        let response = transport.send(request("https://api.twitch.tv/kraken/streams?limit=2")).unwrap();
+        assert_eq!(response.body(), "all");
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_should_answer_unknown_requests_with_not_found() {
+        let client = TwitchClientBuilder::new("client_id")
+                .with_transport(MockTransport::new())
+                .build()
+                .unwrap();
+        match client.channel("test_channel") {
+            Err(Error::NotFound { .. }) => {},
+            result => panic!("expecting not found error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_should_record_requests() {
+        let transport = Arc::new(MockTransport::with_fixtures());
+        let client = TwitchClientBuilder::new("client_id")
+                .with_transport(transport.clone())
+                .build()
+                .unwrap();
+        client.streams(StreamsParams::new().with_limit(1)).unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url(), "https://api.twitch.tv/kraken/streams?limit=1");
+    }
+
+    #[test]
+    fn test_should_resolve_names_to_ids_with_api_version_v5() {
+        let transport = Arc::new(MockTransport::with_fixtures()
+                .with_response(Method::Get, "/kraken/users/22761313/follows/channels", 200, fixtures::FOLLOWS));
+        let client = TwitchClientBuilder::new("client_id")
+                .with_api_version(ApiVersion::V5)
+                .with_user_id_resolver(UserIdResolver::new())
+                .with_transport(transport.clone())
+                .build()
+                .unwrap();
+
+        client.user_follows("test_user1", FollowsParams::default()).unwrap();
+        client.user_follows("Test_User1", FollowsParams::default()).unwrap();
+        let urls: Vec<_> = transport.requests().iter().map(|request| request.url().to_owned()).collect();
+        assert_eq!(urls, vec![
+            "https://api.twitch.tv/kraken/users?login=test_user1",
+            "https://api.twitch.tv/kraken/users/22761313/follows/channels",
+            "https://api.twitch.tv/kraken/users/22761313/follows/channels",
+        ]);
+
+        match client.user_follows("unknown_user", FollowsParams::default()) {
+            Err(Error::NotFound { .. }) => {},
+            result => panic!("expecting not found error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_should_apply_request_options_to_clone_only() {
+        let transport = Arc::new(MockTransport::with_fixtures());
+        let client = TwitchClientBuilder::new("client_id")
+                .with_transport(transport.clone())
+                .build()
+                .unwrap();
+        let options = RequestOptions::new()
+                .with_timeout(Duration::from_secs(60))
+                .with_header("X-Test", "1")
+                .with_api_version(ApiVersion::V5);
+        client.with_options(options).streams(StreamsParams::default()).unwrap();
+        client.streams(StreamsParams::default()).unwrap();
+
+        let requests = transport.requests();
+        let header = |request: &Request, name: &str| {
+            request.headers().iter().find(|header| header.0 == name).map(|header| header.1.clone())
+        };
+        assert_eq!(requests[0].timeout(), Some(Duration::from_secs(60)));
+        assert_eq!(header(&requests[0], "X-Test"), Some("1".to_owned()));
+        assert_eq!(header(&requests[0], "Accept"), Some("application/vnd.twitchtv.v5+json".to_owned()));
+        assert_eq!(requests[1].timeout(), None);
+        assert_eq!(header(&requests[1], "X-Test"), None);
+        assert_eq!(header(&requests[1], "Accept"), Some("application/vnd.twitchtv.v3+json".to_owned()));
+    }
+
+    #[test]
+    fn test_should_send_user_agent() {
+        let transport = Arc::new(MockTransport::with_fixtures());
+        let user_agent = |request: &Request| {
+            request.headers().iter().find(|header| header.0 == "User-Agent").map(|header| header.1.clone())
+        };
+        let client = TwitchClientBuilder::new("client_id")
+                .with_transport(transport.clone())
+                .build()
+                .unwrap();
+        client.streams(StreamsParams::default()).unwrap();
+        let client = TwitchClientBuilder::new("client_id")
+                .with_user_agent("test-bot/1.0")
+                .with_transport(transport.clone())
+                .build()
+                .unwrap();
+        client.streams(StreamsParams::default()).unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(user_agent(&requests[0]), Some(format!("twitch-client/{}", env!("CARGO_PKG_VERSION"))));
+        assert_eq!(user_agent(&requests[1]), Some("test-bot/1.0".to_owned()));
+    }
+
+    #[test]
+    fn test_should_fail_fast_when_circuit_is_open() {
+        let transport = Arc::new(MockTransport::new()
+                .with_response(Method::Get, "/kraken/streams", 503, "Service Unavailable"));
+        let client = TwitchClientBuilder::new("client_id")
+                .with_circuit_breaker(CircuitBreakerPolicy::new(2))
+                .with_transport(transport.clone())
+                .build()
+                .unwrap();
+        assert_eq!(client.circuit_state(), Some(CircuitState::Closed));
+
+        for _ in 0..2 {
+            match client.streams(StreamsParams::default()) {
+                Err(Error::UnexpectedStatus { status, .. }) => assert_eq!(status, 503),
+                result => panic!("expecting unexpected status error, but got: {:?}", result),
+            }
+        }
+        assert_eq!(client.circuit_state(), Some(CircuitState::Open));
+        match client.streams(StreamsParams::default()) {
+            Err(Error::CircuitOpen { .. }) => {},
+            result => panic!("expecting circuit open error, but got: {:?}", result),
+        }
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_should_check_whether_channels_are_live() {
+        let client = create_mock_twitch_client();
+        assert!(client.is_live("test_channel").unwrap());
+
+        let live_streams = client.live_streams_of(&["Test_Channel", "offline_channel"]).unwrap();
+        assert_eq!(live_streams.len(), 2);
+        assert_eq!(live_streams["Test_Channel"].as_ref().map(|stream| stream.channel().name().as_str()),
+                   Some("test_channel"));
+        assert!(live_streams["offline_channel"].is_none());
+    }
+
+    #[test]
+    fn test_live_streams_of_should_keep_streams_of_duplicate_channels() {
+        let client = create_mock_twitch_client();
+        let live_streams = client.live_streams_of(&["test_channel", "Test_Channel", "test_channel"]).unwrap();
+        assert_eq!(live_streams.len(), 2);
+        assert!(live_streams["test_channel"].is_some());
+        assert!(live_streams["Test_Channel"].is_some());
+    }
+
+    #[test]
+    fn test_channel_overview_should_combine_endpoints() {
+        let client = create_mock_twitch_client();
+        let overview = client.channel_overview("test_channel").unwrap();
+        assert_eq!(overview.channel().name(), "test_channel");
+        assert!(overview.is_live());
+        assert_eq!(overview.recent_videos().len(), 1);
+        assert_eq!(overview.follower_count(), 1);
+        assert!(client.channel_overview("unknown_channel").is_err());
+    }
+
+    #[test]
+    fn test_should_throttle_requests_beyond_budget() {
+        let client = TwitchClientBuilder::new("client_id")
+                .with_max_requests_per_minute(1200)
+                .with_transport(MockTransport::with_fixtures())
+                .build()
+                .unwrap();
+
+        let started_at = Instant::now();
+        for _ in 0..3 {
+            client.top_games(TopGamesParams::default()).unwrap();
+        }
+        assert!(started_at.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_should_rotate_credentials_of_provider() {
+        let transport = Arc::new(MockTransport::with_fixtures());
+        let client = TwitchClientBuilder::new("client_id")
+                .with_credentials_provider(RoundRobinCredentials::new(vec![
+                    Credentials::new("client_id1"),
+                    Credentials::new("client_id2").with_oauth_token("oauth_token2"),
+                ]))
+                .with_transport(transport.clone())
+                .build()
+                .unwrap();
+
+        client.top_games(TopGamesParams::default()).unwrap();
+        client.top_games(TopGamesParams::default()).unwrap();
+        let requests = transport.requests();
+        let header = |index: usize, name: &str| {
+            requests[index].headers().iter()
+                    .find(|&&(ref header_name, _)| header_name == name)
+                    .map(|&(_, ref value)| value.clone())
+        };
+        assert_eq!(header(0, "Client-ID"), Some("client_id1".to_owned()));
+        assert_eq!(header(0, "Authorization"), None);
+        assert_eq!(header(1, "Client-ID"), Some("client_id2".to_owned()));
+        assert_eq!(header(1, "Authorization"), Some("OAuth oauth_token2".to_owned()));
+    }
+
+    #[test]
+    fn test_should_fall_back_to_stale_cached_response_if_twitch_is_unavailable() {
+        let directory = ::std::env::temp_dir().join(format!("twitch-client-stale-cache-{}", ::std::process::id()));
+        let disk_cache = DiskResponseCache::new(&directory).unwrap();
+        disk_cache.put("v3 https://api.twitch.tv/kraken/ingests", fixtures::INGESTS.to_owned(), Duration::from_secs(0));
+        let transport = MockTransport::new()
+                .with_response(Method::Get, "/kraken/ingests", 503, "Service Unavailable");
+        let client = TwitchClientBuilder::new("client_id")
+                .with_response_cache(disk_cache.clone(), Duration::from_secs(60))
+                .with_transport(Arc::new(transport))
+                .build()
+                .unwrap();
+
+        let ingests = client.ingests().unwrap();
+        assert!(!ingests.ingests().is_empty());
+        disk_cache.clear().unwrap();
+        assert!(client.ingests().is_err());
+        ::std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_should_remember_last_response_meta() {
+        let headers = vec![
+            ("Twitch-Trace-Id".to_owned(), "3a5b4e2d1c0f9e8d7c6b5a4f3e2d1c0b".to_owned()),
+            ("Date".to_owned(), "Tue, 16 Oct 2018 20:00:00 GMT".to_owned()),
+        ];
+        let transport = MockTransport::new()
+                .with_response_headers(Method::Get, "/kraken/streams", 200, headers, fixtures::STREAMS);
+        let client = TwitchClientBuilder::new("client_id")
+                .with_transport(Arc::new(transport))
+                .build()
+                .unwrap();
+        assert_eq!(client.last_response_meta(), None);
+
+        client.streams(StreamsParams::default()).unwrap();
+        let response_meta = client.last_response_meta().unwrap();
+        assert_eq!(response_meta.status(), 200);
+        assert_eq!(response_meta.request_id(), &Some("3a5b4e2d1c0f9e8d7c6b5a4f3e2d1c0b".to_owned()));
+        assert_eq!(response_meta.date(), &Some("Tue, 16 Oct 2018 20:00:00 GMT".to_owned()));
+
+        assert!(client.stream("unknown_channel").is_err());
+        assert_eq!(client.last_response_meta().map(|response_meta| response_meta.status()), Some(404));
+    }
+
+    #[test]
+    fn test_execute_batch_should_keep_order() {
+        let client = create_mock_twitch_client();
+        let channels = vec!["test_channel", "unknown_channel", "test_channel"];
+        let results = client.execute_batch(channels, 2, |client, channel| client.channel(channel));
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(results[2].is_ok());
+    }
+
+    #[test]
+    fn test_fixtures_of_all_endpoints() {
+        let client = create_mock_twitch_client();
+        assert!(client.basic_info().unwrap().token().valid());
+        assert_eq!(client.top_games(TopGamesParams::default()).unwrap().total(), 322);
+        assert_eq!(client.search_games("counter-strike", false).unwrap().games().len(), 1);
+        assert_eq!(client.find_game_exact("counter-strike: GLOBAL offensive").unwrap().unwrap().id().value(), 32399);
+        assert!(client.find_game_exact("counter-strike").unwrap().is_none());
+        assert_eq!(client.ingests().unwrap().ingests().len(), 1);
+        assert!(client.stream("test_channel").unwrap().stream().is_some());
+        assert_eq!(client.streams(StreamsParams::default()).unwrap().total(), 12345);
+        assert_eq!(client.streams_borrowed(StreamsParams::default(), &mut String::new()).unwrap().total(), 12345);
+        assert_eq!(client.followed_streams(FollowedStreamsParams::default()).unwrap().streams().len(), 1);
+        assert_eq!(client.featured_streams(FeaturedStreamsParams::default()).unwrap().featured().len(), 1);
+        assert_eq!(client.streams_summary(StreamsSummaryParams::default()).unwrap().channels(), 4144);
+        assert_eq!(client.channel("test_channel").unwrap().name(), "test_channel");
+        assert_eq!(client.channel_editors("test_channel").unwrap().users().len(), 1);
+        client.update_channel("test_channel", UpdateChannelParams::new().with_status("test status")).unwrap();
+        client.reset_stream_key("test_channel").unwrap();
+        client.run_commercial("test_channel", 30).unwrap();
+        assert_eq!(client.channel_follows("test_channel", FollowsParams::default()).unwrap().total(), 1);
+        assert_eq!(client.user_follows("test_user1", FollowsParams::default()).unwrap().total(), 1);
+        assert!(client.user_follows_channel("test_user1", "test_channel").unwrap().is_some());
+        assert!(client.user_subscribed_to_channel("test_user1", "test_channel").unwrap().is_some());
+        client.follow_channel("test_user1", "test_channel", true).unwrap();
+        client.unfollow_channel("test_user1", "test_channel").unwrap();
+        assert_eq!(client.blocks("test_user1", BlocksParams::default()).unwrap().blocks().len(), 1);
+        client.block_user("test_user1", "test_user2").unwrap();
+        client.unblock_user("test_user1", "test_user2").unwrap();
+        assert_eq!(client.video("c6055863").unwrap().id(), "c6055863");
+        assert_eq!(client.top_videos(TopVideosParams::default()).unwrap().videos().len(), 1);
+        assert_eq!(client.channel_videos("test_channel", ChannelVideosParams::default()).unwrap().total(), 1);
+        assert_eq!(client.channel_posts("test_channel", PostsParams::default()).unwrap().total(), 1);
+        assert_eq!(client.channel_post("test_channel", "20").unwrap().id(), "20");
+        client.create_post("test_channel", CreatePostParams::new("Hello world!")).unwrap();
+        client.delete_post("test_channel", "20").unwrap();
+        client.create_reaction("test_channel", "20", "25").unwrap();
+        client.delete_reaction("test_channel", "20", "25").unwrap();
+        assert_eq!(client.chat_emoticons().unwrap().emoticons().len(), 1);
+        client.chat_emoticon_images(EmoticonImagesParams::default()).unwrap();
+        assert!(client.chat_badges("test_channel").unwrap().broadcaster().is_some());
+    }
+}
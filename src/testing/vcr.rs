@@ -0,0 +1,220 @@
+//! Recording and replaying of http interactions ("cassettes").
+
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use serde_json;
+
+use error::Result;
+use transport::{Transport, Method, Request, Response};
+
+
+/// Transport that sends the requests with the wrapped transport
+/// and records the interactions to a JSON cassette file, which is rewritten after every request.
+///
+/// Only the method, url and body of the requests are recorded,
+/// so that the OAuth token and the Client ID in the headers do not end up in the cassette.
+///
+/// # Examples
+///
+/// ```no_run
+/// use twitch_client::*;
+/// use twitch_client::transport::HyperTransport;
+/// use twitch_client::testing::{RecordingTransport, ReplayingTransport};
+///
+/// // Record once against Twitch ...
+/// let transport = RecordingTransport::new(HyperTransport::new().unwrap(), "tests/cassettes/top_games.json");
+/// let twitch_client = TwitchClientBuilder::new("<YOUR_TWITCH_CLIENT_ID>")
+///         .with_transport(transport)
+///         .build()
+///         .unwrap();
+/// twitch_client.top_games(TopGamesParams::default()).unwrap();
+///
+/// // ... and replay in the tests.
+/// let transport = ReplayingTransport::from_file("tests/cassettes/top_games.json").unwrap();
+/// let twitch_client = TwitchClientBuilder::new("<YOUR_TWITCH_CLIENT_ID>")
+///         .with_transport(transport)
+///         .build()
+///         .unwrap();
+/// twitch_client.top_games(TopGamesParams::default()).unwrap();
+/// ```
+pub struct RecordingTransport<T: Transport> {
+    transport: T,
+    path: PathBuf,
+    cassette: Mutex<Cassette>,
+}
+
+impl<T: Transport> RecordingTransport<T> {
+    /// Creates a transport that records to the cassette file at the path, replacing an existing file.
+    pub fn new<P: AsRef<Path>>(transport: T, path: P) -> RecordingTransport<T> {
+        RecordingTransport {
+            transport: transport,
+            path: path.as_ref().to_path_buf(),
+            cassette: Mutex::new(Cassette { interactions: Vec::new() }),
+        }
+    }
+    /// Path of the cassette file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl<T: Transport> Transport for RecordingTransport<T> {
+    fn send(&self, request: Request) -> Result<Response> {
+        let recorded_request = RecordedRequest::from_request(&request);
+        let response = try!(self.transport.send(request));
+
+        let mut cassette = self.cassette.lock().unwrap();
+        cassette.interactions.push(Interaction {
+            request: recorded_request,
+            response: RecordedResponse::from_response(&response),
+        });
+        let json = try!(serde_json::to_string_pretty(&*cassette));
+        let mut file = try!(File::create(&self.path));
+        try!(file.write_all(json.as_bytes()));
+        Ok(response)
+    }
+}
+
+
+/// Transport that serves the responses of a cassette file recorded by a `RecordingTransport`.
+///
+/// Requests are matched by method, url and body in the order of the recording.
+/// If all recorded interactions of a request were served, the last one is served again.
+/// Requests that were not recorded are answered with `404 Not Found`.
+pub struct ReplayingTransport {
+    interactions: Vec<Interaction>,
+    served: Mutex<Vec<bool>>,
+}
+
+impl ReplayingTransport {
+    /// Reads the cassette file at the path.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<ReplayingTransport> {
+        let mut json = String::new();
+        let mut file = try!(File::open(path));
+        try!(file.read_to_string(&mut json));
+        let cassette: Cassette = try!(serde_json::from_str(&json));
+        let served = vec![false; cassette.interactions.len()];
+        Ok(ReplayingTransport {
+            interactions: cassette.interactions,
+            served: Mutex::new(served),
+        })
+    }
+}
+
+impl Transport for ReplayingTransport {
+    fn send(&self, request: Request) -> Result<Response> {
+        let request = RecordedRequest::from_request(&request);
+        let mut served = self.served.lock().unwrap();
+        let matching_indexes: Vec<usize> = self.interactions.iter()
+                .enumerate()
+                .filter(|&(_, interaction)| interaction.request == request)
+                .map(|(index, _)| index)
+                .collect();
+        let index = matching_indexes.iter().cloned()
+                .find(|&index| !served[index])
+                .or_else(|| matching_indexes.last().cloned());
+        match index {
+            Some(index) => {
+                served[index] = true;
+                Ok(self.interactions[index].response.to_response())
+            },
+            None => {
+                let message = format!("No recorded interaction for {} {}", request.method, request.url);
+                let body = format!(r#"{{"error":"Not Found","status":404,"message":"{}"}}"#, message);
+                Ok(Response::new(404, Vec::new(), body))
+            },
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Cassette {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct RecordedRequest {
+    method: String,
+    url: String,
+    body: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct RecordedResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl RecordedRequest {
+    fn from_request(request: &Request) -> RecordedRequest {
+        let method = match request.method() {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Delete => "DELETE",
+        };
+        RecordedRequest {
+            method: method.to_owned(),
+            url: request.url().to_owned(),
+            body: request.body().map(|body| body.to_owned()),
+        }
+    }
+}
+
+impl RecordedResponse {
+    fn from_response(response: &Response) -> RecordedResponse {
+        RecordedResponse {
+            status: response.status(),
+            headers: response.headers().to_vec(),
+            body: response.body().to_owned(),
+        }
+    }
+
+    fn to_response(&self) -> Response {
+        Response::new(self.status, self.headers.clone(), self.body.clone())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+    use param::TopGamesParams;
+    use testing::MockTransport;
+    use TwitchClientBuilder;
+
+    #[test]
+    fn test_should_replay_recorded_interactions() {
+        let path = env::temp_dir().join("twitch_client_test_cassette.json");
+
+        let transport = RecordingTransport::new(MockTransport::with_fixtures(), &path);
+        let client = TwitchClientBuilder::new("client_id")
+                .with_transport(transport)
+                .build()
+                .unwrap();
+        let recorded_top_games = client.top_games(TopGamesParams::default()).unwrap();
+
+        let transport = ReplayingTransport::from_file(&path).unwrap();
+        let client = TwitchClientBuilder::new("client_id")
+                .with_transport(transport)
+                .build()
+                .unwrap();
+        let replayed_top_games = client.top_games(TopGamesParams::default()).unwrap();
+        assert_eq!(replayed_top_games.total(), recorded_top_games.total());
+        assert!(client.ingests().is_err(), "ingests were not recorded");
+
+        fs::remove_file(&path).unwrap();
+    }
+}
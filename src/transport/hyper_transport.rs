@@ -0,0 +1,151 @@
+use std::fmt::Debug;
+use std::io;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+use hyper;
+use hyper::client::pool::{self, Pool};
+use hyper::net::{HttpsConnector, HttpStream, NetworkConnector, NetworkStream};
+use hyper::header::Headers;
+#[cfg(not(feature = "rustls"))]
+use hyper_native_tls::NativeTlsClient;
+#[cfg(feature = "rustls")]
+use hyper_rustls::TlsClient;
+
+use error::Result;
+use transport::{self, Transport, TransportConfig, Method, Request, Response, StreamingResponse};
+
+
+/// Transport using a hyper 0.10 client.
+///
+/// Hyper 0.10 only supports client wide timeouts, so the timeout of a `Request` is ignored.
+pub struct HyperTransport {
+    hyper_client: hyper::Client,
+}
+
+impl HyperTransport {
+    /// Creates a transport with a new hyper https client using native tls
+    /// (or rustls with the `rustls` cargo feature).
+    pub fn new() -> Result<HyperTransport> {
+        HyperTransport::with_config(&TransportConfig::default())
+    }
+
+    /// Creates a transport using the provided hyper client.
+    ///
+    /// Note that the provided hyper client needs to use a tls connection.
+    pub fn with_hyper_client(hyper_client: hyper::Client) -> HyperTransport {
+        HyperTransport {
+            hyper_client: hyper_client,
+        }
+    }
+
+    /// Creates a transport with a new hyper https client that opens the connections with the provided connector,
+    /// e.g. to connect through a SOCKS5 proxy or to capture the traffic.
+    ///
+    /// The connector only establishes the plain connection to the host,
+    /// TLS is layered on top of it like for the default connector.
+    pub fn with_connector<C>(connector: C) -> Result<HyperTransport>
+            where C: NetworkConnector + Send + Sync + 'static,
+                  C::Stream: NetworkStream + Send + Sync + Clone + Debug {
+        HyperTransport::with_config_and_connector(&TransportConfig::default(), connector)
+    }
+
+    pub(crate) fn with_config(config: &TransportConfig) -> Result<HyperTransport> {
+        HyperTransport::with_config_and_connector(config, TimeoutConnector(config.connect_timeout))
+    }
+
+    /// Creates a transport with the settings of the config, except the connect timeout
+    /// which is up to the connector.
+    pub(crate) fn with_config_and_connector<C>(config: &TransportConfig, connector: C) -> Result<HyperTransport>
+            where C: NetworkConnector + Send + Sync + 'static,
+                  C::Stream: NetworkStream + Send + Sync + Clone + Debug {
+        let connector = try!(https_connector(connector));
+        let mut hyper_client = if config.disable_keep_alive {
+            hyper::Client::with_connector(connector)
+        } else {
+            let mut pool_config = pool::Config::default();
+            if let Some(max_idle_connections_per_host) = config.max_idle_connections_per_host {
+                pool_config.max_idle = max_idle_connections_per_host;
+            }
+            let mut pool = Pool::with_connector(pool_config, connector);
+            pool.set_idle_timeout(config.idle_timeout);
+            hyper::Client::with_connector(pool)
+        };
+        hyper_client.set_read_timeout(config.read_timeout);
+        Ok(HyperTransport::with_hyper_client(hyper_client))
+    }
+}
+
+impl Transport for HyperTransport {
+    fn send(&self, request: Request) -> Result<Response> {
+        let response = try!(self.send_streaming(request));
+        response.into_response()
+    }
+
+    fn send_streaming(&self, request: Request) -> Result<StreamingResponse> {
+        let method = match request.method() {
+            Method::Get => hyper::method::Method::Get,
+            Method::Post => hyper::method::Method::Post,
+            Method::Put => hyper::method::Method::Put,
+            Method::Delete => hyper::method::Method::Delete,
+        };
+        let mut headers = Headers::new();
+        for &(ref name, ref value) in request.headers() {
+            headers.set_raw(name.clone(), vec![value.clone().into_bytes()]);
+        }
+
+        let mut request_builder = self.hyper_client.request(method, request.url()).headers(headers);
+        if let Some(body) = request.body() {
+            request_builder = request_builder.body(body);
+        }
+        let response = try!(request_builder.send());
+
+        let mut headers = response.headers.iter()
+                .map(|header| (header.name().to_owned(), header.value_string()))
+                .collect();
+        let status = response.status.to_u16();
+        let body = transport::decode_body(&mut headers, response);
+        Ok(StreamingResponse::new(status, headers, body))
+    }
+}
+
+
+/// Https connector using native tls on top of the connections of the connector.
+#[cfg(not(feature = "rustls"))]
+fn https_connector<C>(connector: C) -> Result<HttpsConnector<NativeTlsClient, C>>
+        where C: NetworkConnector, C::Stream: NetworkStream + Send + Sync + Clone + Debug {
+    let ssl = try!(NativeTlsClient::new());
+    Ok(HttpsConnector::with_connector(ssl, connector))
+}
+
+/// Https connector using rustls with the Mozilla root certificates on top of the connections of the connector,
+/// which needs no system TLS library.
+#[cfg(feature = "rustls")]
+fn https_connector<C>(connector: C) -> Result<HttpsConnector<TlsClient, C>>
+        where C: NetworkConnector, C::Stream: NetworkStream + Send + Sync + Clone + Debug {
+    Ok(HttpsConnector::with_connector(TlsClient::new(), connector))
+}
+
+
+/// Http connector that gives up connecting after the optional timeout,
+/// since hyper 0.10 only supports read and write timeouts.
+struct TimeoutConnector(Option<Duration>);
+
+impl NetworkConnector for TimeoutConnector {
+    type Stream = HttpStream;
+
+    fn connect(&self, host: &str, port: u16, _scheme: &str) -> hyper::Result<HttpStream> {
+        let connect_timeout = match self.0 {
+            Some(connect_timeout) => connect_timeout,
+            None => return Ok(HttpStream(try!(TcpStream::connect((host, port))))),
+        };
+
+        let mut last_error = io::Error::new(io::ErrorKind::InvalidInput, "could not resolve to any addresses");
+        for address in try!((host, port).to_socket_addrs()) {
+            match TcpStream::connect_timeout(&address, connect_timeout) {
+                Ok(stream) => return Ok(HttpStream(stream)),
+                Err(error) => last_error = error,
+            }
+        }
+        Err(hyper::Error::Io(last_error))
+    }
+}
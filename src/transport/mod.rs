@@ -0,0 +1,368 @@
+//! Transports that send the http requests to Twitch.
+//!
+//! The transport is selected with cargo features:
+//! `hyper-client` (enabled by default) uses [hyper] with [native_tls],
+//! `rustls` uses [hyper] with [rustls] instead of native tls
+//! and `reqwest` uses [reqwest] with its TLS stack.
+//! If both `hyper-client` and `rustls` are enabled, rustls is used.
+//! If hyper and reqwest are enabled, hyper is used by default.
+//!
+//! The `async` feature adds the `AsyncTransport` of the `AsyncTwitchClient`,
+//! which sends the same requests with the async client of [reqwest] without blocking.
+//!
+//! [hyper]: https://hyper.rs/
+//! [native_tls]: https://docs.rs/crate/native-tls
+//! [rustls]: https://docs.rs/crate/rustls
+//! [reqwest]: https://docs.rs/crate/reqwest
+
+use std::fmt;
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+use std::time::Duration;
+#[cfg(feature = "blocking")]
+use flate2::read::{GzDecoder, ZlibDecoder};
+#[cfg(feature = "async")]
+use futures::Future;
+#[cfg(any(feature = "blocking", feature = "async"))]
+use url::Url;
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+use error::Error;
+use error::Result;
+
+#[cfg(feature = "hyper-transport")]
+mod hyper_transport;
+#[cfg(all(feature = "blocking", feature = "reqwest"))]
+mod reqwest_transport;
+#[cfg(feature = "async")]
+mod reqwest_async_transport;
+
+#[cfg(feature = "hyper-transport")]
+pub use self::hyper_transport::HyperTransport;
+#[cfg(all(feature = "blocking", feature = "reqwest"))]
+pub use self::reqwest_transport::ReqwestTransport;
+#[cfg(feature = "async")]
+pub use self::reqwest_async_transport::ReqwestAsyncTransport;
+
+
+/// Base url of the Twitch API v3 and v5 (kraken).
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) const KRAKEN_BASE_URL: &'static str = "https://api.twitch.tv/kraken";
+/// User agent that identifies this crate and its version, e.g. "twitch-client/0.1.0".
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) const DEFAULT_USER_AGENT: &'static str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Checks that the base url override is an absolute http(s) url and removes trailing slashes,
+/// failing with `Error::InvalidUrl` otherwise (e.g. for "localhost:8080/kraken").
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) fn normalize_base_url(base_url: String) -> Result<String> {
+    let is_http_url = match Url::parse(&base_url) {
+        Ok(url) => url.scheme() == "http" || url.scheme() == "https",
+        Err(_) => false,
+    };
+    if !is_http_url {
+        return Err(Error::InvalidUrl(base_url));
+    }
+    Ok(base_url.trim_right_matches('/').to_owned())
+}
+
+
+/// Sends http requests and reads the responses.
+///
+/// Transports are shared between threads, e.g. for the concurrent requests of batch lookups.
+pub trait Transport: Send + Sync {
+    /// Sends the request and returns the response with any status code.
+    ///
+    /// Errors are only returned if no response could be received at all (e.g. connection failures).
+    fn send(&self, request: Request) -> Result<Response>;
+
+    /// Sends the request and returns the response with a body that is read on demand,
+    /// so that large responses (e.g. long follower lists) are not buffered completely in memory.
+    ///
+    /// Defaults to `send` with the already read body.
+    fn send_streaming(&self, request: Request) -> Result<StreamingResponse> {
+        let response = try!(self.send(request));
+        Ok(StreamingResponse::from(response))
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn send(&self, request: Request) -> Result<Response> {
+        (**self).send(request)
+    }
+
+    fn send_streaming(&self, request: Request) -> Result<StreamingResponse> {
+        (**self).send_streaming(request)
+    }
+}
+
+/// Future of the response of an `AsyncTransport`.
+#[cfg(feature = "async")]
+pub type ResponseFuture = Box<Future<Item=Response, Error=Error> + Send>;
+
+/// Sends http requests without blocking the calling thread, e.g. for the `AsyncTwitchClient`.
+#[cfg(feature = "async")]
+pub trait AsyncTransport: Send + Sync {
+    /// Sends the request and resolves to the response with any status code.
+    ///
+    /// Errors are only returned if no response could be received at all (e.g. connection failures).
+    fn send(&self, request: Request) -> ResponseFuture;
+}
+
+#[cfg(feature = "async")]
+impl<T: AsyncTransport + ?Sized> AsyncTransport for Arc<T> {
+    fn send(&self, request: Request) -> ResponseFuture {
+        (**self).send(request)
+    }
+}
+
+/// Http method of a request.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum Method {
+    /// GET method.
+    Get,
+    /// POST method.
+    Post,
+    /// PUT method.
+    Put,
+    /// DELETE method.
+    Delete,
+}
+
+/// Http request to Twitch.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Request {
+    method: Method,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<String>,
+    timeout: Option<Duration>,
+}
+
+impl Request {
+    pub(crate) fn new(method: Method, url: String, headers: Vec<(String, String)>, body: Option<String>) -> Request {
+        Request {
+            method: method,
+            url: url,
+            headers: headers,
+            body: body,
+            timeout: None,
+        }
+    }
+    pub(crate) fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+    /// Http method of the request.
+    pub fn method(&self) -> Method {
+        self.method
+    }
+    /// Absolute url including the query string.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+    /// Header names and values.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+    /// Optional JSON body.
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_ref().map(|body| body.as_str())
+    }
+    /// Optional timeout of this request that overrides the timeout of the transport
+    /// (see `RequestOptions`).
+    ///
+    /// Transports that only support client wide timeouts (e.g. the hyper transport) ignore it.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+/// Http response from Twitch with the completely read body.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Response {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl Response {
+    /// Creates a response, e.g. for custom transports.
+    pub fn new(status: u16, headers: Vec<(String, String)>, body: String) -> Response {
+        Response {
+            status: status,
+            headers: headers,
+            body: body,
+        }
+    }
+    /// Creates a response from a body that is possibly compressed,
+    /// decoding it according to the `Content-Encoding` header (`gzip` or `deflate`).
+    ///
+    /// The `Content-Encoding` and `Content-Length` headers are removed if the body was decoded,
+    /// since they do not apply to the decoded body anymore.
+    #[cfg(feature = "blocking")]
+    pub fn from_encoded_body(status: u16, mut headers: Vec<(String, String)>, body: &[u8]) -> Result<Response> {
+        let mut body_reader = decode_body(&mut headers, body);
+        let mut decoded_body = String::new();
+        try!(body_reader.read_to_string(&mut decoded_body));
+        Ok(Response::new(status, headers, decoded_body))
+    }
+    /// Http status code (e.g. 404).
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+    /// Whether the status code is in the 2xx range.
+    pub fn is_success(&self) -> bool {
+        self.status >= 200 && self.status < 300
+    }
+    /// Header names and values.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+    /// Returns the value of the first header with the name, ignoring the case of the name.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter()
+                .find(|&&(ref header_name, _)| header_name.eq_ignore_ascii_case(name))
+                .map(|&(_, ref value)| value.as_str())
+    }
+    /// Response body.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+    /// Consumes the response and returns the body.
+    pub fn into_body(self) -> String {
+        self.body
+    }
+}
+
+
+/// Http response from Twitch with a body that is read on demand (see `Transport::send_streaming`).
+pub struct StreamingResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Box<Read + Send>,
+}
+
+impl StreamingResponse {
+    /// Creates a response, e.g. for custom transports.
+    pub fn new(status: u16, headers: Vec<(String, String)>, body: Box<Read + Send>) -> StreamingResponse {
+        StreamingResponse {
+            status: status,
+            headers: headers,
+            body: body,
+        }
+    }
+    /// Http status code (e.g. 404).
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+    /// Whether the status code is in the 2xx range.
+    pub fn is_success(&self) -> bool {
+        self.status >= 200 && self.status < 300
+    }
+    /// Header names and values.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+    /// Consumes the response and returns the unread body.
+    pub fn into_body(self) -> Box<Read + Send> {
+        self.body
+    }
+    /// Reads the complete body into a `Response`.
+    pub fn into_response(mut self) -> Result<Response> {
+        let mut body = String::new();
+        try!(self.body.read_to_string(&mut body));
+        Ok(Response::new(self.status, self.headers, body))
+    }
+}
+
+impl From<Response> for StreamingResponse {
+    fn from(response: Response) -> StreamingResponse {
+        let body = Cursor::new(response.body.into_bytes());
+        StreamingResponse::new(response.status, response.headers, Box::new(body))
+    }
+}
+
+impl fmt::Debug for StreamingResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StreamingResponse")
+                .field("status", &self.status)
+                .field("headers", &self.headers)
+                .finish()
+    }
+}
+
+/// Wraps the body in a decoder according to the `Content-Encoding` header (`gzip` or `deflate`)
+/// and removes the `Content-Encoding` and `Content-Length` headers if it was decoded.
+#[cfg(feature = "blocking")]
+pub(crate) fn decode_body<'a, R: Read + Send + 'a>(headers: &mut Vec<(String, String)>, body: R)
+        -> Box<Read + Send + 'a> {
+    let content_encoding = headers.iter()
+            .find(|&&(ref name, _)| name.eq_ignore_ascii_case("Content-Encoding"))
+            .map(|&(_, ref value)| value.trim().to_lowercase());
+    let decoded_body: Box<Read + Send + 'a> = match content_encoding.as_ref().map(|encoding| encoding.as_str()) {
+        Some("gzip") | Some("x-gzip") => Box::new(GzDecoder::new(body)),
+        Some("deflate") => Box::new(ZlibDecoder::new(body)),
+        _ => return Box::new(body),
+    };
+    headers.retain(|&(ref name, _)| {
+        !name.eq_ignore_ascii_case("Content-Encoding") && !name.eq_ignore_ascii_case("Content-Length")
+    });
+    decoded_body
+}
+
+
+/// Settings of the default transports.
+#[cfg(feature = "blocking")]
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub(crate) struct TransportConfig {
+    pub(crate) connect_timeout: Option<Duration>,
+    pub(crate) read_timeout: Option<Duration>,
+    pub(crate) disable_compression: bool,
+    pub(crate) max_idle_connections_per_host: Option<usize>,
+    pub(crate) idle_timeout: Option<Duration>,
+    pub(crate) disable_keep_alive: bool,
+}
+
+#[cfg(feature = "hyper-transport")]
+pub(crate) fn default_transport(config: &TransportConfig) -> Result<Box<Transport>> {
+    let transport = try!(HyperTransport::with_config(config));
+    Ok(Box::new(transport))
+}
+
+#[cfg(all(feature = "blocking", feature = "reqwest", not(feature = "hyper-transport")))]
+pub(crate) fn default_transport(config: &TransportConfig) -> Result<Box<Transport>> {
+    let transport = try!(ReqwestTransport::with_config(config));
+    Ok(Box::new(transport))
+}
+
+
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use std::io::Write;
+    use flate2::Compression;
+    use flate2::write::{GzEncoder, ZlibEncoder};
+    use super::*;
+
+    #[test]
+    fn test_should_decode_compressed_body() {
+        let mut gzip_encoder = GzEncoder::new(Vec::new(), Compression::default());
+        gzip_encoder.write_all(br#"{"_total":1}"#).unwrap();
+        let gzip_body = gzip_encoder.finish().unwrap();
+        let headers = vec![
+            ("content-encoding".to_owned(), "gzip".to_owned()),
+            ("Content-Length".to_owned(), gzip_body.len().to_string()),
+        ];
+        let response = Response::from_encoded_body(200, headers, &gzip_body).unwrap();
+        assert_eq!(response.body(), r#"{"_total":1}"#);
+        assert!(response.headers().is_empty());
+
+        let mut zlib_encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        zlib_encoder.write_all(b"deflated").unwrap();
+        let headers = vec![("Content-Encoding".to_owned(), "deflate".to_owned())];
+        let response = Response::from_encoded_body(200, headers, &zlib_encoder.finish().unwrap()).unwrap();
+        assert_eq!(response.body(), "deflated");
+
+        let response = Response::from_encoded_body(200, Vec::new(), b"plain").unwrap();
+        assert_eq!(response.body(), "plain");
+    }
+}
@@ -0,0 +1,69 @@
+use std::io;
+
+use futures::{Future, Stream};
+use reqwest;
+
+use error::{Error, Result};
+use transport::{AsyncTransport, Method, Request, Response, ResponseFuture};
+
+
+/// Async transport using the async reqwest client, which needs to be driven by a tokio runtime.
+pub struct ReqwestAsyncTransport {
+    reqwest_client: reqwest::async::Client,
+}
+
+impl ReqwestAsyncTransport {
+    /// Creates a transport with a new async reqwest client.
+    pub fn new() -> Result<ReqwestAsyncTransport> {
+        let reqwest_client = try!(reqwest::async::Client::builder().build());
+        Ok(ReqwestAsyncTransport::with_reqwest_client(reqwest_client))
+    }
+
+    /// Creates a transport using the provided async reqwest client.
+    pub fn with_reqwest_client(reqwest_client: reqwest::async::Client) -> ReqwestAsyncTransport {
+        ReqwestAsyncTransport {
+            reqwest_client: reqwest_client,
+        }
+    }
+}
+
+impl AsyncTransport for ReqwestAsyncTransport {
+    fn send(&self, request: Request) -> ResponseFuture {
+        let method = match request.method() {
+            Method::Get => reqwest::Method::GET,
+            Method::Post => reqwest::Method::POST,
+            Method::Put => reqwest::Method::PUT,
+            Method::Delete => reqwest::Method::DELETE,
+        };
+
+        let mut request_builder = self.reqwest_client.request(method, request.url());
+        for &(ref name, ref value) in request.headers() {
+            request_builder = request_builder.header(name.as_str(), value.as_str());
+        }
+        if let Some(body) = request.body() {
+            request_builder = request_builder.body(body.to_owned());
+        }
+        if let Some(timeout) = request.timeout() {
+            request_builder = request_builder.timeout(timeout);
+        }
+
+        // reqwest decodes gzip (and removes the header), deflate is not requested by the async client.
+        let response = request_builder.send()
+                .and_then(|response| {
+                    let headers: Vec<(String, String)> = response.headers().iter()
+                            .filter_map(|(name, value)| {
+                                value.to_str().ok().map(|value| (name.as_str().to_owned(), value.to_owned()))
+                            })
+                            .collect();
+                    let status = response.status().as_u16();
+                    response.into_body().concat2().map(move |body| (status, headers, body))
+                })
+                .map_err(Error::from)
+                .and_then(|(status, headers, body)| {
+                    let body = try!(String::from_utf8(body.to_vec())
+                            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)));
+                    Ok(Response::new(status, headers, body))
+                });
+        Box::new(response)
+    }
+}
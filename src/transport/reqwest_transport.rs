@@ -0,0 +1,80 @@
+use reqwest;
+
+use error::Result;
+use transport::{self, Transport, TransportConfig, Method, Request, Response, StreamingResponse};
+
+
+/// Transport using a reqwest client.
+pub struct ReqwestTransport {
+    reqwest_client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Creates a transport with a new reqwest client.
+    pub fn new() -> Result<ReqwestTransport> {
+        ReqwestTransport::with_config(&TransportConfig::default())
+    }
+
+    /// Creates a transport using the provided reqwest client.
+    pub fn with_reqwest_client(reqwest_client: reqwest::Client) -> ReqwestTransport {
+        ReqwestTransport {
+            reqwest_client: reqwest_client,
+        }
+    }
+
+    pub(crate) fn with_config(config: &TransportConfig) -> Result<ReqwestTransport> {
+        let mut client_builder = reqwest::Client::builder();
+        if let Some(read_timeout) = config.read_timeout {
+            client_builder = client_builder.timeout(read_timeout);
+        }
+        if let Some(connect_timeout) = config.connect_timeout {
+            client_builder = client_builder.connect_timeout(connect_timeout);
+        }
+        client_builder = client_builder.gzip(!config.disable_compression);
+        if config.disable_keep_alive {
+            client_builder = client_builder.max_idle_per_host(0);
+        } else if let Some(max_idle_connections_per_host) = config.max_idle_connections_per_host {
+            client_builder = client_builder.max_idle_per_host(max_idle_connections_per_host);
+        }
+        let reqwest_client = try!(client_builder.build());
+        Ok(ReqwestTransport::with_reqwest_client(reqwest_client))
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: Request) -> Result<Response> {
+        let response = try!(self.send_streaming(request));
+        response.into_response()
+    }
+
+    fn send_streaming(&self, request: Request) -> Result<StreamingResponse> {
+        let method = match request.method() {
+            Method::Get => reqwest::Method::GET,
+            Method::Post => reqwest::Method::POST,
+            Method::Put => reqwest::Method::PUT,
+            Method::Delete => reqwest::Method::DELETE,
+        };
+
+        let mut request_builder = self.reqwest_client.request(method, request.url());
+        for &(ref name, ref value) in request.headers() {
+            request_builder = request_builder.header(name.as_str(), value.as_str());
+        }
+        if let Some(body) = request.body() {
+            request_builder = request_builder.body(body.to_owned());
+        }
+        if let Some(timeout) = request.timeout() {
+            request_builder = request_builder.timeout(timeout);
+        }
+        let response = try!(request_builder.send());
+
+        let mut headers = response.headers().iter()
+                .filter_map(|(name, value)| {
+                    value.to_str().ok().map(|value| (name.as_str().to_owned(), value.to_owned()))
+                })
+                .collect();
+        let status = response.status().as_u16();
+        // reqwest already decodes gzip (and removes the header), deflate is decoded here.
+        let body = transport::decode_body(&mut headers, response);
+        Ok(StreamingResponse::new(status, headers, body))
+    }
+}
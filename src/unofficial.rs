@@ -0,0 +1,85 @@
+//! Endpoints of the undocumented Twitch APIs (`unofficial` feature).
+//!
+//! These endpoints are used by the Twitch website itself and have no equivalent in the documented APIs.
+//! Twitch may change or remove them at any time without notice, so they are only compiled with the
+//! `unofficial` feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use twitch_client::TwitchClient;
+//!
+//! let twitch_client = TwitchClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap();
+//!
+//! for panel in twitch_client.channel_panels("test_channel").unwrap() {
+//!     println!("{:?}: {:?}", panel.data().title(), panel.data().link());
+//! }
+//!
+//! for host in twitch_client.channel_hosts(12345).unwrap().hosts() {
+//!     println!("Hosted by {:?}", host.host_display_name());
+//! }
+//! ```
+
+use error::Result;
+use model;
+use model::chatter::Chatters;
+use model::host::{ChannelId, Hosts};
+use model::panel::Panel;
+use param::{IntoQueryString, QueryParams};
+use TwitchClient;
+
+
+const CHANNELS_BASE_URL: &'static str = "https://api.twitch.tv/api/channels";
+const HOSTS_URL: &'static str = "https://tmi.twitch.tv/hosts";
+const CHATTERS_BASE_URL: &'static str = "https://tmi.twitch.tv/group/user";
+
+
+impl TwitchClient {
+
+    /// Get channel panels.
+    ///
+    /// Returns the panels below the video player of the channel in display order.
+    pub fn channel_panels(&self, channel: &str) -> Result<Vec<Panel>> {
+        let url = format!("{}/{}/panels", CHANNELS_BASE_URL, channel.to_lowercase());
+        let response = try!(self.http_client.get_content_from_link(&url));
+        let mut panels: Vec<Panel> = try!(model::from_json(&response, self.strict_mode));
+        panels.sort_by_key(|panel| panel.display_order());
+        Ok(panels)
+    }
+
+    /// Get channel hosts.
+    ///
+    /// Returns the channels that are currently hosting the channel, including their logins and display names.
+    pub fn channel_hosts<C: Into<ChannelId>>(&self, channel_id: C) -> Result<Hosts> {
+        let params = HostsParams {
+            include_logins: 1,
+            target: channel_id.into(),
+        };
+        let query = params.into_query_string();
+        let url = format!("{}{}", HOSTS_URL, query);
+        let response = try!(self.http_client.get_content_from_link(&url));
+        let hosts: Hosts = try!(model::from_json(&response, self.strict_mode));
+        Ok(hosts)
+    }
+
+    /// Get chatters.
+    ///
+    /// Returns the logins of the users in the chat of the channel grouped by role (broadcaster, moderators,
+    /// vips, staff and viewers) with the total number of chatters.
+    pub fn chatters(&self, channel: &str) -> Result<Chatters> {
+        let url = format!("{}/{}/chatters", CHATTERS_BASE_URL, channel.to_lowercase());
+        let response = try!(self.http_client.get_content_from_link(&url));
+        let chatters: Chatters = try!(model::from_json(&response, self.strict_mode));
+        Ok(chatters)
+    }
+
+}
+
+
+#[derive(Serialize)]
+struct HostsParams {
+    include_logins: u8,
+    target: ChannelId,
+}
+
+impl QueryParams for HostsParams {}
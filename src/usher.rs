@@ -0,0 +1,274 @@
+//! HLS playlists of live streams.
+//!
+//! Twitch serves live streams as HLS from usher. The master playlist of a channel
+//! requires a signed access token and lists a variant playlist for every quality (e.g. "720p60" or "audio_only"),
+//! which can be passed to a player or downloader.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use twitch_client::TwitchClient;
+//!
+//! let twitch_client = TwitchClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap();
+//!
+//! let playlist = twitch_client.stream_playlist("test_channel").unwrap();
+//! for variant in playlist.variants() {
+//!     println!("{}: {}", variant.name(), variant.url());
+//! }
+//! ```
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use url::form_urlencoded;
+
+use error::Result;
+use model;
+use model::usher::StreamAccessToken;
+use TwitchClient;
+
+
+const ACCESS_TOKEN_BASE_URL: &'static str = "https://api.twitch.tv/api/channels";
+const USHER_BASE_URL: &'static str = "https://usher.ttvnw.net/api/channel/hls";
+
+
+impl TwitchClient {
+
+    /// Get stream access token.
+    ///
+    /// Returns the signed access token that is required for the playlists of the channel's live stream.
+    pub fn stream_access_token(&self, channel: &str) -> Result<StreamAccessToken> {
+        let url = format!("{}/{}/access_token", ACCESS_TOKEN_BASE_URL, channel.to_lowercase());
+        let response = try!(self.http_client.get_content_from_link(&url));
+        let access_token: StreamAccessToken = try!(model::from_json(&response, self.strict_mode));
+        Ok(access_token)
+    }
+
+    /// Get stream playlist url.
+    ///
+    /// Returns the url of the master playlist of the channel's live stream,
+    /// signed with a new access token (see `stream_access_token`).
+    pub fn stream_playlist_url(&self, channel: &str) -> Result<String> {
+        let access_token = try!(self.stream_access_token(channel));
+        Ok(playlist_url(channel, &access_token))
+    }
+
+    /// Get stream playlist.
+    ///
+    /// Returns the parsed master playlist of the channel's live stream.
+    /// Fails with `Error::NotFound` if the channel is offline.
+    pub fn stream_playlist(&self, channel: &str) -> Result<MasterPlaylist> {
+        let url = try!(self.stream_playlist_url(channel));
+        let response = try!(self.http_client.get_content_from_link(&url));
+        Ok(MasterPlaylist::parse(&response))
+    }
+
+}
+
+
+/// Builds the url of the master playlist of a channel's live stream with an access token.
+pub fn playlist_url(channel: &str, access_token: &StreamAccessToken) -> String {
+    let random = SystemTime::now().duration_since(UNIX_EPOCH)
+            .map(|duration| duration.subsec_nanos() % 1_000_000)
+            .unwrap_or(0);
+    let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("player", "twitchweb")
+            .append_pair("token", access_token.token())
+            .append_pair("sig", access_token.sig())
+            .append_pair("allow_source", "true")
+            .append_pair("allow_audio_only", "true")
+            .append_pair("p", &random.to_string())
+            .finish();
+    format!("{}/{}.m3u8?{}", USHER_BASE_URL, channel.to_lowercase(), query)
+}
+
+
+/// Master playlist of a live stream, listing the available qualities.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MasterPlaylist {
+    variants: Vec<Variant>,
+}
+
+/// Quality variant of a live stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    name: String,
+    group_id: String,
+    bandwidth: u64,
+    resolution: Option<(u32, u32)>,
+    codecs: Option<String>,
+    frame_rate: Option<f64>,
+    url: String,
+}
+
+impl MasterPlaylist {
+    /// Parses the variants of an M3U8 master playlist.
+    ///
+    /// Malformed lines are skipped, so that changes of the playlist format do not break existing qualities.
+    pub fn parse(playlist: &str) -> MasterPlaylist {
+        let mut names = Vec::new();
+        let mut variants = Vec::new();
+        let mut stream_info = None;
+
+        for line in playlist.lines().map(|line| line.trim()).filter(|line| !line.is_empty()) {
+            if line.starts_with("#EXT-X-MEDIA:") {
+                let attributes = parse_attributes(&line["#EXT-X-MEDIA:".len()..]);
+                let group_id = attribute(&attributes, "GROUP-ID");
+                let name = attribute(&attributes, "NAME");
+                if let (Some(group_id), Some(name)) = (group_id, name) {
+                    names.push((group_id.to_owned(), name.to_owned()));
+                }
+            } else if line.starts_with("#EXT-X-STREAM-INF:") {
+                stream_info = Some(parse_attributes(&line["#EXT-X-STREAM-INF:".len()..]));
+            } else if !line.starts_with('#') {
+                if let Some(attributes) = stream_info.take() {
+                    let group_id = attribute(&attributes, "VIDEO").unwrap_or_default().to_owned();
+                    let name = names.iter()
+                            .find(|&&(ref name_group_id, _)| *name_group_id == group_id)
+                            .map(|&(_, ref name)| name.clone())
+                            .unwrap_or_else(|| group_id.clone());
+                    variants.push(Variant {
+                        name: name,
+                        group_id: group_id,
+                        bandwidth: attribute(&attributes, "BANDWIDTH")
+                                .and_then(|bandwidth| bandwidth.parse().ok())
+                                .unwrap_or(0),
+                        resolution: attribute(&attributes, "RESOLUTION").and_then(parse_resolution),
+                        codecs: attribute(&attributes, "CODECS").map(|codecs| codecs.to_owned()),
+                        frame_rate: attribute(&attributes, "FRAME-RATE").and_then(|rate| rate.parse().ok()),
+                        url: line.to_owned(),
+                    });
+                }
+            }
+        }
+
+        MasterPlaylist {
+            variants: variants,
+        }
+    }
+    /// Variants in the order of the playlist, which lists the source quality first.
+    pub fn variants(&self) -> &Vec<Variant> {
+        &self.variants
+    }
+    /// Returns the variant with the name (e.g. "720p60") or group id (e.g. "chunked" for the source quality).
+    pub fn variant(&self, name: &str) -> Option<&Variant> {
+        self.variants.iter().find(|variant| variant.name == name || variant.group_id == name)
+    }
+}
+
+impl Variant {
+    /// Example value: "1080p60 (source)"
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+    /// Example value: "chunked"
+    pub fn group_id(&self) -> &String {
+        &self.group_id
+    }
+    /// Bits per second.
+    ///
+    /// Example value: 6450000
+    pub fn bandwidth(&self) -> u64 {
+        self.bandwidth
+    }
+    /// Width and height, `None` for audio only.
+    ///
+    /// Example value: (1920, 1080)
+    pub fn resolution(&self) -> Option<(u32, u32)> {
+        self.resolution
+    }
+    /// Example value: "avc1.64002A,mp4a.40.2"
+    pub fn codecs(&self) -> &Option<String> {
+        &self.codecs
+    }
+    /// Example value: 60.0
+    pub fn frame_rate(&self) -> Option<f64> {
+        self.frame_rate
+    }
+    /// Url of the variant playlist.
+    ///
+    /// Example value: "https://video-weaver.fra02.hls.ttvnw.net/v1/playlist/CpQD.m3u8"
+    pub fn url(&self) -> &String {
+        &self.url
+    }
+}
+
+fn parse_attributes(attributes: &str) -> Vec<(String, String)> {
+    let mut parsed = Vec::new();
+    let mut in_quotes = false;
+    let mut start = 0;
+    for (index, character) in attributes.char_indices().chain(Some((attributes.len(), ','))) {
+        match character {
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => {
+                let attribute = &attributes[start..index];
+                if let Some(separator) = attribute.find('=') {
+                    let value = attribute[separator + 1..].trim_matches('"');
+                    parsed.push((attribute[..separator].to_owned(), value.to_owned()));
+                }
+                start = index + 1;
+            },
+            _ => {},
+        }
+    }
+    parsed
+}
+
+fn attribute<'a>(attributes: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    attributes.iter()
+            .find(|&&(ref attribute_name, _)| attribute_name == name)
+            .map(|&(_, ref value)| value.as_str())
+}
+
+fn parse_resolution(resolution: &str) -> Option<(u32, u32)> {
+    let mut dimensions = resolution.splitn(2, 'x');
+    match (dimensions.next().and_then(|width| width.parse().ok()),
+           dimensions.next().and_then(|height| height.parse().ok())) {
+        (Some(width), Some(height)) => Some((width, height)),
+        _ => None,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAYLIST: &'static str = r#"#EXTM3U
+#EXT-X-TWITCH-INFO:NODE="video-edge-c2a3b4.fra02",SERVER-TIME="1555946400.00"
+#EXT-X-MEDIA:TYPE=VIDEO,GROUP-ID="chunked",NAME="1080p60 (source)",AUTOSELECT=YES,DEFAULT=YES
+#EXT-X-STREAM-INF:BANDWIDTH=6450000,RESOLUTION=1920x1080,CODECS="avc1.64002A,mp4a.40.2",VIDEO="chunked",FRAME-RATE=60.000
+https://video-weaver.fra02.hls.ttvnw.net/v1/playlist/source.m3u8
+#EXT-X-MEDIA:TYPE=VIDEO,GROUP-ID="720p30",NAME="720p",AUTOSELECT=YES,DEFAULT=YES
+#EXT-X-STREAM-INF:BANDWIDTH=2373000,RESOLUTION=1280x720,CODECS="avc1.4D401F,mp4a.40.2",VIDEO="720p30",FRAME-RATE=30.000
+https://video-weaver.fra02.hls.ttvnw.net/v1/playlist/720p30.m3u8
+#EXT-X-MEDIA:TYPE=VIDEO,GROUP-ID="audio_only",NAME="audio_only",AUTOSELECT=NO,DEFAULT=NO
+#EXT-X-STREAM-INF:BANDWIDTH=160000,CODECS="mp4a.40.2",VIDEO="audio_only"
+https://video-weaver.fra02.hls.ttvnw.net/v1/playlist/audio_only.m3u8
+"#;
+
+    #[test]
+    fn test_should_parse_variants() {
+        let playlist = MasterPlaylist::parse(PLAYLIST);
+        assert_eq!(playlist.variants().len(), 3);
+
+        let source = playlist.variant("chunked").unwrap();
+        assert_eq!(source.name(), "1080p60 (source)");
+        assert_eq!(source.bandwidth(), 6450000);
+        assert_eq!(source.resolution(), Some((1920, 1080)));
+        assert_eq!(source.codecs(), &Some("avc1.64002A,mp4a.40.2".to_owned()));
+        assert_eq!(source.frame_rate(), Some(60.0));
+        assert_eq!(source.url(), "https://video-weaver.fra02.hls.ttvnw.net/v1/playlist/source.m3u8");
+
+        let audio_only = playlist.variant("audio_only").unwrap();
+        assert_eq!(audio_only.resolution(), None);
+        assert_eq!(audio_only.frame_rate(), None);
+    }
+
+    #[test]
+    fn test_should_skip_malformed_lines() {
+        let playlist = MasterPlaylist::parse("#EXTM3U\n\
+                                              https://example.com/orphan.m3u8\n\
+                                              #EXT-X-STREAM-INF:BANDWIDTH=x\n");
+        assert!(playlist.variants().is_empty());
+    }
+}
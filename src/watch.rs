@@ -0,0 +1,299 @@
+//! Polling of channels for changes of their live streams.
+//!
+//! Twitch API v3/v5 has no push notifications for the live status of a channel,
+//! so the `StreamWatcher` polls the streams of a set of channels at an interval on a background thread
+//! and reports the differences to the last poll as `StreamEvent`s.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//! use twitch_client::TwitchClient;
+//! use twitch_client::watch::{StreamWatcher, StreamEvent};
+//!
+//! let twitch_client = Arc::new(TwitchClient::new("<YOUR_TWITCH_CLIENT_ID>").unwrap());
+//!
+//! let (handle, events) = StreamWatcher::new(twitch_client)
+//!         .with_channel("test_channel")
+//!         .with_interval(Duration::from_secs(60))
+//!         .spawn_with_receiver();
+//! for event in events {
+//!     if let StreamEvent::WentLive { channel, .. } = event {
+//!         println!("{} went live", channel);
+//!     }
+//! }
+//! handle.stop();
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use error::Error;
+use model::stream::Stream;
+use TwitchClient;
+
+
+const DEFAULT_INTERVAL_SECS: u64 = 60;
+
+
+/// Change of a watched channel's live stream.
+#[derive(Debug)]
+pub enum StreamEvent {
+    /// The channel started streaming (or was already live at the first poll).
+    WentLive {
+        /// Name of the channel.
+        channel: String,
+        /// Live stream of the channel.
+        stream: Stream,
+    },
+    /// The channel stopped streaming.
+    WentOffline {
+        /// Name of the channel.
+        channel: String,
+    },
+    /// The game of the live stream changed.
+    GameChanged {
+        /// Name of the channel.
+        channel: String,
+        /// Game before the change.
+        old_game: Option<String>,
+        /// Game after the change.
+        new_game: Option<String>,
+    },
+    /// The title (status) of the live stream changed.
+    TitleChanged {
+        /// Name of the channel.
+        channel: String,
+        /// Title before the change.
+        old_title: Option<String>,
+        /// Title after the change.
+        new_title: Option<String>,
+    },
+    /// Polling failed. The state of the channels is kept and compared with the next successful poll.
+    PollFailed(Error),
+}
+
+/// Polls the live streams of channels on a background thread (see `watch`).
+pub struct StreamWatcher {
+    client: Arc<TwitchClient>,
+    channels: Vec<String>,
+    interval: Duration,
+}
+
+/// Handle of a running `StreamWatcher` to change the watched channels or to stop it.
+///
+/// The watcher is stopped when the handle is dropped.
+pub struct WatcherHandle {
+    channels: Arc<Mutex<Vec<String>>>,
+    stop_sender: Sender<()>,
+    thread: JoinHandle<()>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct LastSeen {
+    game: Option<String>,
+    title: Option<String>,
+}
+
+impl StreamWatcher {
+    /// Constructs a new watcher without channels that polls every 60 seconds.
+    pub fn new(client: Arc<TwitchClient>) -> StreamWatcher {
+        StreamWatcher {
+            client: client,
+            channels: Vec::new(),
+            interval: Duration::from_secs(DEFAULT_INTERVAL_SECS),
+        }
+    }
+    /// Watches the channel (name).
+    /// Can be called multiple times to watch a list of channels.
+    pub fn with_channel(mut self, channel: &str) -> StreamWatcher {
+        self.channels.push(channel.to_lowercase());
+        self
+    }
+    /// Sets the time between two polls.
+    ///
+    /// Twitch caches the streams for about a minute, so shorter intervals rarely report changes earlier.
+    pub fn with_interval(mut self, interval: Duration) -> StreamWatcher {
+        self.interval = interval;
+        self
+    }
+    /// Starts polling on a background thread that calls the callback with every event.
+    pub fn spawn<F: FnMut(StreamEvent) + Send + 'static>(self, mut callback: F) -> WatcherHandle {
+        let channels = Arc::new(Mutex::new(self.channels));
+        let watched_channels = channels.clone();
+        let client = self.client;
+        let interval = self.interval;
+        let (stop_sender, stop_receiver) = mpsc::channel();
+
+        let thread = thread::spawn(move || {
+            let mut last_seen = HashMap::new();
+            loop {
+                let channels = watched_channels.lock().unwrap().clone();
+                for event in poll(&client, &channels, &mut last_seen) {
+                    callback(event);
+                }
+                match stop_receiver.recv_timeout(interval) {
+                    Err(RecvTimeoutError::Timeout) => {},
+                    _ => break,
+                }
+            }
+        });
+
+        WatcherHandle {
+            channels: channels,
+            stop_sender: stop_sender,
+            thread: thread,
+        }
+    }
+    /// Starts polling on a background thread that sends the events to the returned receiver.
+    ///
+    /// The thread keeps polling until the handle is stopped or dropped, even if the receiver is dropped.
+    pub fn spawn_with_receiver(self) -> (WatcherHandle, Receiver<StreamEvent>) {
+        let (sender, receiver) = mpsc::channel();
+        let handle = self.spawn(move |event| {
+            let _ = sender.send(event);
+        });
+        (handle, receiver)
+    }
+}
+
+impl WatcherHandle {
+    /// Watches the channel (name) from the next poll on.
+    pub fn add_channel(&self, channel: &str) {
+        let channel = channel.to_lowercase();
+        let mut channels = self.channels.lock().unwrap();
+        if !channels.contains(&channel) {
+            channels.push(channel);
+        }
+    }
+    /// Stops watching the channel (name) from the next poll on, without reporting it as offline.
+    pub fn remove_channel(&self, channel: &str) {
+        let channel = channel.to_lowercase();
+        self.channels.lock().unwrap().retain(|watched_channel| *watched_channel != channel);
+    }
+    /// Returns the watched channels.
+    pub fn channels(&self) -> Vec<String> {
+        self.channels.lock().unwrap().clone()
+    }
+    /// Stops polling and waits until the background thread finished.
+    pub fn stop(self) {
+        let _ = self.stop_sender.send(());
+        let _ = self.thread.join();
+    }
+}
+
+/// Polls the streams of the channels once and returns the changes to the last seen state.
+fn poll(client: &TwitchClient, channels: &[String], last_seen: &mut HashMap<String, LastSeen>) -> Vec<StreamEvent> {
+    last_seen.retain(|channel, _| channels.contains(channel));
+    if channels.is_empty() {
+        return Vec::new();
+    }
+    let channel_names: Vec<&str> = channels.iter().map(|channel| channel.as_str()).collect();
+    let streams = match client.streams_for_channels(&channel_names) {
+        Ok(streams) => streams,
+        Err(err) => return vec![StreamEvent::PollFailed(err)],
+    };
+
+    let mut events = Vec::new();
+    let mut live_channels = Vec::new();
+    for stream in streams.streams() {
+        let channel = stream.channel().name().to_lowercase();
+        let seen = LastSeen {
+            game: stream.game().clone(),
+            title: stream.channel().status().clone(),
+        };
+        match last_seen.insert(channel.clone(), seen.clone()) {
+            None => events.push(StreamEvent::WentLive {
+                channel: channel.clone(),
+                stream: stream.clone(),
+            }),
+            Some(previous) => {
+                if previous.game != seen.game {
+                    events.push(StreamEvent::GameChanged {
+                        channel: channel.clone(),
+                        old_game: previous.game,
+                        new_game: seen.game,
+                    });
+                }
+                if previous.title != seen.title {
+                    events.push(StreamEvent::TitleChanged {
+                        channel: channel.clone(),
+                        old_title: previous.title,
+                        new_title: seen.title,
+                    });
+                }
+            },
+        }
+        live_channels.push(channel);
+    }
+
+    let offline_channels: Vec<String> = last_seen.keys()
+            .filter(|channel| !live_channels.contains(channel))
+            .cloned()
+            .collect();
+    for channel in offline_channels {
+        last_seen.remove(&channel);
+        events.push(StreamEvent::WentOffline {
+            channel: channel,
+        });
+    }
+    events
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use transport::Method;
+    use testing::MockTransport;
+    use TwitchClientBuilder;
+
+    fn create_mock_twitch_client(streams: &str) -> TwitchClient {
+        TwitchClientBuilder::new("client_id")
+                .with_transport(MockTransport::new().with_response(Method::Get, "/kraken/streams", 200, streams))
+                .build()
+                .unwrap()
+    }
+
+    #[test]
+    fn test_should_report_transitions_once() {
+        let live_client = create_mock_twitch_client(::testing::fixtures::STREAMS);
+        let offline_client = create_mock_twitch_client(r#"{"_total": 0, "streams": []}"#);
+        let channels = vec!["test_channel".to_owned()];
+        let mut last_seen = HashMap::new();
+
+        match poll(&live_client, &channels, &mut last_seen).as_slice() {
+            [StreamEvent::WentLive { ref channel, .. }] => assert_eq!(channel, "test_channel"),
+            events => panic!("expecting went live event, but got: {:?}", events),
+        }
+        assert!(poll(&live_client, &channels, &mut last_seen).is_empty());
+        match poll(&offline_client, &channels, &mut last_seen).as_slice() {
+            [StreamEvent::WentOffline { ref channel }] => assert_eq!(channel, "test_channel"),
+            events => panic!("expecting went offline event, but got: {:?}", events),
+        }
+        assert!(poll(&offline_client, &channels, &mut last_seen).is_empty());
+    }
+
+    #[test]
+    fn test_should_report_game_change() {
+        let client = create_mock_twitch_client(::testing::fixtures::STREAMS);
+        let channels = vec!["test_channel".to_owned()];
+        let mut last_seen = HashMap::new();
+        last_seen.insert("test_channel".to_owned(), LastSeen {
+            game: Some("Old Game".to_owned()),
+            title: Some("test status".to_owned()),
+        });
+
+        match poll(&client, &channels, &mut last_seen).as_slice() {
+            [StreamEvent::GameChanged { ref old_game, ref new_game, .. }] => {
+                assert_eq!(old_game, &Some("Old Game".to_owned()));
+                assert_eq!(new_game, &Some("StarCraft II: Heart of the Swarm".to_owned()));
+            },
+            events => panic!("expecting game changed event, but got: {:?}", events),
+        }
+    }
+}